@@ -0,0 +1,464 @@
+//! 보스 모듈
+//!
+//! 스테이지 타임라인의 `Boss` 이벤트([`BossStart`])를 받아 보스를 스폰하고 연출과 공격 단계를 진행합니다.
+//! 보스도 [`Enemy`], [`Collider`], [`Velocity`]를 가진 적이므로
+//! 이동과 충돌은 일반 적과 같은 시스템이 처리합니다.
+//!
+//! # 구성
+//! - 몸통: [`Boss`], [`Health`], [`ScoreValue`]를 가진 적 엔티티
+//! - 부위: 몸통 주위의 판정 엔티티 ([`BossPart`]), 맞히면 몸통 체력이 깎임
+//!
+//! # 진행 ([`BossState`])
+//! 1. 등장: 화면 위에서 내려와 자리를 잡음 (피해를 받지 않음)
+//! 2. 전투: 좌우로 움직이며 남은 체력 비율에 맞는 단계의 탄막 패턴을 실행
+//! 3. 격파: 화면의 적 탄을 지우고 잠시 번쩍이며 가라앉은 뒤 사라짐 (점수는 격파 순간 피해 처리 시스템이 지급)
+//! 4. 퇴장: 제한 시간이 지나면 화면 위로 빠져나감 (점수 없음)
+//!
+//! 보스가 사라지면 스테이지 타임라인이 다음 이벤트로 진행합니다.
+//! 능력치, 부위, 단계는 설정 파일의 `boss` 항목에서 정합니다.
+
+use bevy::{ecs::message::MessageReader, prelude::*};
+
+use crate::components::{
+    Collider, ColliderShape, Damage, Enemy, Health, HitFlash, Hostile, Projectile, ScoreValue,
+    Velocity,
+};
+use crate::config::GameConfig;
+use crate::enemy::update_difficulty;
+use crate::pattern::{BulletEmitter, PatternLibrary, PatternRunner};
+use crate::resources::{Difficulty, Playfield, SpriteAssets};
+use crate::stage::{run_stage_timeline, BossStart};
+use crate::GameplaySet;
+
+/// 보스가 처음 스폰되는 높이 (화면 위쪽 끝에서 위로, 픽셀)
+const BOSS_SPAWN_MARGIN: f32 = 120.0;
+/// 격파된 보스가 가라앉는 속도 (픽셀/초)
+const BOSS_SINK_SPEED: f32 = 40.0;
+
+// =============================================================================
+// 보스 컴포넌트
+// =============================================================================
+
+/// 보스 몸통 컴포넌트입니다. 진행 상태와 현재 공격 단계를 저장합니다.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Boss {
+    /// 현재 진행 상태
+    pub state: BossState,
+    /// 현재 공격 단계 (설정의 `phases` 순서)
+    pub phase: usize,
+    /// 현재 상태가 이어진 시간 (초)
+    pub state_time: f32,
+}
+
+impl Boss {
+    /// 피해를 받는 상태인지 반환합니다. 전투 중에만 피해를 받습니다.
+    pub fn is_vulnerable(&self) -> bool {
+        self.state == BossState::Fighting
+    }
+
+    fn enter(&mut self, state: BossState) {
+        self.state = state;
+        self.state_time = 0.0;
+    }
+}
+
+/// 보스의 진행 상태입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BossState {
+    /// 화면 위에서 내려오는 중
+    Entering,
+    /// 공격 단계를 진행하는 중
+    Fighting,
+    /// 격파 연출 중
+    Defeated,
+    /// 제한 시간이 지나 화면 위로 퇴장하는 중
+    Escaping,
+}
+
+/// 보스 몸통에 붙은 부위 판정 컴포넌트입니다.
+///
+/// 매 틱 몸통 위치에 `offset`을 더한 곳으로 옮겨지고, 몸통과 같은 속도로 움직입니다.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct BossPart {
+    /// 부위가 붙은 보스 몸통
+    pub boss: Entity,
+    /// 몸통 중심에서의 위치
+    pub offset: Vec2,
+}
+
+// =============================================================================
+// 보스 플러그인
+// =============================================================================
+
+/// 보스 스폰과 연출, 공격 단계를 담당하는 플러그인입니다.
+pub struct BossPlugin;
+
+impl Plugin for BossPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            (spawn_bosses, update_bosses, attach_boss_parts)
+                .chain()
+                .after(run_stage_timeline)
+                // 보스 체력이 이번 틱의 난이도(랭크 포함)로 정해지도록
+                .after(update_difficulty)
+                .in_set(GameplaySet::Spawn),
+        );
+    }
+}
+
+// =============================================================================
+// 시스템 (Systems)
+// =============================================================================
+
+/// [`BossStart`] 메시지를 받아 화면 위에 보스와 부위를 스폰하는 시스템입니다.
+///
+/// 체력에는 [`Difficulty`]의 적 체력 배율을 적용합니다.
+fn spawn_bosses(
+    mut commands: Commands,
+    mut boss_start: MessageReader<BossStart>,
+    sprites: Res<SpriteAssets>,
+    playfield: Res<Playfield>,
+    config: Res<GameConfig>,
+    difficulty: Res<Difficulty>,
+) {
+    for start in boss_start.read() {
+        info!("Boss of stage {} appears", start.stage + 1);
+
+        let settings = &config.boss;
+        let position = Vec2::new(0.0, playfield.half_extents().y + BOSS_SPAWN_MARGIN);
+        let max_health =
+            ((settings.max_health as f32 * difficulty.enemy_health).round() as u32).max(1);
+
+        let boss = commands
+            .spawn((
+                Sprite {
+                    image: sprites.boss.clone(),
+                    ..default()
+                },
+                Transform {
+                    translation: position.extend(0.0),
+                    scale: Vec3::splat(settings.scale),
+                    ..default()
+                },
+                Enemy,
+                Boss {
+                    state: BossState::Entering,
+                    phase: 0,
+                    state_time: 0.0,
+                },
+                Velocity(Vec2::ZERO),
+                Collider::enemy(ColliderShape::Circle {
+                    radius: settings.collision_radius,
+                }),
+                Damage(config.enemy_contact_damage),
+                Health {
+                    current: max_health,
+                    max: max_health,
+                },
+                ScoreValue(settings.score),
+            ))
+            .id();
+
+        for part in &settings.parts {
+            let offset = Vec2::from(part.offset);
+            commands.spawn((
+                Transform::from_translation((position + offset).extend(0.0)),
+                Enemy,
+                BossPart { boss, offset },
+                Velocity(Vec2::ZERO),
+                Collider::enemy(ColliderShape::Circle {
+                    radius: part.collision_radius,
+                }),
+                Damage(config.enemy_contact_damage),
+            ));
+        }
+    }
+}
+
+/// 보스의 상태에 따라 이동 속도와 공격 단계를 정하는 시스템입니다.
+///
+/// - 등장: 멈출 위치까지 일정한 속도로 내려오고, 도착하면 첫 단계를 시작합니다.
+/// - 전투: 사인파로 좌우로 움직이며, 남은 체력 비율이 다음 단계 기준 이하가 되면 패턴을 바꿉니다.
+///   체력이 0이 되면 격파, 제한 시간이 지나면 퇴장으로 넘어갑니다.
+/// - 격파: 부위와 화면의 적 탄을 지우고, 연출 시간이 끝나면 몸통을 제거합니다.
+/// - 퇴장: 화면 위로 완전히 나가면 몸통을 제거합니다.
+///
+/// 위치는 다음 이동 단계에서 속도에 따라 바뀌므로 여기서는 속도만 정합니다.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn update_bosses(
+    mut commands: Commands,
+    time: Res<Time>,
+    playfield: Res<Playfield>,
+    config: Res<GameConfig>,
+    library: Res<PatternLibrary>,
+    mut bosses: Query<(Entity, &mut Boss, &Transform, &mut Velocity, &Health)>,
+    parts: Query<(Entity, &BossPart)>,
+    hostile_projectiles: Query<Entity, (With<Projectile>, With<Hostile>)>,
+) {
+    let delta = time.delta_secs();
+    let settings = &config.boss;
+    let top = playfield.half_extents().y;
+
+    for (entity, mut boss, transform, mut velocity, health) in bosses.iter_mut() {
+        boss.state_time += delta;
+        let position = transform.translation.truncate();
+
+        match boss.state {
+            BossState::Entering => {
+                let hold_y = top - settings.entrance_depth;
+                let entrance_speed =
+                    (BOSS_SPAWN_MARGIN + settings.entrance_depth) / settings.entrance_duration;
+                if position.y <= hold_y {
+                    velocity.0 = Vec2::ZERO;
+                    boss.enter(BossState::Fighting);
+                    boss.phase = 0;
+                    set_phase_pattern(&mut commands, entity, &config, &library, 0);
+                } else {
+                    // 멈출 위치를 지나치지 않도록 마지막 틱에는 남은 거리만큼만 이동
+                    let step = ((hold_y - position.y) / delta).max(-entrance_speed);
+                    velocity.0 = Vec2::new(0.0, step);
+                }
+            }
+            BossState::Fighting => {
+                if health.current == 0 {
+                    info!("Boss defeated");
+                    boss.enter(BossState::Defeated);
+                    velocity.0 = Vec2::new(0.0, -BOSS_SINK_SPEED);
+                    commands
+                        .entity(entity)
+                        .remove::<(BulletEmitter, PatternRunner, Collider)>()
+                        .insert(HitFlash(Timer::from_seconds(
+                            settings.defeat_duration,
+                            TimerMode::Once,
+                        )));
+                    for (part_entity, part) in parts.iter() {
+                        if part.boss == entity {
+                            commands.entity(part_entity).despawn();
+                        }
+                    }
+                    for projectile in hostile_projectiles.iter() {
+                        commands.entity(projectile).despawn();
+                    }
+                    continue;
+                }
+
+                if boss.state_time >= settings.time_limit {
+                    info!("Boss escaped");
+                    boss.enter(BossState::Escaping);
+                    velocity.0 = Vec2::new(0.0, settings.exit_speed);
+                    commands
+                        .entity(entity)
+                        .remove::<(BulletEmitter, PatternRunner)>();
+                    continue;
+                }
+
+                let fraction = health.current as f32 / health.max as f32;
+                let phase = settings.phase_at(fraction);
+                if phase != boss.phase {
+                    boss.phase = phase;
+                    set_phase_pattern(&mut commands, entity, &config, &library, phase);
+                }
+
+                // 다음 틱 위치가 사인파 위에 오도록 속도를 정함 (오차가 쌓이지 않음)
+                let angular = std::f32::consts::TAU / settings.sway_period;
+                let target_x = settings.sway_amplitude * (angular * boss.state_time).sin();
+                velocity.0 = Vec2::new((target_x - position.x) / delta, 0.0);
+            }
+            BossState::Defeated => {
+                if boss.state_time >= settings.defeat_duration {
+                    commands.entity(entity).despawn();
+                }
+            }
+            BossState::Escaping => {
+                if position.y > top + BOSS_SPAWN_MARGIN {
+                    commands.entity(entity).despawn();
+                    for (part_entity, part) in parts.iter() {
+                        if part.boss == entity {
+                            commands.entity(part_entity).despawn();
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 부위를 몸통 위치에 맞추고 몸통과 같은 속도를 주는 시스템입니다.
+///
+/// 몸통이 사라진 부위는 제거합니다.
+#[allow(clippy::type_complexity)]
+fn attach_boss_parts(
+    mut commands: Commands,
+    bosses: Query<(&Transform, &Velocity), (With<Boss>, Without<BossPart>)>,
+    mut parts: Query<(Entity, &BossPart, &mut Transform, &mut Velocity), Without<Boss>>,
+) {
+    for (entity, part, mut transform, mut velocity) in parts.iter_mut() {
+        let Ok((boss_transform, boss_velocity)) = bosses.get(part.boss) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        let position = boss_transform.translation.truncate() + part.offset;
+        transform.translation = position.extend(transform.translation.z);
+        velocity.0 = boss_velocity.0;
+    }
+}
+
+/// 보스에 `phase` 단계의 탄막 패턴을 붙입니다.
+///
+/// 실행 중인 패턴은 멈추고, 새 패턴은 다음 패턴 단계에서 처음부터 실행됩니다.
+/// 패턴이 없는 단계면 쏘지 않습니다.
+fn set_phase_pattern(
+    commands: &mut Commands,
+    entity: Entity,
+    config: &GameConfig,
+    library: &PatternLibrary,
+    phase: usize,
+) {
+    let mut boss = commands.entity(entity);
+    boss.remove::<(BulletEmitter, PatternRunner)>();
+
+    let handle = config
+        .boss
+        .phases
+        .get(phase)
+        .and_then(|phase| phase.pattern.as_ref())
+        .and_then(|path| library.handles.get(path));
+    if let Some(handle) = handle {
+        boss.insert(BulletEmitter(handle.clone()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{BossPhaseSettings, BossSettings};
+    use crate::resources::{AppState, FIXED_TICK_RATE};
+    use crate::tests::stepped_app;
+
+    #[test]
+    fn phase_follows_remaining_health() {
+        // 기본 설정의 단계 기준: 1.0, 0.6, 0.3
+        let settings = BossSettings::default();
+        let cases = [
+            (1.0, 0),
+            (0.61, 0),
+            (0.6, 1),
+            (0.31, 1),
+            (0.3, 2),
+            (0.01, 2),
+            (0.0, 2),
+        ];
+        for (fraction, phase) in cases {
+            assert_eq!(settings.phase_at(fraction), phase, "fraction {fraction}");
+        }
+
+        // 첫 단계 기준이 1.0보다 낮아도 처음에는 첫 단계
+        let settings = BossSettings {
+            phases: vec![BossPhaseSettings {
+                health: 0.5,
+                pattern: None,
+            }],
+            ..default()
+        };
+        assert_eq!(settings.phase_at(1.0), 0);
+        assert_eq!(
+            BossSettings {
+                phases: Vec::new(),
+                ..default()
+            }
+            .phase_at(0.5),
+            0
+        );
+    }
+
+    /// 판을 시작하고 보스를 불러 전투 상태가 될 때까지 진행한 뒤 몸통 엔티티를 반환합니다.
+    fn fighting_boss(app: &mut App) -> Entity {
+        app.world_mut()
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::InGame);
+        app.update();
+        app.world_mut().write_message(BossStart { stage: 0 });
+
+        for _ in 0..600 {
+            app.update();
+            let world = app.world_mut();
+            let mut bosses = world.query::<(Entity, &Boss)>();
+            if let Some((entity, boss)) = bosses.iter(world).next() {
+                if boss.state == BossState::Fighting {
+                    return entity;
+                }
+            }
+        }
+        panic!("boss never finished its entrance");
+    }
+
+    fn part_count(app: &mut App) -> usize {
+        let world = app.world_mut();
+        world.query::<&BossPart>().iter(world).count()
+    }
+
+    #[test]
+    fn boss_stops_at_hold_height_and_its_parts_die_with_it() {
+        let mut app = stepped_app(1);
+        let boss = fighting_boss(&mut app);
+
+        let world = app.world();
+        let config = world.resource::<GameConfig>();
+        let hold_y = world.resource::<Playfield>().half_extents().y - config.boss.entrance_depth;
+        let defeat_duration = config.boss.defeat_duration;
+        let parts = config.boss.parts.len();
+        assert!(parts > 0);
+        let body = world.get::<Transform>(boss).unwrap().translation;
+        assert!((body.y - hold_y).abs() < 1e-3, "stopped at {}", body.y);
+        assert_eq!(part_count(&mut app), parts);
+
+        // 부위는 몸통을 따라다님
+        app.update();
+        let world = app.world_mut();
+        let body = world.get::<Transform>(boss).unwrap().translation.truncate();
+        for (part, transform) in world.query::<(&BossPart, &Transform)>().iter(world) {
+            assert_eq!(part.boss, boss);
+            assert_eq!(transform.translation.truncate(), body + part.offset);
+        }
+
+        // 체력이 0이 되면 격파: 부위는 바로 사라지고 몸통은 연출이 끝난 뒤 사라짐
+        app.world_mut().get_mut::<Health>(boss).unwrap().current = 0;
+        app.update();
+        assert_eq!(
+            app.world().get::<Boss>(boss).unwrap().state,
+            BossState::Defeated
+        );
+        assert_eq!(part_count(&mut app), 0);
+
+        let ticks = (defeat_duration * FIXED_TICK_RATE as f32).ceil() as usize + 1;
+        for _ in 0..ticks {
+            app.update();
+        }
+        assert!(app.world().get_entity(boss).is_err());
+    }
+
+    #[test]
+    fn escaped_boss_is_removed_with_its_parts() {
+        let mut app = stepped_app(1);
+        let boss = fighting_boss(&mut app);
+        let time_limit = app.world().resource::<GameConfig>().boss.time_limit;
+
+        app.world_mut().get_mut::<Boss>(boss).unwrap().state_time = time_limit;
+        app.update();
+        assert_eq!(
+            app.world().get::<Boss>(boss).unwrap().state,
+            BossState::Escaping
+        );
+        assert!(part_count(&mut app) > 0);
+
+        for _ in 0..600 {
+            app.update();
+            if app.world().get_entity(boss).is_err() {
+                break;
+            }
+        }
+        assert!(app.world().get_entity(boss).is_err(), "boss never left");
+        assert_eq!(part_count(&mut app), 0);
+    }
+}
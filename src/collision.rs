@@ -1,12 +1,99 @@
 //! 충돌 감지 모듈
 //!
-//! 간단한 거리 기반(원형) 충돌 감지를 구현합니다.
-//! 적-플레이어 충돌 시 GameOver 상태로 전환합니다.
+//! 충돌체([`Collider`])끼리의 겹침 검사와 광선 질의([`raycast`])로 충돌을 감지합니다.
+//! 충돌 후보는 매 틱 다시 만드는 공간 격자([`SpatialGrid`], `spatial.rs`)에서 찾고,
+//! 도형 계산은 `shape.rs`에 있습니다.
+//!
+//! 이 모듈은 누가 누구에게 닿았는지만 찾아 [`Hit`] 메시지로 보냅니다.
+//! 피해, 투사체 소모, 아이템 줍기, 점수, 번쩍임 같은 결과는 메시지를 읽는 시스템들이 처리합니다
+//! (`damage.rs`, `powerup.rs`).
+//!
+//! # 충돌 규칙
+//! 공격자의 `mask`와 대상의 `layers`가 겹치고 도형이 겹치면 공격자가 대상에 닿습니다.
+//! 어떤 층끼리 닿는지는 [`Collider`]의 층별 생성 함수에서 정하므로,
+//! 새 상호작용은 이 모듈을 고치지 않고 층과 mask만 바꾸면 됩니다.
+//! - 플레이어 탄 → 적, 지형 / 적, 적 탄 → 플레이어 / 적 탄 → 지형 / 플레이어 → 아이템
+//! - 레이저 광선([`LaserBeam`]): 플레이어 탄과 같은 층에 처음 닿는 곳까지만 뻗고, 발사 간격마다 한 번
+//!
+//! 투사체는 가장 가까운 대상 하나에만 닿고, 나머지는 닿은 대상 모두에 닿습니다.
+//! 이전 위치([`PreviousPosition`])가 있는 원 충돌체는 연속 충돌 검사를 합니다:
+//! 이전 위치에서 현재 위치까지 지나간 경로에서 닿은 대상을 찾으므로, 빠른 투사체가 대상을 뚫고 지나가지 않고
+//! 가장 가까운 대상은 경로에서 먼저 닿은 대상입니다.
+//! 보스 부위([`BossPart`])에 닿으면 보스 몸통을 대상으로 보냅니다.
+//! 부활 대기 중인 엔티티는 격자에 없어 충돌하지 않고, 무적 상태인 엔티티에는 아무것도 닿지 않습니다.
 
 use bevy::prelude::*;
 
-use crate::components::{CollisionRadius, Enemy, Player, Projectile};
-use crate::resources::{AppState, Score, SCORE_PER_ENEMY};
+use crate::boss::BossPart;
+use crate::components::{
+    Collider, ColliderShape, CollisionLayers, Damage, Invulnerable, PreviousPosition, Projectile,
+    Respawning,
+};
+use crate::resources::Playfield;
+use crate::shape::Shape;
+use crate::spatial::{rebuild_spatial_grid, SpatialGrid};
+use crate::weapon::LaserBeam;
+use crate::GameplaySet;
+
+/// 레이저 광선이 닿는 층 (플레이어 탄과 같음)
+const LASER_BEAM_MASK: CollisionLayers =
+    CollisionLayers(CollisionLayers::ENEMY.0 | CollisionLayers::TERRAIN.0);
+
+// =============================================================================
+// 메시지
+// =============================================================================
+
+/// 공격자가 대상에 닿았을 때 보내는 메시지입니다.
+///
+/// 공격자는 충돌체의 mask로 대상에 닿은 엔티티(투사체, 적, 플레이어)나 레이저 광선입니다.
+/// 한 틱에 같은 대상에 여러 메시지가 올 수 있으며, 읽는 쪽에서 대상마다 한 번씩 처리합니다.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hit {
+    pub attacker: Entity,
+    pub target: Entity,
+    /// 대상에게 줄 피해 (공격자의 [`Damage`], 없으면 0)
+    pub damage: u32,
+}
+
+// =============================================================================
+// 광선 질의
+// =============================================================================
+
+/// 광선이 처음 닿은 대상입니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit {
+    pub entity: Entity,
+    /// 광선 시작점에서 닿은 지점까지의 거리
+    pub distance: f32,
+}
+
+/// `start`에서 `direction` 방향으로 `max_distance`까지 뻗은 광선이 처음 닿는 도형을 찾습니다.
+///
+/// `shapes`는 `(엔티티, 도형)` 목록입니다.
+/// 같은 거리에서 여러 도형에 닿으면 배치 순서([`Shape::placement_cmp`])로 골라 순회 순서와 무관하게 결과가 같습니다.
+/// 엔티티 순서는 도형까지 같을 때만 씁니다.
+pub fn raycast(
+    start: Vec2,
+    direction: Vec2,
+    max_distance: f32,
+    shapes: impl IntoIterator<Item = (Entity, Shape)>,
+) -> Option<RayHit> {
+    let end = start + direction.normalize_or_zero() * max_distance;
+    shapes
+        .into_iter()
+        .filter_map(|(entity, shape)| {
+            shape
+                .segment_distance(start, end)
+                .map(|distance| (RayHit { entity, distance }, shape))
+        })
+        .min_by(|(a, a_shape), (b, b_shape)| {
+            a.distance
+                .total_cmp(&b.distance)
+                .then_with(|| a_shape.placement_cmp(b_shape))
+                .then(a.entity.cmp(&b.entity))
+        })
+        .map(|(hit, _)| hit)
+}
 
 // =============================================================================
 // 충돌 플러그인
@@ -17,13 +104,12 @@ pub struct CollisionPlugin;
 
 impl Plugin for CollisionPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (
-                check_projectile_enemy_collision,
-                check_enemy_player_collision,
-            )
-                .run_if(in_state(AppState::InGame)),
+        app.add_message::<Hit>().add_systems(
+            FixedUpdate,
+            (detect_laser_hits, detect_contacts)
+                .chain()
+                .after(rebuild_spatial_grid)
+                .in_set(GameplaySet::Collision),
         );
     }
 }
@@ -32,68 +118,226 @@ impl Plugin for CollisionPlugin {
 // 시스템 (Systems)
 // =============================================================================
 
-/// 투사체와 적의 충돌을 검사하는 시스템입니다.
+/// 레이저 광선이 처음 닿는 충돌체를 찾아 광선 길이를 정하고 [`Hit`]을 보내는 시스템입니다.
 ///
-/// 충돌 시:
-/// - 투사체와 적 모두 삭제
-/// - 점수 증가
-fn check_projectile_enemy_collision(
-    mut commands: Commands,
-    mut score: ResMut<Score>,
-    projectiles: Query<(Entity, &Transform, &CollisionRadius), With<Projectile>>,
-    enemies: Query<(Entity, &Transform, &CollisionRadius), With<Enemy>>,
+/// 광선은 적(보스 부위 포함)이나 지형에 닿으면 멈추고, 닿은 것이 없으면 화면 끝까지 뻗습니다.
+/// 메시지는 발사 간격마다 한 번([`LaserBeam::damage`]가 0이 아닌 틱)만 보냅니다.
+/// 피해를 받지 않는 상태의 보스도 광선을 막습니다.
+fn detect_laser_hits(
+    mut hits: MessageWriter<Hit>,
+    playfield: Res<Playfield>,
+    grid: Res<SpatialGrid>,
+    mut beams: Query<(Entity, &mut LaserBeam)>,
+    parts: Query<&BossPart>,
 ) {
-    for (proj_entity, proj_transform, proj_radius) in projectiles.iter() {
-        for (enemy_entity, enemy_transform, enemy_radius) in enemies.iter() {
-            let distance = proj_transform
-                .translation
-                .truncate()
-                .distance(enemy_transform.translation.truncate());
+    // 화면 어디에서 쏘아도 반대편 끝을 넘는 길이
+    let max_distance = playfield.half_extents().length() * 2.0;
 
-            let collision_distance = proj_radius.0 + enemy_radius.0;
+    for (beam_entity, mut beam) in beams.iter_mut() {
+        let end = beam.origin + beam.direction * max_distance;
+        let shapes = grid
+            .query_segment(beam.origin, end, LASER_BEAM_MASK)
+            .into_iter()
+            .map(|entry| (entry.entity, entry.shape));
+        let hit = raycast(beam.origin, beam.direction, max_distance, shapes);
+        beam.length = hit.map_or(max_distance, |hit| hit.distance);
 
-            if distance < collision_distance {
-                // 충돌! 둘 다 제거
-                commands.entity(proj_entity).despawn();
-                commands.entity(enemy_entity).despawn();
-
-                // 점수 증가
-                score.0 += SCORE_PER_ENEMY;
-
-                break;
-            }
+        let Some(hit) = hit else {
+            continue;
+        };
+        if beam.damage == 0 {
+            continue;
         }
+        // 보스 부위는 몸통이 피해를 입음
+        let target = parts.get(hit.entity).map_or(hit.entity, |part| part.boss);
+        hits.write(Hit {
+            attacker: beam_entity,
+            target,
+            damage: beam.damage,
+        });
     }
 }
 
-/// 적과 플레이어의 충돌을 검사하는 시스템입니다.
+/// 충돌체끼리 닿은 것을 찾아 [`Hit`]을 보내는 시스템입니다.
 ///
-/// # 상태 전환
-/// 적이 플레이어에 닿으면 GameOver 상태로 전환합니다.
-/// NextState<AppState>를 사용하여 상태 전환을 요청합니다.
-fn check_enemy_player_collision(
-    mut next_state: ResMut<NextState<AppState>>,
-    player: Query<(&Transform, &CollisionRadius), With<Player>>,
-    enemies: Query<(&Transform, &CollisionRadius), With<Enemy>>,
+/// mask가 비어 있지 않은 충돌체마다 격자에서 mask의 층과 겹치는 충돌체를 찾습니다.
+/// [`PreviousPosition`]이 있는 원 충돌체는 이전 위치에서 현재 위치까지 쓸고 지나간 영역으로 찾습니다.
+/// 투사체는 가장 가까운 대상 하나에만 닿습니다 (같은 거리면 격자의 배치 순서에서 앞선 대상).
+/// 거리는 쓸고 지나갔으면 처음 닿기까지 움직인 거리, 아니면 도형 중심 사이 거리입니다.
+///
+/// 충돌 결과를 처리하는 시스템들은 이 시스템 뒤에 실행되어야 합니다.
+#[allow(clippy::type_complexity)]
+pub(crate) fn detect_contacts(
+    mut hits: MessageWriter<Hit>,
+    grid: Res<SpatialGrid>,
+    attackers: Query<
+        (
+            Entity,
+            &Transform,
+            &Collider,
+            Option<&Damage>,
+            Option<&PreviousPosition>,
+            Has<Projectile>,
+        ),
+        Without<Respawning>,
+    >,
+    invulnerable: Query<(), With<Invulnerable>>,
+    parts: Query<&BossPart>,
 ) {
-    // 플레이어가 없으면 조기 종료
-    let Ok((player_transform, player_radius)) = player.single() else {
-        return;
-    };
-
-    for (enemy_transform, enemy_radius) in enemies.iter() {
-        let distance = player_transform
-            .translation
-            .truncate()
-            .distance(enemy_transform.translation.truncate());
-
-        let collision_distance = player_radius.0 + enemy_radius.0;
-
-        if distance < collision_distance {
-            // 게임 오버! 상태 전환 요청
-            // NextState::set()으로 다음 프레임에 상태가 변경됩니다.
-            next_state.set(AppState::GameOver);
-            return;
+    for (attacker, transform, collider, damage, previous, is_projectile) in attackers.iter() {
+        if collider.mask.is_empty() {
+            continue;
         }
+
+        let damage = damage.map_or(0, |damage| damage.0);
+        // (대상, 가장 가까운 대상을 고를 때 쓰는 거리)
+        let candidates = match (collider.shape, previous) {
+            (ColliderShape::Circle { radius }, Some(previous)) => {
+                let position = transform.translation.truncate();
+                grid.query_sweep(previous.0, position, radius, collider.mask)
+            }
+            _ => {
+                let shape = Shape::from_collider(collider, transform);
+                let center = shape.bounding_circle().0;
+                grid.query_shape(&shape, collider.mask)
+                    .into_iter()
+                    .map(|entry| (entry, center.distance(entry.shape.bounding_circle().0)))
+                    .collect()
+            }
+        };
+        let targets = candidates
+            .into_iter()
+            .filter(|(entry, _)| entry.entity != attacker && !invulnerable.contains(entry.entity))
+            .map(|(entry, distance)| (entry.entity, distance));
+        let mut hit = |target: Entity| {
+            // 보스 부위는 몸통이 피해를 입음
+            let target = parts.get(target).map_or(target, |part| part.boss);
+            hits.write(Hit {
+                attacker,
+                target,
+                damage,
+            });
+        };
+
+        if is_projectile {
+            // 후보가 배치 순서이므로 거리가 같으면 앞선 대상이 남음
+            let closest = targets.reduce(|closest, candidate| {
+                if candidate.1 < closest.1 {
+                    candidate
+                } else {
+                    closest
+                }
+            });
+            if let Some((target, _)) = closest {
+                hit(target);
+            }
+        } else {
+            for (target, _) in targets {
+                hit(target);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::resources::ENEMY_COLLISION_RADIUS;
+
+    /// 격자를 다시 만들고 충돌을 찾아 이번 틱에 보낸 [`Hit`]을 반환합니다.
+    fn detect(world: &mut World) -> Vec<Hit> {
+        world.run_system_once(rebuild_spatial_grid).unwrap();
+        world.run_system_once(detect_contacts).unwrap();
+        world.resource_mut::<Messages<Hit>>().drain().collect()
+    }
+
+    fn empty_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Playfield>();
+        world.init_resource::<SpatialGrid>();
+        world.init_resource::<Messages<Hit>>();
+        world
+    }
+
+    fn spawn_enemy(world: &mut World, position: Vec2) -> Entity {
+        world
+            .spawn((
+                Transform::from_translation(position.extend(0.0)),
+                Collider::enemy(ColliderShape::Circle {
+                    radius: ENEMY_COLLISION_RADIUS,
+                }),
+            ))
+            .id()
+    }
+
+    fn spawn_bullet(world: &mut World, previous: Vec2, position: Vec2) -> Entity {
+        world
+            .spawn((
+                Projectile,
+                Damage(1),
+                PreviousPosition(previous),
+                Transform::from_translation(position.extend(0.0)),
+                Collider::player_bullet(ColliderShape::Circle { radius: 4.0 }),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn fast_projectile_hits_enemy_it_skipped_over() {
+        let mut world = empty_world();
+        let enemy = spawn_enemy(&mut world, Vec2::ZERO);
+        // 한 틱에 100픽셀: 이전 위치와 현재 위치 모두 적과 떨어져 있음
+        let bullet = spawn_bullet(&mut world, Vec2::new(0.0, -50.0), Vec2::new(0.0, 50.0));
+
+        assert_eq!(
+            detect(&mut world),
+            [Hit {
+                attacker: bullet,
+                target: enemy,
+                damage: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn fast_projectile_hits_first_enemy_on_its_path() {
+        let mut world = empty_world();
+        spawn_enemy(&mut world, Vec2::new(0.0, 40.0));
+        let near = spawn_enemy(&mut world, Vec2::new(0.0, -40.0));
+        let bullet = spawn_bullet(&mut world, Vec2::new(0.0, -100.0), Vec2::new(0.0, 100.0));
+
+        let hits = detect(&mut world);
+        assert_eq!(hits.len(), 1);
+        assert_eq!((hits[0].attacker, hits[0].target), (bullet, near));
+    }
+
+    #[test]
+    fn unmoved_projectile_only_hits_what_it_overlaps() {
+        let mut world = empty_world();
+        spawn_enemy(&mut world, Vec2::ZERO);
+        let position = Vec2::new(0.0, -30.0);
+        spawn_bullet(&mut world, position, position);
+        assert!(detect(&mut world).is_empty());
+
+        let mut world = empty_world();
+        let enemy = spawn_enemy(&mut world, Vec2::ZERO);
+        let position = Vec2::new(0.0, -20.0);
+        let bullet = spawn_bullet(&mut world, position, position);
+        let hits = detect(&mut world);
+        assert_eq!(hits.len(), 1);
+        assert_eq!((hits[0].attacker, hits[0].target), (bullet, enemy));
+    }
+
+    #[test]
+    fn projectile_starting_inside_enemy_still_hits() {
+        let mut world = empty_world();
+        let enemy = spawn_enemy(&mut world, Vec2::ZERO);
+        let bullet = spawn_bullet(&mut world, Vec2::new(0.0, -5.0), Vec2::new(0.0, 95.0));
+
+        let hits = detect(&mut world);
+        assert_eq!(hits.len(), 1);
+        assert_eq!((hits[0].attacker, hits[0].target), (bullet, enemy));
     }
 }
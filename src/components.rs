@@ -4,6 +4,7 @@
 //! 컴포넌트는 순수한 데이터 구조체로, 어떠한 동작(behavior)도 포함하지 않습니다.
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 // =============================================================================
 // 게임 엔티티 마커 컴포넌트
@@ -22,17 +23,319 @@ pub struct Projectile;
 #[derive(Component)]
 pub struct Enemy;
 
+/// 적 진영에 속함을 나타내는 마커 컴포넌트입니다.
+/// 이 마커가 붙은 투사체는 플레이어만 맞히고 적은 통과합니다.
+#[derive(Component)]
+pub struct Hostile;
+
+/// 적의 종류를 나타내는 컴포넌트입니다.
+/// 종류마다 스프라이트, 크기, 체력, 점수, 이동 방식이 다릅니다.
+#[derive(
+    Component, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum EnemyKind {
+    /// 똑바로 내려오는 기본 드론
+    Drone,
+    /// 좌우로 사인파를 그리며 내려오는 적
+    Weaver,
+    /// 화면 중앙 쪽으로 비스듬히 빠르게 내려오는 적
+    Diver,
+    /// 플레이어를 향해 방향을 틀며 돌진하는 적
+    Kamikaze,
+    /// 느리지만 크고 단단한 적
+    Bruiser,
+}
+
+impl EnemyKind {
+    /// 모든 적 종류 (스폰 테이블 순서)
+    pub const ALL: [EnemyKind; 5] = [
+        EnemyKind::Drone,
+        EnemyKind::Weaver,
+        EnemyKind::Diver,
+        EnemyKind::Kamikaze,
+        EnemyKind::Bruiser,
+    ];
+}
+
+/// 적의 사격 주기와 조준 여부를 저장하는 컴포넌트입니다.
+/// 타이머가 끝날 때마다 적 투사체를 한 발 발사합니다.
+#[derive(Component)]
+pub struct EnemyGun {
+    pub timer: Timer,
+    /// `true`면 플레이어를 조준하고, `false`면 똑바로 아래로 쏩니다.
+    pub aimed: bool,
+}
+
+/// 적이 떨어뜨린 파워업 아이템을 식별하는 마커 컴포넌트입니다.
+/// 아이템의 효과는 함께 붙은 [`PowerUpKind`]로 정해집니다.
+#[derive(Component)]
+pub struct Pickup;
+
+/// 파워업의 종류를 나타내는 컴포넌트입니다.
+#[derive(Component, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PowerUpKind {
+    /// 일정 시간 양옆으로 기울어진 탄을 함께 발사
+    SpreadShot,
+    /// 일정 시간 발사 속도 증가
+    RapidFire,
+    /// 일정 시간 피해를 받지 않는 방어막
+    Shield,
+    /// 목숨 하나 추가
+    ExtraLife,
+    /// 일정 시간 얻는 점수 배율 증가
+    ScoreMultiplier,
+    /// 화면의 적 탄과 보스를 뺀 적을 모두 제거
+    Bomb,
+    /// 무기 강화 단계 하나 올림
+    WeaponUp,
+}
+
+impl PowerUpKind {
+    /// 모든 파워업 종류 (HUD 표시 순서)
+    pub const ALL: [PowerUpKind; 7] = [
+        PowerUpKind::SpreadShot,
+        PowerUpKind::RapidFire,
+        PowerUpKind::Shield,
+        PowerUpKind::ExtraLife,
+        PowerUpKind::ScoreMultiplier,
+        PowerUpKind::Bomb,
+        PowerUpKind::WeaponUp,
+    ];
+}
+
+/// 플레이어 무기의 종류입니다.
+/// 종류마다 강화 단계별 발사 간격, 탄 수, 퍼짐, 탄속, 피해가 다릅니다.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WeaponKind {
+    /// 나란히 날아가는 여러 줄의 탄
+    Twin,
+    /// 부채꼴로 퍼지는 탄
+    Spread,
+    /// 빠르고 가는 광선
+    Laser,
+    /// 느리지만 강한 유도탄
+    Homing,
+}
+
+impl WeaponKind {
+    /// 모든 무기 종류 (무기 교체 순서)
+    pub const ALL: [WeaponKind; 4] = [
+        WeaponKind::Twin,
+        WeaponKind::Spread,
+        WeaponKind::Laser,
+        WeaponKind::Homing,
+    ];
+
+    /// 무기 교체 순서에서 다음 종류를 반환합니다.
+    pub fn next(self) -> WeaponKind {
+        let index = Self::ALL.iter().position(|kind| *kind == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// 격추 시 얻는 점수를 저장하는 컴포넌트입니다.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ScoreValue(pub u32);
+
 // =============================================================================
 // 물리/이동 컴포넌트
 // =============================================================================
 
-/// 이동 속도를 저장하는 컴포넌트입니다.
+/// 이동 속도(픽셀/초)를 저장하는 컴포넌트입니다.
+/// 이동 단계에서 위치에 더해집니다 (`kinematics.rs`).
 #[derive(Component)]
 pub struct Velocity(pub Vec2);
 
-/// 충돌 반경을 저장하는 컴포넌트입니다.
+/// 가속도(픽셀/초²)를 저장하는 컴포넌트입니다. 매 틱 [`Velocity`]에 더해집니다.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Acceleration(pub Vec2);
+
+/// 공기 저항처럼 속도를 줄이는 비율(1/초)을 저장하는 컴포넌트입니다.
+/// 매 틱 [`Velocity`]에 `e^(-비율 × 틱 간격)`을 곱하므로, 1초마다 속도가 `e^(-비율)`배가 됩니다.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Drag(pub f32);
+
+/// 최대 속력(픽셀/초)을 저장하는 컴포넌트입니다. 가속도와 저항을 적용한 뒤 [`Velocity`]를 이 속력으로 자릅니다.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct MaxSpeed(pub f32);
+
+/// 회전 속도(라디안/초, 반시계 방향)를 저장하는 컴포넌트입니다.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct AngularVelocity(pub f32);
+
+/// 스프라이트가 진행 방향을 향하게 하는 마커 컴포넌트입니다.
+/// 스프라이트의 위쪽(+Y)을 [`Velocity`] 방향으로 돌리며, 멈춰 있으면 방향을 유지합니다.
+/// [`AngularVelocity`]보다 우선합니다.
 #[derive(Component)]
-pub struct CollisionRadius(pub f32);
+pub struct FaceVelocity;
+
+/// 남은 수명을 저장하는 컴포넌트입니다.
+/// 타이머가 끝나면 엔티티가 제거되며, 화면 밖으로 나가도 수명이 다할 때까지 남아 있습니다.
+#[derive(Component)]
+pub struct Lifetime(pub Timer);
+
+// =============================================================================
+// 충돌 컴포넌트
+// =============================================================================
+// 충돌체는 도형과 층(layers), 닿는 층(mask)을 가집니다.
+// 공격자의 mask와 대상의 layers가 겹치면 공격자가 대상에 닿습니다 (collision.rs).
+// 어떤 층끼리 닿는지는 아래 `Collider`의 층별 생성 함수에서 정합니다.
+
+/// 충돌 층 비트 플래그입니다. 여러 층을 `|`로 합칠 수 있습니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CollisionLayers(pub u32);
+
+impl CollisionLayers {
+    pub const NONE: Self = Self(0);
+    pub const PLAYER: Self = Self(1 << 0);
+    pub const PLAYER_BULLET: Self = Self(1 << 1);
+    pub const ENEMY: Self = Self(1 << 2);
+    pub const ENEMY_BULLET: Self = Self(1 << 3);
+    pub const PICKUP: Self = Self(1 << 4);
+    pub const TERRAIN: Self = Self(1 << 5);
+
+    /// 두 플래그에 공통된 층이 있는지 반환합니다.
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    /// 층이 하나도 없는지 반환합니다.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl std::ops::BitOr for CollisionLayers {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// 충돌 도형입니다. 크기는 월드 좌표(픽셀) 기준이며 [`Transform`]의 크기 배율은 적용하지 않습니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColliderShape {
+    /// 원
+    Circle { radius: f32 },
+    /// 회전하지 않는 직사각형 (Transform의 회전을 무시)
+    Aabb { half_extents: Vec2 },
+    /// Transform과 함께 회전하는 직사각형
+    Obb { half_extents: Vec2 },
+    /// 로컬 Y축 방향 선분에서 `radius` 이내의 영역, Transform과 함께 회전
+    Capsule { half_length: f32, radius: f32 },
+}
+
+/// 충돌체 컴포넌트입니다.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Collider {
+    pub shape: ColliderShape,
+    /// 이 충돌체가 속한 층
+    pub layers: CollisionLayers,
+    /// 이 충돌체가 닿는 층 (비어 있으면 다른 충돌체에 닿기만 함)
+    pub mask: CollisionLayers,
+}
+
+impl Collider {
+    /// 플레이어: 아이템에 닿음 (줍기)
+    pub fn player(shape: ColliderShape) -> Self {
+        Self {
+            shape,
+            layers: CollisionLayers::PLAYER,
+            mask: CollisionLayers::PICKUP,
+        }
+    }
+
+    /// 플레이어 탄: 적과 지형에 닿음
+    pub fn player_bullet(shape: ColliderShape) -> Self {
+        Self {
+            shape,
+            layers: CollisionLayers::PLAYER_BULLET,
+            mask: CollisionLayers::ENEMY | CollisionLayers::TERRAIN,
+        }
+    }
+
+    /// 적 (보스 몸통과 부위 포함): 플레이어에 닿음 (접촉 피해)
+    pub fn enemy(shape: ColliderShape) -> Self {
+        Self {
+            shape,
+            layers: CollisionLayers::ENEMY,
+            mask: CollisionLayers::PLAYER,
+        }
+    }
+
+    /// 적 탄: 플레이어와 지형에 닿음
+    pub fn enemy_bullet(shape: ColliderShape) -> Self {
+        Self {
+            shape,
+            layers: CollisionLayers::ENEMY_BULLET,
+            mask: CollisionLayers::PLAYER | CollisionLayers::TERRAIN,
+        }
+    }
+
+    /// 아이템: 플레이어가 닿아 주움
+    pub fn pickup(shape: ColliderShape) -> Self {
+        Self {
+            shape,
+            layers: CollisionLayers::PICKUP,
+            mask: CollisionLayers::NONE,
+        }
+    }
+
+    /// 지형: 탄을 막음
+    pub fn terrain(shape: ColliderShape) -> Self {
+        Self {
+            shape,
+            layers: CollisionLayers::TERRAIN,
+            mask: CollisionLayers::NONE,
+        }
+    }
+}
+
+/// 이동하기 전(이전 틱)의 위치 컴포넌트입니다.
+///
+/// 원 충돌체에 붙이면 충돌 검사가 현재 위치만이 아니라 이전 위치에서 현재 위치까지 지나간 경로를 검사하므로,
+/// 한 틱에 대상보다 멀리 움직이는 빠른 투사체도 대상을 뚫고 지나가지 않습니다.
+/// 스폰할 때 스폰 위치로 넣고, 이동 시스템 직전에 갱신합니다.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct PreviousPosition(pub Vec2);
+
+// =============================================================================
+// 생존 컴포넌트
+// =============================================================================
+
+/// 체력을 저장하는 컴포넌트입니다.
+/// `current`가 0이 되면 사망합니다.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Health {
+    pub current: u32,
+    pub max: u32,
+}
+
+/// 충돌한 대상에게 주는 피해량을 저장하는 컴포넌트입니다.
+/// 투사체와 적(접촉 피해)이 가집니다.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Damage(pub u32);
+
+/// 남은 목숨 수를 저장하는 컴포넌트입니다. (현재 목숨 포함)
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Lives(pub u32);
+
+/// 사망 후 부활을 기다리는 중임을 나타내는 컴포넌트입니다.
+/// 타이머가 끝나면 시작 위치에서 부활합니다.
+/// 이 컴포넌트가 있는 동안에는 이동, 발사, 충돌이 모두 무시됩니다.
+#[derive(Component)]
+pub struct Respawning(pub Timer);
+
+/// 피격 직후 스프라이트가 하얗게 번쩍이는 시간을 저장하는 컴포넌트입니다.
+/// 다시 맞으면 새 타이머로 교체됩니다.
+#[derive(Component)]
+pub struct HitFlash(pub Timer);
+
+/// 무적 상태를 나타내는 컴포넌트입니다.
+/// 타이머가 끝날 때까지 피해를 받지 않으며, 스프라이트가 깜빡입니다.
+#[derive(Component)]
+pub struct Invulnerable(pub Timer);
 
 // =============================================================================
 // UI 마커 컴포넌트
@@ -52,11 +355,55 @@ pub struct InGameUI;
 #[derive(Component)]
 pub struct GameOverUI;
 
+/// 일시정지 메뉴의 루트 엔티티를 식별하는 마커입니다.
+#[derive(Component)]
+pub struct PauseMenuUI;
+
+/// 리더보드 화면의 루트 엔티티를 식별하는 마커입니다.
+#[derive(Component)]
+pub struct LeaderboardUI;
+
+/// 리플레이 재생 중 표시되는 안내 텍스트를 식별하는 마커입니다.
+#[derive(Component)]
+pub struct ReplayBanner;
+
 /// 점수 텍스트를 식별하는 마커입니다.
 /// 점수 업데이트 시스템에서 이 컴포넌트로 텍스트 엔티티를 찾습니다.
 #[derive(Component)]
 pub struct ScoreText;
 
+/// 남은 목숨과 체력 텍스트를 식별하는 마커입니다.
+#[derive(Component)]
+pub struct LivesText;
+
+/// 난이도 레벨 텍스트를 식별하는 마커입니다.
+#[derive(Component)]
+pub struct LevelText;
+
+/// 스테이지 안내 문구 텍스트를 식별하는 마커입니다.
+#[derive(Component)]
+pub struct StageMessageText;
+
+/// 보스 체력 바 전체(테두리와 배경)를 식별하는 마커입니다.
+#[derive(Component)]
+pub struct BossHealthBar;
+
+/// 보스 체력 바의 남은 체력 부분을 식별하는 마커입니다.
+#[derive(Component)]
+pub struct BossHealthFill;
+
+/// 적용 중인 파워업 목록 텍스트를 식별하는 마커입니다.
+#[derive(Component)]
+pub struct PowerUpText;
+
+/// 현재 무기와 강화 단계 텍스트를 식별하는 마커입니다.
+#[derive(Component)]
+pub struct WeaponText;
+
+/// 디버그 오버레이 텍스트를 식별하는 마커입니다.
+#[derive(Component)]
+pub struct DebugOverlayText;
+
 /// 신기록 축하 텍스트를 식별하는 마커입니다.
 /// 펄스 애니메이션을 적용할 때 사용합니다.
 #[derive(Component)]
@@ -70,10 +417,16 @@ pub struct CursorBlink;
 /// 버튼 클릭 시 어떤 동작을 수행할지 결정합니다.
 #[derive(Component, Clone, Copy)]
 pub enum ButtonAction {
-    /// 게임 재시작 (GameOver → InGame)
+    /// 게임 재시작 (GameOver/일시정지 → InGame)
     RestartGame,
-    /// 메인 메뉴로 돌아가기 (GameOver → MainMenu)
+    /// 메인 메뉴로 돌아가기 (GameOver/Leaderboard/일시정지 → MainMenu)
     MainMenu,
+    /// 일시정지 해제 (Paused → Running)
+    Resume,
+    /// 방금 끝난 판의 리플레이를 파일로 저장
+    SaveReplay,
+    /// 리더보드 화면 열기 (GameOver → Leaderboard)
+    Leaderboard,
 }
 
 // =============================================================================
@@ -0,0 +1,1541 @@
+//! 게임 설정 모듈
+//!
+//! 밸런스 수치를 `assets/config/game.ron` 파일에서 읽어 [`GameConfig`] 리소스로 제공합니다.
+//! 게임 실행 중 파일을 수정하면 자동으로 다시 읽어 적용합니다 (핫 리로드, `dev` 기능 필요).
+//! 파일이 없거나 잘못된 경우에는 `resources.rs`의 기본 상수를 그대로 사용합니다.
+//!
+//! # 설정 파일 예시
+//! ```text
+//! (
+//!     player_speed: 300.0,
+//!     enemy_spawn_interval: 1.0,
+//!     difficulty: (level_duration: 30.0, spawn_rate: [(0.0, 1.0), (300.0, 2.5)]),
+//!     enemies: (
+//!         drone: (speed: 150.0, collision_radius: 18.0, scale: 0.5,
+//!                 max_health: 1, score: 100, spawn_weight: 6),
+//!         // ...
+//!     ),
+//! )
+//! ```
+//! 빠진 항목은 기본값으로 채워집니다.
+
+use std::fmt;
+
+use bevy::{
+    asset::{io::Reader, AssetLoadFailedEvent, AssetLoader, LoadContext},
+    ecs::message::MessageReader,
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::components::{EnemyKind, PowerUpKind, WeaponKind};
+use crate::resources::{
+    Difficulty, EnemySpawnTimer, ENEMY_COLLISION_RADIUS, ENEMY_CONTACT_DAMAGE, ENEMY_MAX_HEALTH,
+    ENEMY_PROJECTILE_COLLISION_RADIUS, ENEMY_PROJECTILE_DAMAGE, ENEMY_PROJECTILE_SCALE,
+    ENEMY_PROJECTILE_SPEED, ENEMY_SCALE, ENEMY_SPAWN_INTERVAL, ENEMY_SPEED,
+    PLAYER_COLLISION_RADIUS, PLAYER_INVULNERABILITY_DURATION, PLAYER_LIVES, PLAYER_MAX_HEALTH,
+    PLAYER_RESPAWN_DELAY, PLAYER_SCALE, PLAYER_SPEED, PROJECTILE_COLLISION_RADIUS,
+    PROJECTILE_SCALE, SCORE_PER_ENEMY,
+};
+
+/// 게임 설정 파일 경로 (assets/ 기준)
+pub const GAME_CONFIG_PATH: &str = "config/game.ron";
+
+// =============================================================================
+// 게임 설정
+// =============================================================================
+
+/// 게임 밸런스 수치를 저장하는 리소스이자 에셋입니다.
+///
+/// 게임플레이 시스템은 상수 대신 이 리소스에서 값을 읽습니다.
+/// 기본값은 `resources.rs`의 상수와 같습니다.
+#[derive(Asset, Resource, TypePath, Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct GameConfig {
+    // 플레이어
+    pub player_speed: f32,
+    pub player_collision_radius: f32,
+    pub player_scale: f32,
+    pub player_max_health: u32,
+    pub player_lives: u32,
+    pub player_respawn_delay: f32,
+    pub player_invulnerability_duration: f32,
+
+    // 플레이어 무기
+    /// 판을 시작할 때 든 무기
+    pub player_weapon: WeaponKind,
+    pub weapons: WeaponTable,
+    /// 보조 무기 (유도 미사일)
+    pub missiles: MissileSettings,
+
+    // 투사체 (탄속과 피해는 무기 설정에서 정함)
+    pub projectile_collision_radius: f32,
+    pub projectile_scale: f32,
+
+    // 적
+    pub enemy_spawn_interval: f32,
+    pub enemy_contact_damage: u32,
+    pub enemies: EnemyTable,
+
+    // 무한 모드 난이도
+    pub difficulty: DifficultySettings,
+
+    // 숨은 랭크
+    pub rank: RankSettings,
+
+    // 보스
+    pub boss: BossSettings,
+
+    // 파워업
+    pub powerups: PowerUpSettings,
+
+    // 스테이지
+    /// 순서대로 진행할 스테이지 파일 경로 (assets/ 기준, 모두 마치면 무작위 스폰)
+    pub stages: Vec<String>,
+
+    // 적 투사체
+    pub enemy_projectile_speed: f32,
+    pub enemy_projectile_collision_radius: f32,
+    pub enemy_projectile_scale: f32,
+    pub enemy_projectile_damage: u32,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            player_speed: PLAYER_SPEED,
+            player_collision_radius: PLAYER_COLLISION_RADIUS,
+            player_scale: PLAYER_SCALE,
+            player_max_health: PLAYER_MAX_HEALTH,
+            player_lives: PLAYER_LIVES,
+            player_respawn_delay: PLAYER_RESPAWN_DELAY,
+            player_invulnerability_duration: PLAYER_INVULNERABILITY_DURATION,
+            player_weapon: WeaponKind::Twin,
+            weapons: WeaponTable::default(),
+            missiles: MissileSettings::default(),
+            projectile_collision_radius: PROJECTILE_COLLISION_RADIUS,
+            projectile_scale: PROJECTILE_SCALE,
+            enemy_spawn_interval: ENEMY_SPAWN_INTERVAL,
+            enemy_contact_damage: ENEMY_CONTACT_DAMAGE,
+            enemies: EnemyTable::default(),
+            difficulty: DifficultySettings::default(),
+            rank: RankSettings::default(),
+            boss: BossSettings::default(),
+            powerups: PowerUpSettings::default(),
+            stages: vec!["stages/stage1.stage.ron".to_string()],
+            enemy_projectile_speed: ENEMY_PROJECTILE_SPEED,
+            enemy_projectile_collision_radius: ENEMY_PROJECTILE_COLLISION_RADIUS,
+            enemy_projectile_scale: ENEMY_PROJECTILE_SCALE,
+            enemy_projectile_damage: ENEMY_PROJECTILE_DAMAGE,
+        }
+    }
+}
+
+/// 적 종류 하나의 능력치입니다.
+///
+/// 종류별로 모든 항목을 적어야 합니다.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct EnemyStats {
+    pub speed: f32,
+    pub collision_radius: f32,
+    pub scale: f32,
+    pub max_health: u32,
+    /// 격추 시 얻는 점수
+    pub score: u32,
+    /// 스폰 가중치 (0이면 스폰되지 않음)
+    pub spawn_weight: u32,
+    /// 무한 모드에서 이 레벨부터 등장 (생략하면 처음부터)
+    #[serde(default)]
+    pub min_level: u32,
+    /// 사격 설정 (생략하면 쏘지 않음)
+    #[serde(default)]
+    pub fire: Option<EnemyFire>,
+    /// 탄막 패턴 파일 경로 (assets/ 기준, 생략하면 패턴 없음)
+    #[serde(default)]
+    pub pattern: Option<String>,
+    /// 격추 시 파워업을 떨어뜨릴 확률표 (생략하면 떨어뜨리지 않음)
+    #[serde(default)]
+    pub drops: Vec<PowerUpDrop>,
+}
+
+impl EnemyStats {
+    /// `0.0..1.0` 범위의 `roll`로 확률표에서 떨어뜨릴 파워업을 고릅니다.
+    ///
+    /// 확률표 순서대로 확률을 누적하며, 합을 넘으면 아무것도 떨어뜨리지 않습니다.
+    pub fn pick_drop(&self, mut roll: f32) -> Option<PowerUpKind> {
+        for drop in &self.drops {
+            if roll < drop.chance {
+                return Some(drop.kind);
+            }
+            roll -= drop.chance;
+        }
+        None
+    }
+}
+
+/// 적이 격추될 때 파워업 하나를 떨어뜨릴 확률입니다.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct PowerUpDrop {
+    pub kind: PowerUpKind,
+    /// 떨어뜨릴 확률 (0.0 ~ 1.0)
+    pub chance: f32,
+}
+
+/// 적 종류의 사격 설정입니다.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct EnemyFire {
+    /// 사격 간격 (초)
+    pub interval: f32,
+    /// `true`면 플레이어를 조준, `false`면 똑바로 아래로 발사
+    pub aimed: bool,
+}
+
+/// 스테이지 끝에 나오는 보스 설정입니다.
+///
+/// 보스는 몸통과 [`BossPartSettings`] 부위로 이루어진 큰 판정을 가지며,
+/// 남은 체력 비율에 따라 [`BossPhaseSettings`] 단계를 차례로 진행합니다.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct BossSettings {
+    pub max_health: u32,
+    /// 격파 시 얻는 점수
+    pub score: u32,
+    pub scale: f32,
+    /// 몸통 판정 반지름
+    pub collision_radius: f32,
+    /// 몸통에 붙은 부위 판정 (맞히면 몸통 체력이 깎임)
+    pub parts: Vec<BossPartSettings>,
+    /// 등장 후 멈추는 위치 (화면 위쪽 끝에서 아래로, 픽셀)
+    pub entrance_depth: f32,
+    /// 등장 연출 시간 (초, 이동 중에는 피해를 받지 않음)
+    pub entrance_duration: f32,
+    /// 싸우는 동안 좌우로 움직이는 폭 (픽셀)
+    pub sway_amplitude: f32,
+    /// 좌우 왕복 한 번의 시간 (초)
+    pub sway_period: f32,
+    /// 제한 시간 (초, 넘기면 보스가 화면 위로 퇴장하고 점수 없음)
+    pub time_limit: f32,
+    /// 격파 연출 시간 (초)
+    pub defeat_duration: f32,
+    /// 퇴장 속도 (픽셀/초)
+    pub exit_speed: f32,
+    /// 공격 단계 (남은 체력 비율이 큰 순서)
+    pub phases: Vec<BossPhaseSettings>,
+}
+
+impl Default for BossSettings {
+    fn default() -> Self {
+        Self {
+            max_health: 300,
+            score: 20000,
+            scale: 0.9,
+            collision_radius: 48.0,
+            parts: vec![
+                BossPartSettings {
+                    offset: (-60.0, 5.0),
+                    collision_radius: 30.0,
+                },
+                BossPartSettings {
+                    offset: (60.0, 5.0),
+                    collision_radius: 30.0,
+                },
+            ],
+            entrance_depth: 160.0,
+            entrance_duration: 3.0,
+            sway_amplitude: 120.0,
+            sway_period: 6.0,
+            time_limit: 90.0,
+            defeat_duration: 2.0,
+            exit_speed: 200.0,
+            phases: vec![
+                BossPhaseSettings {
+                    health: 1.0,
+                    pattern: Some("patterns/boss_phase1.pattern.ron".to_string()),
+                },
+                BossPhaseSettings {
+                    health: 0.6,
+                    pattern: Some("patterns/boss_phase2.pattern.ron".to_string()),
+                },
+                BossPhaseSettings {
+                    health: 0.3,
+                    pattern: Some("patterns/boss_phase3.pattern.ron".to_string()),
+                },
+            ],
+        }
+    }
+}
+
+impl BossSettings {
+    /// 남은 체력 비율에 맞는 공격 단계 번호를 반환합니다.
+    ///
+    /// 시작 비율이 `fraction` 이상인 단계 중 마지막 단계입니다.
+    pub fn phase_at(&self, fraction: f32) -> usize {
+        self.phases
+            .iter()
+            .rposition(|phase| phase.health >= fraction)
+            .unwrap_or(0)
+    }
+
+    /// 단계별 탄막 패턴 파일 경로를 반환합니다.
+    pub fn pattern_paths(&self) -> impl Iterator<Item = &str> {
+        self.phases
+            .iter()
+            .filter_map(|phase| phase.pattern.as_deref())
+    }
+
+    /// 보스 설정의 문제를 `problems`에 추가합니다.
+    fn validate(&self, problems: &mut Vec<String>) {
+        if self.max_health == 0 {
+            problems.push("boss.max_health: 1 이상이어야 합니다 (현재 0)".to_string());
+        }
+        let positive = [
+            ("scale", self.scale),
+            ("collision_radius", self.collision_radius),
+            ("entrance_duration", self.entrance_duration),
+            ("sway_period", self.sway_period),
+            ("time_limit", self.time_limit),
+            ("defeat_duration", self.defeat_duration),
+            ("exit_speed", self.exit_speed),
+        ];
+        for (name, value) in positive {
+            if !(value.is_finite() && value > 0.0) {
+                problems.push(format!(
+                    "boss.{name}: 0보다 큰 값이어야 합니다 (현재 {value})"
+                ));
+            }
+        }
+        for (index, part) in self.parts.iter().enumerate() {
+            if !(part.collision_radius.is_finite() && part.collision_radius > 0.0) {
+                problems.push(format!(
+                    "boss.parts[{index}].collision_radius: 0보다 큰 값이어야 합니다 (현재 {})",
+                    part.collision_radius
+                ));
+            }
+        }
+
+        if self.phases.is_empty() {
+            problems.push("boss.phases: 단계가 하나 이상 있어야 합니다".to_string());
+        }
+        for (index, phase) in self.phases.iter().enumerate() {
+            let previous = index.checked_sub(1).map(|i| self.phases[i].health);
+            if !(phase.health > 0.0 && phase.health <= 1.0)
+                || previous.is_some_and(|previous| phase.health >= previous)
+            {
+                problems.push(format!(
+                    "boss.phases[{index}].health: 0 ~ 1 사이이고 앞 단계보다 작아야 합니다 (현재 {})",
+                    phase.health
+                ));
+            }
+        }
+    }
+}
+
+/// 보스 몸통에 붙은 부위 판정 하나입니다.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct BossPartSettings {
+    /// 몸통 중심에서의 위치 (픽셀)
+    pub offset: (f32, f32),
+    pub collision_radius: f32,
+}
+
+/// 보스의 공격 단계 하나입니다.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct BossPhaseSettings {
+    /// 이 단계가 시작되는 남은 체력 비율 (첫 단계는 1.0)
+    pub health: f32,
+    /// 탄막 패턴 파일 경로 (assets/ 기준, 생략하면 쏘지 않음)
+    #[serde(default)]
+    pub pattern: Option<String>,
+}
+
+/// 적이 떨어뜨리는 파워업 아이템과 효과 설정입니다.
+///
+/// 떨어뜨릴 확률은 적 종류별 `drops` 확률표에서 정합니다.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct PowerUpSettings {
+    /// 아이템이 내려오는 속도 (픽셀/초)
+    pub fall_speed: f32,
+    pub collision_radius: f32,
+    pub scale: f32,
+    /// 확산탄 지속 시간 (초)
+    pub spread_shot_duration: f32,
+    /// 확산탄 양옆 탄의 각도 (도)
+    pub spread_shot_angle: f32,
+    /// 연사 지속 시간 (초)
+    pub rapid_fire_duration: f32,
+    /// 연사 중 발사 속도 배율 (발사 간격을 이 값으로 나눔)
+    pub rapid_fire_rate: f32,
+    /// 방어막 지속 시간 (초)
+    pub shield_duration: f32,
+    /// 점수 배율 지속 시간 (초)
+    pub score_multiplier_duration: f32,
+    /// 점수 배율
+    pub score_multiplier: u32,
+    /// 폭탄이 보스에게 주는 피해 (보스를 격파하지는 못함)
+    pub bomb_boss_damage: u32,
+}
+
+impl Default for PowerUpSettings {
+    fn default() -> Self {
+        Self {
+            fall_speed: 90.0,
+            collision_radius: 16.0,
+            scale: 0.9,
+            spread_shot_duration: 10.0,
+            spread_shot_angle: 12.0,
+            rapid_fire_duration: 10.0,
+            rapid_fire_rate: 2.0,
+            shield_duration: 6.0,
+            score_multiplier_duration: 15.0,
+            score_multiplier: 2,
+            bomb_boss_damage: 20,
+        }
+    }
+}
+
+impl PowerUpSettings {
+    /// 지속 효과의 지속 시간을 반환합니다. 즉시 효과(목숨 추가, 폭탄, 무기 강화)는 `None`입니다.
+    pub fn duration(&self, kind: PowerUpKind) -> Option<f32> {
+        match kind {
+            PowerUpKind::SpreadShot => Some(self.spread_shot_duration),
+            PowerUpKind::RapidFire => Some(self.rapid_fire_duration),
+            PowerUpKind::Shield => Some(self.shield_duration),
+            PowerUpKind::ScoreMultiplier => Some(self.score_multiplier_duration),
+            PowerUpKind::ExtraLife | PowerUpKind::Bomb | PowerUpKind::WeaponUp => None,
+        }
+    }
+
+    /// 파워업 설정의 문제를 `problems`에 추가합니다.
+    fn validate(&self, problems: &mut Vec<String>) {
+        let positive = [
+            ("fall_speed", self.fall_speed),
+            ("collision_radius", self.collision_radius),
+            ("scale", self.scale),
+            ("spread_shot_duration", self.spread_shot_duration),
+            ("rapid_fire_duration", self.rapid_fire_duration),
+            ("rapid_fire_rate", self.rapid_fire_rate),
+            ("shield_duration", self.shield_duration),
+            ("score_multiplier_duration", self.score_multiplier_duration),
+        ];
+        for (name, value) in positive {
+            if !(value.is_finite() && value > 0.0) {
+                problems.push(format!(
+                    "powerups.{name}: 0보다 큰 값이어야 합니다 (현재 {value})"
+                ));
+            }
+        }
+        if !(self.spread_shot_angle.is_finite() && self.spread_shot_angle >= 0.0) {
+            problems.push(format!(
+                "powerups.spread_shot_angle: 0 이상이어야 합니다 (현재 {})",
+                self.spread_shot_angle
+            ));
+        }
+        if self.score_multiplier == 0 {
+            problems.push("powerups.score_multiplier: 1 이상이어야 합니다 (현재 0)".to_string());
+        }
+    }
+}
+
+/// 무기 종류별 강화 단계 표입니다.
+///
+/// 빠진 종류는 기본값을 사용합니다.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct WeaponTable {
+    pub twin: WeaponSettings,
+    pub spread: WeaponSettings,
+    pub laser: WeaponSettings,
+    pub homing: WeaponSettings,
+}
+
+impl Default for WeaponTable {
+    fn default() -> Self {
+        Self {
+            twin: WeaponSettings::new(&[
+                (0.2, 2, 16.0, 0.0, 550.0, 1),
+                (0.16, 2, 16.0, 0.0, 600.0, 1),
+                (0.16, 3, 14.0, 4.0, 600.0, 1),
+                (0.13, 4, 12.0, 6.0, 650.0, 1),
+            ]),
+            spread: WeaponSettings::new(&[
+                (0.25, 3, 0.0, 24.0, 500.0, 1),
+                (0.22, 5, 0.0, 36.0, 500.0, 1),
+                (0.2, 5, 0.0, 44.0, 550.0, 1),
+                (0.18, 7, 0.0, 56.0, 550.0, 1),
+            ]),
+            laser: WeaponSettings::new(&[
+                (0.12, 1, 0.0, 0.0, 1000.0, 1),
+                (0.1, 1, 0.0, 0.0, 1000.0, 1),
+                (0.1, 2, 14.0, 0.0, 1000.0, 1),
+                (0.08, 2, 14.0, 0.0, 1000.0, 1),
+            ]),
+            homing: WeaponSettings::new(&[
+                (0.4, 2, 28.0, 20.0, 350.0, 2),
+                (0.35, 2, 28.0, 20.0, 380.0, 2),
+                (0.35, 4, 20.0, 40.0, 380.0, 2),
+                (0.3, 4, 20.0, 40.0, 420.0, 3),
+            ]),
+        }
+    }
+}
+
+impl WeaponTable {
+    /// 무기 종류의 설정을 반환합니다.
+    pub fn get(&self, kind: WeaponKind) -> &WeaponSettings {
+        match kind {
+            WeaponKind::Twin => &self.twin,
+            WeaponKind::Spread => &self.spread,
+            WeaponKind::Laser => &self.laser,
+            WeaponKind::Homing => &self.homing,
+        }
+    }
+
+    /// 무기 설정의 문제를 `problems`에 추가합니다.
+    fn validate(&self, problems: &mut Vec<String>) {
+        let named = [
+            ("twin", &self.twin),
+            ("spread", &self.spread),
+            ("laser", &self.laser),
+            ("homing", &self.homing),
+        ];
+        for (kind_name, settings) in named {
+            if settings.levels.is_empty() {
+                problems.push(format!(
+                    "weapons.{kind_name}.levels: 단계가 하나 이상 있어야 합니다"
+                ));
+            }
+            for (index, level) in settings.levels.iter().enumerate() {
+                level.validate(&format!("weapons.{kind_name}.levels[{index}]"), problems);
+            }
+        }
+    }
+}
+
+/// 무기 종류 하나의 강화 단계 목록입니다.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct WeaponSettings {
+    /// 강화 단계별 능력치 (첫 항목이 강화 전)
+    pub levels: Vec<WeaponLevel>,
+}
+
+impl WeaponSettings {
+    /// `(발사 간격, 탄 수, 탄 간격, 퍼짐 각도, 탄속, 피해)` 목록으로 설정을 만듭니다.
+    fn new(levels: &[(f32, u32, f32, f32, f32, u32)]) -> Self {
+        Self {
+            levels: levels
+                .iter()
+                .map(
+                    |&(interval, count, spacing, spread, speed, damage)| WeaponLevel {
+                        interval,
+                        count,
+                        spacing,
+                        spread,
+                        speed,
+                        damage,
+                    },
+                )
+                .collect(),
+        }
+    }
+
+    /// 가장 높은 강화 단계 (0부터)
+    pub fn max_level(&self) -> u32 {
+        self.levels.len().saturating_sub(1) as u32
+    }
+
+    /// `level` 단계의 능력치를 반환합니다. 최고 단계를 넘으면 최고 단계의 값입니다.
+    ///
+    /// 검증된 설정에는 단계가 항상 하나 이상 있습니다.
+    pub fn level(&self, level: u32) -> &WeaponLevel {
+        &self.levels[(level as usize).min(self.levels.len() - 1)]
+    }
+}
+
+/// 무기 강화 단계 하나의 능력치입니다.
+///
+/// 한 번 발사할 때 `count`발을 좌우로 `spacing` 픽셀씩 벌려 나란히 놓고,
+/// 양 끝 탄 사이가 `spread`도가 되도록 부채꼴로 기울여 쏩니다.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct WeaponLevel {
+    /// 발사 간격 (초, 레이저는 피해를 주는 간격)
+    pub interval: f32,
+    /// 한 번에 쏘는 탄 수
+    pub count: u32,
+    /// 나란한 탄 사이 간격 (픽셀)
+    #[serde(default)]
+    pub spacing: f32,
+    /// 부채꼴 전체 각도 (도)
+    #[serde(default)]
+    pub spread: f32,
+    /// 탄속 (픽셀/초, 레이저는 쓰지 않음)
+    pub speed: f32,
+    /// 탄 하나가 주는 피해
+    pub damage: u32,
+}
+
+impl WeaponLevel {
+    /// 한 번 발사할 때 각 탄의 `(가로 위치, 각도(도))`를 왼쪽 탄부터 반환합니다.
+    ///
+    /// 양수 각도는 오른쪽으로 기울어진 방향입니다.
+    pub fn volley(&self) -> impl Iterator<Item = (f32, f32)> + '_ {
+        let last = self.count.saturating_sub(1) as f32;
+        (0..self.count).map(move |index| {
+            // -0.5 ~ 0.5 (한 발이면 가운데)
+            let t = if last > 0.0 {
+                index as f32 / last - 0.5
+            } else {
+                0.0
+            };
+            (t * self.spacing * last, t * self.spread)
+        })
+    }
+
+    /// 능력치의 문제를 `problems`에 추가합니다. `path`는 오류 메시지에 쓰는 항목 이름입니다.
+    fn validate(&self, path: &str, problems: &mut Vec<String>) {
+        let positive = [("interval", self.interval), ("speed", self.speed)];
+        for (name, value) in positive {
+            if !(value.is_finite() && value > 0.0) {
+                problems.push(format!(
+                    "{path}.{name}: 0보다 큰 값이어야 합니다 (현재 {value})"
+                ));
+            }
+        }
+        let non_negative = [("spacing", self.spacing), ("spread", self.spread)];
+        for (name, value) in non_negative {
+            if !(value.is_finite() && value >= 0.0) {
+                problems.push(format!("{path}.{name}: 0 이상이어야 합니다 (현재 {value})"));
+            }
+        }
+        let counts = [("count", self.count), ("damage", self.damage)];
+        for (name, value) in counts {
+            if value == 0 {
+                problems.push(format!("{path}.{name}: 1 이상이어야 합니다 (현재 0)"));
+            }
+        }
+    }
+}
+
+/// 보조 무기(유도 미사일 발사기)와 유도탄의 설정입니다.
+///
+/// 유도탄의 회전 속도와 수명은 유도 무기(`weapons.homing`)의 탄에도 똑같이 적용됩니다.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct MissileSettings {
+    /// 발사기 능력치 (발사 키를 누르고 있는 동안 주 무기와 함께 발사)
+    pub launcher: WeaponLevel,
+    /// 유도탄이 1초에 돌 수 있는 최대 각도 (도)
+    pub turn_rate: f32,
+    /// 유도탄 수명 (초)
+    pub lifetime: f32,
+}
+
+impl Default for MissileSettings {
+    fn default() -> Self {
+        Self {
+            launcher: WeaponLevel {
+                interval: 1.2,
+                count: 2,
+                spacing: 40.0,
+                spread: 90.0,
+                speed: 320.0,
+                damage: 1,
+            },
+            turn_rate: 240.0,
+            lifetime: 3.0,
+        }
+    }
+}
+
+impl MissileSettings {
+    /// 미사일 설정의 문제를 `problems`에 추가합니다.
+    fn validate(&self, problems: &mut Vec<String>) {
+        self.launcher.validate("missiles.launcher", problems);
+        let positive = [("turn_rate", self.turn_rate), ("lifetime", self.lifetime)];
+        for (name, value) in positive {
+            if !(value.is_finite() && value > 0.0) {
+                problems.push(format!(
+                    "missiles.{name}: 0보다 큰 값이어야 합니다 (현재 {value})"
+                ));
+            }
+        }
+    }
+}
+
+/// 무한 모드 난이도 곡선 설정입니다.
+///
+/// 곡선은 `(경과 시간(초), 배율)` 점의 목록이며, 점 사이는 선형으로 잇고
+/// 첫 점 이전과 마지막 점 이후는 끝 값을 유지합니다.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct DifficultySettings {
+    /// 레벨 하나의 길이 (초)
+    pub level_duration: f32,
+    /// 최대 레벨
+    pub max_level: u32,
+    /// 적 스폰 빈도 배율 곡선
+    pub spawn_rate: DifficultyCurve,
+    /// 적 이동 속도 배율 곡선
+    pub enemy_speed: DifficultyCurve,
+    /// 적 사격 빈도 배율 곡선
+    pub fire_rate: DifficultyCurve,
+}
+
+impl Default for DifficultySettings {
+    fn default() -> Self {
+        Self {
+            level_duration: 30.0,
+            max_level: 20,
+            spawn_rate: DifficultyCurve(vec![(0.0, 1.0), (300.0, 2.5)]),
+            enemy_speed: DifficultyCurve(vec![(0.0, 1.0), (300.0, 1.5)]),
+            fire_rate: DifficultyCurve(vec![(0.0, 1.0), (300.0, 2.0)]),
+        }
+    }
+}
+
+impl DifficultySettings {
+    /// 곡선이 `elapsed`초 진행되었을 때의 난이도를 계산합니다.
+    pub fn sample(&self, elapsed: f32) -> Difficulty {
+        let level = (elapsed / self.level_duration) as u32 + 1;
+
+        Difficulty {
+            elapsed,
+            level: level.min(self.max_level),
+            spawn_rate: self.spawn_rate.sample(elapsed),
+            enemy_speed: self.enemy_speed.sample(elapsed),
+            fire_rate: self.fire_rate.sample(elapsed),
+            ..default()
+        }
+    }
+
+    /// 난이도 설정의 문제를 `problems`에 추가합니다.
+    fn validate(&self, problems: &mut Vec<String>) {
+        if !(self.level_duration.is_finite() && self.level_duration > 0.0) {
+            problems.push(format!(
+                "difficulty.level_duration: 0보다 큰 값이어야 합니다 (현재 {})",
+                self.level_duration
+            ));
+        }
+        if self.max_level == 0 {
+            problems.push("difficulty.max_level: 1 이상이어야 합니다 (현재 0)".to_string());
+        }
+
+        let curves = [
+            ("spawn_rate", &self.spawn_rate),
+            ("enemy_speed", &self.enemy_speed),
+            ("fire_rate", &self.fire_rate),
+        ];
+        for (name, curve) in curves {
+            if curve.0.is_empty() {
+                problems.push(format!("difficulty.{name}: 점이 하나 이상 있어야 합니다"));
+            }
+            for (index, &(time, value)) in curve.0.iter().enumerate() {
+                if !(value.is_finite() && value > 0.0) {
+                    problems.push(format!(
+                        "difficulty.{name}[{index}]: 배율은 0보다 커야 합니다 (현재 {value})"
+                    ));
+                }
+                let previous = index.checked_sub(1).map(|i| curve.0[i].0);
+                if !time.is_finite() || previous.is_some_and(|previous| time < previous) {
+                    problems.push(format!(
+                        "difficulty.{name}[{index}]: 시간은 유한하고 앞 점보다 작지 않아야 합니다 (현재 {time})"
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// 숨은 랭크(`rank.rs`)의 변화량과 효과 설정입니다.
+///
+/// 효과 배율은 랭크 1.0일 때 더해지는 값이며, 랭크에 비례해 적용됩니다.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct RankSettings {
+    /// 판 시작 랭크
+    pub initial: f32,
+    /// 격추 1회당 오르는 랭크
+    pub kill_gain: f32,
+    /// 명중 1회당 오르는 랭크
+    pub hit_gain: f32,
+    /// 발사 1회당 내리는 랭크
+    pub shot_cost: f32,
+    /// 무피격 상태에서 1초당 오르는 최대 랭크
+    pub streak_gain: f32,
+    /// 무피격 상승 속도가 최대가 되기까지의 시간 (초)
+    pub streak_ramp: f32,
+    /// 사망 1회당 내리는 랭크
+    pub death_penalty: f32,
+    /// 랭크 1.0일 때 추가되는 스폰 빈도 배율
+    pub spawn_rate_bonus: f32,
+    /// 랭크 1.0일 때 추가되는 적 체력 배율
+    pub enemy_health_bonus: f32,
+    /// 랭크 1.0일 때 추가되는 적 투사체 속도 배율
+    pub bullet_speed_bonus: f32,
+}
+
+impl Default for RankSettings {
+    fn default() -> Self {
+        Self {
+            initial: 0.0,
+            kill_gain: 0.01,
+            hit_gain: 0.003,
+            shot_cost: 0.001,
+            streak_gain: 0.004,
+            streak_ramp: 30.0,
+            death_penalty: 0.25,
+            spawn_rate_bonus: 0.5,
+            enemy_health_bonus: 1.0,
+            bullet_speed_bonus: 0.4,
+        }
+    }
+}
+
+impl RankSettings {
+    /// 랭크 설정의 문제를 `problems`에 추가합니다.
+    fn validate(&self, problems: &mut Vec<String>) {
+        let fields = [
+            ("kill_gain", self.kill_gain),
+            ("hit_gain", self.hit_gain),
+            ("shot_cost", self.shot_cost),
+            ("streak_gain", self.streak_gain),
+            ("death_penalty", self.death_penalty),
+            ("spawn_rate_bonus", self.spawn_rate_bonus),
+            ("enemy_health_bonus", self.enemy_health_bonus),
+            ("bullet_speed_bonus", self.bullet_speed_bonus),
+        ];
+        for (name, value) in fields {
+            if !(value.is_finite() && value >= 0.0) {
+                problems.push(format!("rank.{name}: 0 이상이어야 합니다 (현재 {value})"));
+            }
+        }
+        if !(0.0..=1.0).contains(&self.initial) {
+            problems.push(format!(
+                "rank.initial: 0.0 ~ 1.0 사이여야 합니다 (현재 {})",
+                self.initial
+            ));
+        }
+        if !(self.streak_ramp.is_finite() && self.streak_ramp > 0.0) {
+            problems.push(format!(
+                "rank.streak_ramp: 0보다 큰 값이어야 합니다 (현재 {})",
+                self.streak_ramp
+            ));
+        }
+    }
+}
+
+/// 경과 시간(초)에 따른 배율 곡선입니다. `[(시간, 배율), ...]` 형식으로 적습니다.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(transparent)]
+pub struct DifficultyCurve(pub Vec<(f32, f32)>);
+
+impl DifficultyCurve {
+    /// `time`초에서의 배율을 반환합니다. 점이 없으면 1.0입니다.
+    pub fn sample(&self, time: f32) -> f32 {
+        let points = &self.0;
+        let Some(&(first_time, first_value)) = points.first() else {
+            return 1.0;
+        };
+        if time <= first_time {
+            return first_value;
+        }
+
+        for pair in points.windows(2) {
+            let ((start_time, start_value), (end_time, end_value)) = (pair[0], pair[1]);
+            if time <= end_time {
+                let span = end_time - start_time;
+                if span <= 0.0 {
+                    return end_value;
+                }
+                let t = (time - start_time) / span;
+                return start_value + (end_value - start_value) * t;
+            }
+        }
+
+        points[points.len() - 1].1
+    }
+}
+
+/// 적 종류별 능력치 표입니다.
+///
+/// 빠진 종류는 기본값을 사용합니다.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct EnemyTable {
+    pub drone: EnemyStats,
+    pub weaver: EnemyStats,
+    pub diver: EnemyStats,
+    pub kamikaze: EnemyStats,
+    pub bruiser: EnemyStats,
+}
+
+impl Default for EnemyTable {
+    fn default() -> Self {
+        Self {
+            drone: EnemyStats {
+                speed: ENEMY_SPEED,
+                collision_radius: ENEMY_COLLISION_RADIUS,
+                scale: ENEMY_SCALE,
+                max_health: ENEMY_MAX_HEALTH,
+                score: SCORE_PER_ENEMY,
+                spawn_weight: 6,
+                min_level: 0,
+                fire: Some(EnemyFire {
+                    interval: 3.0,
+                    aimed: false,
+                }),
+                pattern: None,
+                drops: vec![
+                    PowerUpDrop {
+                        kind: PowerUpKind::RapidFire,
+                        chance: 0.02,
+                    },
+                    PowerUpDrop {
+                        kind: PowerUpKind::ScoreMultiplier,
+                        chance: 0.02,
+                    },
+                    PowerUpDrop {
+                        kind: PowerUpKind::WeaponUp,
+                        chance: 0.02,
+                    },
+                ],
+            },
+            weaver: EnemyStats {
+                speed: 120.0,
+                collision_radius: 18.0,
+                scale: 0.5,
+                max_health: 2,
+                score: 150,
+                spawn_weight: 3,
+                min_level: 0,
+                fire: None,
+                pattern: None,
+                drops: vec![
+                    PowerUpDrop {
+                        kind: PowerUpKind::SpreadShot,
+                        chance: 0.05,
+                    },
+                    PowerUpDrop {
+                        kind: PowerUpKind::RapidFire,
+                        chance: 0.03,
+                    },
+                    PowerUpDrop {
+                        kind: PowerUpKind::WeaponUp,
+                        chance: 0.04,
+                    },
+                ],
+            },
+            diver: EnemyStats {
+                speed: 240.0,
+                collision_radius: 16.0,
+                scale: 0.45,
+                max_health: 1,
+                score: 150,
+                spawn_weight: 2,
+                min_level: 2,
+                fire: None,
+                pattern: None,
+                drops: vec![
+                    PowerUpDrop {
+                        kind: PowerUpKind::Shield,
+                        chance: 0.04,
+                    },
+                    PowerUpDrop {
+                        kind: PowerUpKind::ScoreMultiplier,
+                        chance: 0.03,
+                    },
+                ],
+            },
+            kamikaze: EnemyStats {
+                speed: 200.0,
+                collision_radius: 16.0,
+                scale: 0.45,
+                max_health: 1,
+                score: 200,
+                spawn_weight: 2,
+                min_level: 3,
+                fire: None,
+                pattern: None,
+                drops: vec![
+                    PowerUpDrop {
+                        kind: PowerUpKind::Shield,
+                        chance: 0.05,
+                    },
+                    PowerUpDrop {
+                        kind: PowerUpKind::Bomb,
+                        chance: 0.02,
+                    },
+                ],
+            },
+            bruiser: EnemyStats {
+                speed: 70.0,
+                collision_radius: 32.0,
+                scale: 0.9,
+                max_health: 8,
+                score: 500,
+                spawn_weight: 1,
+                min_level: 4,
+                fire: None,
+                pattern: Some("patterns/bruiser.pattern.ron".to_string()),
+                drops: vec![
+                    PowerUpDrop {
+                        kind: PowerUpKind::SpreadShot,
+                        chance: 0.15,
+                    },
+                    PowerUpDrop {
+                        kind: PowerUpKind::Bomb,
+                        chance: 0.08,
+                    },
+                    PowerUpDrop {
+                        kind: PowerUpKind::ExtraLife,
+                        chance: 0.05,
+                    },
+                    PowerUpDrop {
+                        kind: PowerUpKind::WeaponUp,
+                        chance: 0.1,
+                    },
+                ],
+            },
+        }
+    }
+}
+
+impl EnemyTable {
+    /// 적 종류의 능력치를 반환합니다.
+    pub fn get(&self, kind: EnemyKind) -> &EnemyStats {
+        match kind {
+            EnemyKind::Drone => &self.drone,
+            EnemyKind::Weaver => &self.weaver,
+            EnemyKind::Diver => &self.diver,
+            EnemyKind::Kamikaze => &self.kamikaze,
+            EnemyKind::Bruiser => &self.bruiser,
+        }
+    }
+
+    /// `level`에서 등장할 수 있는 종류의 스폰 가중치를 반환합니다.
+    pub fn spawn_weight(&self, kind: EnemyKind, level: u32) -> u32 {
+        let stats = self.get(kind);
+        if level >= stats.min_level {
+            stats.spawn_weight
+        } else {
+            0
+        }
+    }
+
+    /// `level`에서 등장할 수 있는 종류 중 가중치에 따라 적 종류를 고릅니다.
+    ///
+    /// `roll`은 `0..total_spawn_weight(level)` 범위의 값이어야 합니다.
+    pub fn pick(&self, mut roll: u32, level: u32) -> EnemyKind {
+        for kind in EnemyKind::ALL {
+            let weight = self.spawn_weight(kind, level);
+            if roll < weight {
+                return kind;
+            }
+            roll -= weight;
+        }
+        EnemyKind::Drone
+    }
+
+    /// 설정된 모든 탄막 패턴 경로를 반환합니다. (중복 포함)
+    pub fn pattern_paths(&self) -> impl Iterator<Item = &str> {
+        EnemyKind::ALL
+            .into_iter()
+            .filter_map(|kind| self.get(kind).pattern.as_deref())
+    }
+
+    /// 설정 파일의 항목 이름과 능력치 쌍을 반환합니다. (오류 메시지용)
+    fn named(&self) -> [(&'static str, &EnemyStats); 5] {
+        [
+            ("drone", &self.drone),
+            ("weaver", &self.weaver),
+            ("diver", &self.diver),
+            ("kamikaze", &self.kamikaze),
+            ("bruiser", &self.bruiser),
+        ]
+    }
+
+    /// `level`에서 등장할 수 있는 종류의 스폰 가중치 합을 반환합니다.
+    pub fn total_spawn_weight(&self, level: u32) -> u32 {
+        EnemyKind::ALL
+            .into_iter()
+            .map(|kind| self.spawn_weight(kind, level))
+            .sum()
+    }
+}
+
+impl GameConfig {
+    /// 설정 값 전체에서 계산한 64비트 지문입니다.
+    ///
+    /// 리플레이가 녹화 당시와 같은 설정으로 재생되는지 확인할 때 씁니다.
+    /// 형식이 정해지지 않은 `Debug` 출력 대신, 설정 파일과 같은 RON으로 직렬화한 문자열에
+    /// FNV-1a 해시를 적용하므로 실행이나 컴파일러가 바뀌어도 같은 값이 나옵니다.
+    pub fn fingerprint(&self) -> u64 {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        ron::to_string(self)
+            .expect("game config is always serializable")
+            .bytes()
+            .fold(FNV_OFFSET, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+            })
+    }
+
+    /// RON 설정 파일 내용을 읽고 [`GameConfig::validate`]로 검증합니다.
+    ///
+    /// 빠진 항목은 기본값으로 채우고, 알 수 없는 항목은 문법 오류로 거부합니다.
+    pub fn from_ron(bytes: &[u8]) -> Result<Self, ConfigError> {
+        let config: GameConfig = ron::de::from_bytes(bytes)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// 설정 값이 유효한지 검사합니다.
+    ///
+    /// 잘못된 항목을 모두 모아 한 번에 보고합니다.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let positive_fields = [
+            ("player_speed", self.player_speed),
+            ("player_collision_radius", self.player_collision_radius),
+            ("player_scale", self.player_scale),
+            ("player_respawn_delay", self.player_respawn_delay),
+            (
+                "player_invulnerability_duration",
+                self.player_invulnerability_duration,
+            ),
+            (
+                "projectile_collision_radius",
+                self.projectile_collision_radius,
+            ),
+            ("projectile_scale", self.projectile_scale),
+            ("enemy_spawn_interval", self.enemy_spawn_interval),
+            ("enemy_projectile_speed", self.enemy_projectile_speed),
+            (
+                "enemy_projectile_collision_radius",
+                self.enemy_projectile_collision_radius,
+            ),
+            ("enemy_projectile_scale", self.enemy_projectile_scale),
+        ];
+
+        let positive_counts = [
+            ("player_max_health", self.player_max_health),
+            ("player_lives", self.player_lives),
+            ("enemy_contact_damage", self.enemy_contact_damage),
+            ("enemy_projectile_damage", self.enemy_projectile_damage),
+        ];
+
+        let mut problems: Vec<String> = positive_fields
+            .into_iter()
+            .filter(|(_, value)| !(value.is_finite() && *value > 0.0))
+            .map(|(name, value)| format!("{name}: 0보다 큰 값이어야 합니다 (현재 {value})"))
+            .chain(
+                positive_counts
+                    .into_iter()
+                    .filter(|(_, value)| *value == 0)
+                    .map(|(name, _)| format!("{name}: 1 이상이어야 합니다 (현재 0)")),
+            )
+            .collect();
+
+        for (kind_name, stats) in self.enemies.named() {
+            let fields = [
+                ("speed", stats.speed),
+                ("collision_radius", stats.collision_radius),
+                ("scale", stats.scale),
+            ];
+            for (name, value) in fields {
+                if !(value.is_finite() && value > 0.0) {
+                    problems.push(format!(
+                        "enemies.{kind_name}.{name}: 0보다 큰 값이어야 합니다 (현재 {value})"
+                    ));
+                }
+            }
+            if let Some(fire) = &stats.fire {
+                if !(fire.interval.is_finite() && fire.interval > 0.0) {
+                    problems.push(format!(
+                        "enemies.{kind_name}.fire.interval: 0보다 큰 값이어야 합니다 (현재 {})",
+                        fire.interval
+                    ));
+                }
+            }
+            if stats.max_health == 0 {
+                problems.push(format!(
+                    "enemies.{kind_name}.max_health: 1 이상이어야 합니다 (현재 0)"
+                ));
+            }
+            for (index, drop) in stats.drops.iter().enumerate() {
+                if !(0.0..=1.0).contains(&drop.chance) {
+                    problems.push(format!(
+                        "enemies.{kind_name}.drops[{index}].chance: 0.0 ~ 1.0 사이여야 합니다 (현재 {})",
+                        drop.chance
+                    ));
+                }
+            }
+            let total_chance: f32 = stats.drops.iter().map(|drop| drop.chance).sum();
+            if total_chance > 1.0 {
+                problems.push(format!(
+                    "enemies.{kind_name}.drops: 확률의 합이 1.0 이하여야 합니다 (현재 {total_chance})"
+                ));
+            }
+        }
+
+        if self.enemies.total_spawn_weight(1) == 0 {
+            problems.push(
+                "enemies: 레벨 1부터 나오고 spawn_weight가 1 이상인 종류가 하나는 있어야 합니다"
+                    .to_string(),
+            );
+        }
+
+        self.difficulty.validate(&mut problems);
+        self.rank.validate(&mut problems);
+        self.boss.validate(&mut problems);
+        self.weapons.validate(&mut problems);
+        self.missiles.validate(&mut problems);
+        self.powerups.validate(&mut problems);
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::Invalid(problems))
+        }
+    }
+}
+
+/// 설정 파일을 읽을 때 발생하는 오류입니다.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// 파일 입출력 오류
+    Io(std::io::Error),
+    /// RON 문법 오류 또는 알 수 없는 항목
+    Parse(ron::error::SpannedError),
+    /// 값 검증 실패 (잘못된 항목 목록)
+    Invalid(Vec<String>),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "입출력 오류: {err}"),
+            Self::Parse(err) => write!(f, "설정 파일 문법 오류: {err}"),
+            Self::Invalid(problems) => {
+                write!(f, "잘못된 설정 값 {}개", problems.len())?;
+                for problem in problems {
+                    write!(f, "\n  - {problem}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<std::io::Error> for ConfigError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<ron::error::SpannedError> for ConfigError {
+    fn from(err: ron::error::SpannedError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+/// `.ron` 파일을 [`GameConfig`]로 읽는 에셋 로더입니다.
+///
+/// [`GameConfig::from_ron`]으로 읽고 검증하며, 실패하면 로드 자체가 실패합니다.
+#[derive(Default, TypePath)]
+pub struct GameConfigLoader;
+
+impl AssetLoader for GameConfigLoader {
+    type Asset = GameConfig;
+    type Settings = ();
+    type Error = ConfigError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        GameConfig::from_ron(&bytes)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ron"]
+    }
+}
+
+/// 로드 중인 설정 파일의 핸들을 저장하는 리소스입니다.
+#[derive(Resource)]
+pub struct GameConfigHandle(pub Handle<GameConfig>);
+
+// =============================================================================
+// 설정 플러그인
+// =============================================================================
+
+/// 게임 설정 파일의 로드와 핫 리로드를 담당하는 플러그인입니다.
+///
+/// AssetPlugin이 없는 헤드리스 앱에서는 기본값만 사용합니다.
+pub struct ConfigPlugin;
+
+impl Plugin for ConfigPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameConfig>();
+
+        if !app.is_plugin_added::<AssetPlugin>() {
+            return;
+        }
+
+        app.init_asset::<GameConfig>()
+            .init_asset_loader::<GameConfigLoader>()
+            .add_systems(PreStartup, load_game_config)
+            .add_systems(Update, (apply_game_config, report_config_errors));
+    }
+}
+
+// =============================================================================
+// 시스템 (Systems)
+// =============================================================================
+
+/// 설정 파일 로드를 시작하는 시스템입니다.
+fn load_game_config(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(GameConfigHandle(asset_server.load(GAME_CONFIG_PATH)));
+}
+
+/// 설정 파일이 로드되거나 수정되면 [`GameConfig`] 리소스에 반영하는 시스템입니다.
+///
+/// 적 스폰 간격은 타이머에 바로 적용합니다.
+/// 나머지 값은 다음에 스폰되는 엔티티부터 적용됩니다.
+fn apply_game_config(
+    mut events: MessageReader<AssetEvent<GameConfig>>,
+    handle: Res<GameConfigHandle>,
+    configs: Res<Assets<GameConfig>>,
+    mut config: ResMut<GameConfig>,
+    mut spawn_timer: ResMut<EnemySpawnTimer>,
+) {
+    for event in events.read() {
+        if !(event.is_loaded_with_dependencies(&handle.0) || event.is_modified(&handle.0)) {
+            continue;
+        }
+        let Some(loaded) = configs.get(&handle.0) else {
+            continue;
+        };
+
+        if *config != *loaded {
+            info!("Game config loaded from {GAME_CONFIG_PATH}");
+            *config = loaded.clone();
+            spawn_timer
+                .0
+                .set_duration(std::time::Duration::from_secs_f32(
+                    config.enemy_spawn_interval,
+                ));
+        }
+    }
+}
+
+/// 설정 파일을 읽지 못했을 때 오류를 보고하는 시스템입니다.
+///
+/// 현재 적용된 값(처음이면 기본 상수)을 그대로 유지합니다.
+fn report_config_errors(mut events: MessageReader<AssetLoadFailedEvent<GameConfig>>) {
+    for event in events.read() {
+        error!(
+            "Failed to load game config '{}', keeping current values: {}",
+            event.path, event.error
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 검증 실패로 보고된 문제 목록을 꺼냅니다.
+    fn problems(result: Result<GameConfig, ConfigError>) -> Vec<String> {
+        match result {
+            Err(ConfigError::Invalid(problems)) => problems,
+            other => panic!("expected validation problems, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn defaults_and_shipped_config_are_valid() {
+        assert!(GameConfig::default().validate().is_ok());
+        GameConfig::from_ron(include_bytes!("../assets/config/game.ron")).unwrap();
+    }
+
+    #[test]
+    fn missing_fields_fall_back_to_defaults() {
+        let config = GameConfig::from_ron(b"(player_speed: 420.0, boss: (score: 5))").unwrap();
+        assert_eq!(
+            config,
+            GameConfig {
+                player_speed: 420.0,
+                boss: BossSettings {
+                    score: 5,
+                    ..default()
+                },
+                ..default()
+            }
+        );
+        assert_eq!(GameConfig::from_ron(b"()").unwrap(), GameConfig::default());
+    }
+
+    #[test]
+    fn unknown_fields_and_bad_syntax_are_parse_errors() {
+        for source in [
+            "(player_sped: 300.0)",
+            "(boss: (health: 10))",
+            "(player_speed: 300.0",
+            "(player_lives: -1)",
+        ] {
+            assert!(
+                matches!(
+                    GameConfig::from_ron(source.as_bytes()),
+                    Err(ConfigError::Parse(_))
+                ),
+                "{source}"
+            );
+        }
+    }
+
+    #[test]
+    fn out_of_range_values_are_all_reported_together() {
+        let source = "(
+            player_speed: -1.0,
+            player_scale: 0.0,
+            player_lives: 0,
+            boss: (phases: []),
+            powerups: (score_multiplier: 0),
+        )";
+        assert_eq!(
+            problems(GameConfig::from_ron(source.as_bytes())),
+            [
+                "player_speed: 0보다 큰 값이어야 합니다 (현재 -1)",
+                "player_scale: 0보다 큰 값이어야 합니다 (현재 0)",
+                "player_lives: 1 이상이어야 합니다 (현재 0)",
+                "boss.phases: 단계가 하나 이상 있어야 합니다",
+                "powerups.score_multiplier: 1 이상이어야 합니다 (현재 0)",
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_enemy_and_boss_problems_name_their_path() {
+        let mut config = GameConfig::default();
+        config.enemies.diver.max_health = 0;
+        config.enemies.diver.drops = vec![
+            PowerUpDrop {
+                kind: PowerUpKind::Bomb,
+                chance: 0.7,
+            },
+            PowerUpDrop {
+                kind: PowerUpKind::Shield,
+                chance: 1.5,
+            },
+        ];
+        config.boss.phases[2].health = 0.6;
+
+        let Err(err) = config.validate() else {
+            panic!("invalid config passed validation");
+        };
+        let ConfigError::Invalid(problems) = &err else {
+            panic!("unexpected error {err:?}");
+        };
+        assert_eq!(
+            problems,
+            &[
+                "enemies.diver.max_health: 1 이상이어야 합니다 (현재 0)",
+                "enemies.diver.drops[1].chance: 0.0 ~ 1.0 사이여야 합니다 (현재 1.5)",
+                "enemies.diver.drops: 확률의 합이 1.0 이하여야 합니다 (현재 2.2)",
+                "boss.phases[2].health: 0 ~ 1 사이이고 앞 단계보다 작아야 합니다 (현재 0.6)",
+            ]
+        );
+        assert_eq!(
+            err.to_string(),
+            format!("잘못된 설정 값 4개\n  - {}", problems.join("\n  - "))
+        );
+    }
+
+    #[test]
+    fn no_spawnable_enemy_is_reported() {
+        let mut config = GameConfig::default();
+        let enemies = &mut config.enemies;
+        for stats in [
+            &mut enemies.drone,
+            &mut enemies.weaver,
+            &mut enemies.diver,
+            &mut enemies.kamikaze,
+        ] {
+            stats.spawn_weight = 0;
+        }
+        // 가중치는 있지만 레벨 1에는 나오지 않음
+        enemies.bruiser.min_level = 2;
+        let source = ron::to_string(&config).unwrap();
+        assert_eq!(
+            problems(GameConfig::from_ron(source.as_bytes())),
+            ["enemies: 레벨 1부터 나오고 spawn_weight가 1 이상인 종류가 하나는 있어야 합니다"]
+        );
+    }
+
+    #[test]
+    fn drops_are_picked_by_cumulative_chance() {
+        let mut stats = GameConfig::default().enemies.drone;
+        stats.drops = vec![
+            PowerUpDrop {
+                kind: PowerUpKind::SpreadShot,
+                chance: 0.25,
+            },
+            PowerUpDrop {
+                kind: PowerUpKind::Shield,
+                chance: 0.5,
+            },
+        ];
+        let cases = [
+            (0.0, Some(PowerUpKind::SpreadShot)),
+            (0.2499, Some(PowerUpKind::SpreadShot)),
+            (0.25, Some(PowerUpKind::Shield)),
+            (0.7499, Some(PowerUpKind::Shield)),
+            (0.75, None),
+            (0.99999, None),
+        ];
+        for (roll, expected) in cases {
+            assert_eq!(stats.pick_drop(roll), expected, "roll {roll}");
+        }
+
+        // 합이 1이면 마지막 항목이 1 바로 아래까지 차지
+        stats.drops[1].chance = 0.75;
+        assert_eq!(stats.pick_drop(0.99999), Some(PowerUpKind::Shield));
+        assert_eq!(
+            stats.pick_drop(1.0 - f32::EPSILON),
+            Some(PowerUpKind::Shield)
+        );
+
+        stats.drops.clear();
+        assert_eq!(stats.pick_drop(0.0), None);
+    }
+
+    #[test]
+    fn fingerprint_follows_config_values() {
+        let config = GameConfig::default();
+        assert_eq!(config.fingerprint(), config.clone().fingerprint());
+        assert_eq!(
+            GameConfig::from_ron(b"()").unwrap().fingerprint(),
+            config.fingerprint()
+        );
+
+        let mut changed = config.clone();
+        changed.enemies.bruiser.score += 1;
+        assert_ne!(changed.fingerprint(), config.fingerprint());
+    }
+}
@@ -0,0 +1,250 @@
+//! 피해 처리 모듈
+//!
+//! 충돌 시스템(`collision.rs`)이 보낸 [`Hit`] 메시지를 읽어 충돌 결과를 처리합니다.
+//! 결과마다 시스템이 따로 있어, 새 효과(소리 등)는 메시지를 읽는 시스템을 하나 더 붙이면 됩니다.
+//!
+//! # 처리 순서
+//! 1. 적 피해: 대상마다 한 틱의 피해를 합쳐 한 번만 적용, 격추되면 [`EnemyDestroyed`] 전송
+//! 2. 플레이어 피해: 피해를 합쳐 한 번만 적용, 목숨 감소와 부활 대기, 게임 오버
+//! 3. 공격자 소모: 닿은 투사체와 플레이어에 부딪힌 적을 삭제 (레이저 광선과 보스는 남음)
+//! 4. 번쩍임: 피해를 입고 살아남은 적에 [`HitFlash`]
+//! 5. 점수: 격추마다 적의 점수에 점수 배율을 곱해 더함
+//!
+//! 같은 틱에 여러 투사체가 한 적을 맞혀도 피해는 합산되고, 삭제와 점수는 한 번만 일어납니다.
+//! 한 틱의 피해와 랭크 기록은 더하기만 하므로 대상을 처리하는 순서와 무관하게 결과가 같습니다.
+//! [`EnemyDestroyed`]는 처리 순서대로 보내므로, 순서가 결과에 영향을 주는 쪽(아이템 드롭)에서 정렬해 씁니다.
+//! 명중, 격추, 피격, 사망은 숨은 랭크([`Rank`])에 기록하고,
+//! 점수 배율과 방어막 파워업([`ActivePowerUps`])을 적용합니다.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use bevy::{ecs::message::MessageReader, prelude::*};
+
+use crate::boss::{Boss, BossPart};
+use crate::collision::{detect_contacts, Hit};
+use crate::components::{
+    Enemy, EnemyKind, Health, HitFlash, Lives, Player, PowerUpKind, Projectile, Respawning,
+    ScoreValue,
+};
+use crate::config::GameConfig;
+use crate::powerup::ActivePowerUps;
+use crate::rank::Rank;
+use crate::resources::{AppState, Score, ENEMY_HIT_FLASH_DURATION};
+use crate::GameplaySet;
+
+// =============================================================================
+// 메시지
+// =============================================================================
+
+/// 적이 플레이어의 공격에 격추되었을 때 보내는 메시지입니다.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct EnemyDestroyed {
+    /// 격추된 적의 종류 (보스는 `None`)
+    pub kind: Option<EnemyKind>,
+    /// 격추된 위치
+    pub position: Vec2,
+    /// 적의 점수 (배율 적용 전)
+    pub score: u32,
+}
+
+// =============================================================================
+// 피해 처리 플러그인
+// =============================================================================
+
+/// 충돌 결과(피해, 공격자 소모, 번쩍임, 점수)를 처리하는 플러그인입니다.
+pub struct DamagePlugin;
+
+impl Plugin for DamagePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<EnemyDestroyed>().add_systems(
+            FixedUpdate,
+            (
+                apply_enemy_damage,
+                apply_player_damage,
+                consume_attackers,
+                flash_damaged_enemies,
+                score_kills,
+            )
+                .chain()
+                .after(detect_contacts)
+                .in_set(GameplaySet::Collision),
+        );
+    }
+}
+
+// =============================================================================
+// 시스템 (Systems)
+// =============================================================================
+
+/// 적에게 닿은 [`Hit`]을 대상마다 모아 피해를 주는 시스템입니다.
+///
+/// 체력이 0이 되면 적을 삭제하고 [`EnemyDestroyed`]를 보냅니다
+/// (보스는 격파 연출 뒤에 보스 시스템이 삭제).
+/// 이미 격추된 적이나 피해를 받지 않는 상태의 보스는 피해를 받지 않으며 명중으로 치지 않습니다.
+#[allow(clippy::type_complexity)]
+fn apply_enemy_damage(
+    mut commands: Commands,
+    mut hits: MessageReader<Hit>,
+    mut destroyed: MessageWriter<EnemyDestroyed>,
+    config: Res<GameConfig>,
+    mut rank: ResMut<Rank>,
+    mut enemies: Query<
+        (
+            &Transform,
+            &mut Health,
+            &ScoreValue,
+            Option<&EnemyKind>,
+            Option<&Boss>,
+        ),
+        With<Enemy>,
+    >,
+) {
+    // 대상마다 (피해 합, 명중 수)
+    let mut totals: BTreeMap<Entity, (u32, u32)> = BTreeMap::new();
+    for hit in hits.read() {
+        if enemies.contains(hit.target) {
+            let total = totals.entry(hit.target).or_default();
+            total.0 += hit.damage;
+            total.1 += 1;
+        }
+    }
+
+    for (target, (damage, count)) in totals {
+        let Ok((transform, mut health, score_value, kind, boss)) = enemies.get_mut(target) else {
+            continue;
+        };
+        if health.current == 0 || boss.is_some_and(|boss| !boss.is_vulnerable()) {
+            continue;
+        }
+
+        for _ in 0..count {
+            rank.record_hit(&config.rank);
+        }
+        health.current = health.current.saturating_sub(damage);
+        if health.current == 0 {
+            if boss.is_none() {
+                commands.entity(target).despawn();
+            }
+            destroyed.write(EnemyDestroyed {
+                kind: kind.copied(),
+                position: transform.translation.truncate(),
+                score: score_value.0,
+            });
+        }
+    }
+}
+
+/// 플레이어에 닿은 [`Hit`]의 피해를 합쳐 한 번에 주는 시스템입니다.
+///
+/// 방어막 파워업이 켜져 있으면 피해를 입지 않습니다.
+///
+/// # 사망과 상태 전환
+/// 체력이 0이 되면 목숨을 하나 잃고 [`Respawning`] 상태가 됩니다.
+/// 남은 목숨이 없으면 GameOver 상태로 전환합니다.
+/// NextState<AppState>를 사용하여 상태 전환을 요청합니다.
+fn apply_player_damage(
+    mut commands: Commands,
+    mut hits: MessageReader<Hit>,
+    mut next_state: ResMut<NextState<AppState>>,
+    config: Res<GameConfig>,
+    mut rank: ResMut<Rank>,
+    powerups: Res<ActivePowerUps>,
+    mut player: Query<(Entity, &mut Health, &mut Lives, &mut Visibility), With<Player>>,
+) {
+    let Ok((player_entity, mut health, mut lives, mut visibility)) = player.single_mut() else {
+        hits.clear();
+        return;
+    };
+
+    let damage: u32 = hits
+        .read()
+        .filter(|hit| hit.target == player_entity)
+        .map(|hit| hit.damage)
+        .sum();
+    if damage == 0 || powerups.is_active(PowerUpKind::Shield) {
+        return;
+    }
+
+    rank.record_damage();
+    health.current = health.current.saturating_sub(damage);
+    if health.current > 0 {
+        return;
+    }
+
+    // 사망: 목숨 감소
+    rank.record_death(&config.rank);
+    lives.0 = lives.0.saturating_sub(1);
+    if lives.0 == 0 {
+        // 게임 오버! 상태 전환 요청
+        // NextState::set()으로 다음 프레임에 상태가 변경됩니다.
+        next_state.set(AppState::GameOver);
+        return;
+    }
+
+    // 부활 대기 (잠시 사라졌다가 시작 위치에서 부활)
+    *visibility = Visibility::Hidden;
+    commands
+        .entity(player_entity)
+        .insert(Respawning(Timer::from_seconds(
+            config.player_respawn_delay,
+            TimerMode::Once,
+        )));
+}
+
+/// 대상에 닿은 공격자를 삭제하는 시스템입니다.
+///
+/// 투사체는 대상에 닿으면 사라지고 (피해를 받지 않는 보스에 닿아도 흡수됨),
+/// 플레이어에 부딪힌 적은 점수 없이 사라집니다.
+/// 레이저 광선과 보스 몸통, 부위는 남습니다.
+/// 앞 시스템에서 이미 격추되어 삭제된 적은 건너뜁니다.
+#[allow(clippy::type_complexity)]
+fn consume_attackers(
+    mut commands: Commands,
+    mut hits: MessageReader<Hit>,
+    attackers: Query<(Has<Projectile>, Has<Enemy>, Has<Boss>, Has<BossPart>)>,
+) {
+    let attacker_entities: BTreeSet<Entity> = hits.read().map(|hit| hit.attacker).collect();
+    for attacker in attacker_entities {
+        let Ok((is_projectile, is_enemy, is_boss, is_boss_part)) = attackers.get(attacker) else {
+            continue;
+        };
+        if is_projectile || (is_enemy && !is_boss && !is_boss_part) {
+            commands.entity(attacker).despawn();
+        }
+    }
+}
+
+/// 피해를 입고 살아남은 적에 [`HitFlash`]를 붙이는 시스템입니다.
+fn flash_damaged_enemies(
+    mut commands: Commands,
+    mut hits: MessageReader<Hit>,
+    enemies: Query<(&Health, Option<&Boss>), With<Enemy>>,
+) {
+    let targets: BTreeSet<Entity> = hits.read().map(|hit| hit.target).collect();
+    for target in targets {
+        let Ok((health, boss)) = enemies.get(target) else {
+            continue;
+        };
+        if health.current == 0 || boss.is_some_and(|boss| !boss.is_vulnerable()) {
+            continue;
+        }
+        commands.entity(target).insert(HitFlash(Timer::from_seconds(
+            ENEMY_HIT_FLASH_DURATION,
+            TimerMode::Once,
+        )));
+    }
+}
+
+/// 격추된 적마다 점수 배율을 곱한 점수를 더하는 시스템입니다.
+fn score_kills(
+    mut destroyed: MessageReader<EnemyDestroyed>,
+    config: Res<GameConfig>,
+    powerups: Res<ActivePowerUps>,
+    mut score: ResMut<Score>,
+    mut rank: ResMut<Rank>,
+) {
+    for kill in destroyed.read() {
+        score.0 += kill.score * powerups.score_multiplier(&config);
+        rank.record_kill(&config.rank);
+    }
+}
@@ -1,16 +1,63 @@
 //! 적(Enemy) 모듈
 //!
-//! 적의 주기적 스폰과 이동을 담당합니다.
+//! 적의 주기적 스폰과 종류별 조종(속도 변경), 피격 시 번쩍임 효과를 담당합니다.
+//! 속도에 따른 이동은 운동 모듈(`kinematics.rs`)이 처리합니다.
+//!
+//! # 적 종류 ([`EnemyKind`])
+//! - Drone: 똑바로 내려옴
+//! - Weaver: 좌우로 사인파를 그리며 내려옴
+//! - Diver: 화면 중앙 쪽으로 비스듬히 빠르게 내려옴
+//! - Kamikaze: 플레이어보다 위에 있는 동안 플레이어 쪽으로 방향을 틀며 돌진
+//! - Bruiser: 느리고 크며 체력이 높음, 탄막 패턴을 쏨
+//!
+//! 스테이지가 진행 중이면 스테이지 타임라인(`stage.rs`)이 정한 대로 스폰하고,
+//! 모든 스테이지를 마친 뒤에는 설정 파일의 `spawn_weight` 가중치에 따라 무작위로 스폰합니다.
+//!
+//! # 난이도 ([`Difficulty`])
+//! 무한 모드(스테이지가 없는 동안)에는 흐른 시간에 따라 설정의 난이도 곡선을 진행합니다.
+//! 레벨이 오르면 스폰 빈도, 적 이동 속도, 사격 빈도가 늘고
+//! `min_level`이 높은 종류가 등장하기 시작합니다.
+//! 숨은 랭크(`rank.rs`)는 스테이지 중에도 스폰 빈도, 적 체력, 적 투사체 속도를 더 올립니다.
+//!
+//! # 적 사격
+//! 설정에 `fire`가 있는 종류는 [`EnemyGun`] 주기마다 적 투사체를 쏩니다.
+//! 설정에 `pattern`이 있는 종류는 탄막 패턴을 실행합니다 (`pattern.rs`).
+//! 투사체의 화면 밖 정리는 `projectile.rs`에서 처리합니다.
 //! InGame 상태에서만 동작하며, 상태 전환 시 자동으로 정리됩니다.
 
 use bevy::prelude::*;
-use bevy::window::PrimaryWindow;
-use rand::Rng;
+use rand::RngExt;
 
-use crate::components::{CollisionRadius, Enemy, Velocity};
-use crate::resources::{
-    AppState, EnemySpawnTimer, ENEMY_COLLISION_RADIUS, ENEMY_SCALE, ENEMY_SPEED,
+use crate::boss::{Boss, BossPart};
+use crate::components::{
+    Collider, ColliderShape, Damage, Enemy, EnemyGun, EnemyKind, Health, HitFlash, Player,
+    ScoreValue, Velocity,
 };
+use crate::config::GameConfig;
+use crate::kinematics::integrate_velocity;
+use crate::projectile::spawn_hostile_projectile;
+use crate::rank::Rank;
+use crate::resources::{Difficulty, EnemySpawnTimer, GameRng, Playfield, Playing, SpriteAssets};
+use crate::stage::{run_stage_timeline, stage_active, StageProgress};
+use crate::GameplaySet;
+
+/// 피격 시 스프라이트에 곱하는 색
+///
+/// 텍스처 색에 곱해지므로 1보다 큰 값을 써야 하얗게 번쩍입니다.
+const HIT_FLASH_COLOR: Color = Color::linear_rgb(8.0, 8.0, 8.0);
+
+/// Weaver가 좌우로 흔들리는 폭 (픽셀)
+const WEAVE_AMPLITUDE: f32 = 80.0;
+/// Weaver가 한 번 좌우로 왕복하는 동안 내려오는 거리 (픽셀)
+const WEAVE_WAVELENGTH: f32 = 240.0;
+/// Diver가 수직선에서 기울어진 각도 (라디안)
+const DIVE_ANGLE: f32 = 0.5;
+/// Kamikaze가 1초에 틀 수 있는 최대 각도 (라디안)
+const KAMIKAZE_TURN_RATE: f32 = 2.0;
+/// 적을 제거하기 전에 화면 밖으로 벗어날 수 있는 거리 (픽셀)
+///
+/// 가장자리 근처에서 스폰된 Weaver가 흔들리며 잠깐 화면 밖으로 나갔다 돌아올 수 있을 만큼 넓게 잡습니다.
+const OFFSCREEN_MARGIN: f32 = 100.0;
 
 // =============================================================================
 // 적 플러그인
@@ -23,15 +70,31 @@ impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
         app
             // 게임 시작 시 스폰 타이머 리셋
-            .add_systems(OnEnter(AppState::InGame), reset_spawn_timer)
+            .add_systems(OnEnter(Playing), reset_spawn_timer)
             // 게임 종료 시 모든 적 정리
-            .add_systems(OnExit(AppState::InGame), cleanup_enemies)
-            // 게임플레이 시스템 (InGame 상태에서만 실행)
+            .add_systems(OnExit(Playing), cleanup_enemies)
+            // 게임플레이 시스템 (고정 틱, 게임플레이 중에만 실행)
             .add_systems(
-                Update,
-                (enemy_spawning, enemy_movement, despawn_offscreen_enemies)
+                FixedUpdate,
+                (
+                    update_difficulty,
+                    enemy_spawning.run_if(not(stage_active)),
+                    enemy_shooting,
+                )
                     .chain()
-                    .run_if(in_state(AppState::InGame)),
+                    // 스테이지가 이번 틱에 끝나거나 시작해도 난수와 난이도를 같은 순서로 쓰도록
+                    .after(run_stage_timeline)
+                    .in_set(GameplaySet::Spawn),
+            )
+            .add_systems(
+                FixedUpdate,
+                steer_enemies
+                    .before(integrate_velocity)
+                    .in_set(GameplaySet::Movement),
+            )
+            .add_systems(
+                FixedUpdate,
+                (despawn_offscreen_enemies, update_hit_flash).in_set(GameplaySet::Cleanup),
             );
     }
 }
@@ -40,14 +103,49 @@ impl Plugin for EnemyPlugin {
 // 시스템 (Systems)
 // =============================================================================
 
-/// 스폰 타이머를 리셋하는 시스템입니다.
+/// 스폰 타이머와 난이도 곡선을 리셋하는 시스템입니다.
 ///
-/// 게임 재시작 시 타이머가 이전 상태를 유지하지 않도록
-/// 명시적으로 리셋합니다.
-fn reset_spawn_timer(mut spawn_timer: ResMut<EnemySpawnTimer>) {
+/// 게임 재시작 시 타이머와 난이도가 이전 상태를 유지하지 않도록
+/// 명시적으로 리셋하고, 설정의 스폰 간격을 적용합니다.
+fn reset_spawn_timer(
+    mut spawn_timer: ResMut<EnemySpawnTimer>,
+    mut difficulty: ResMut<Difficulty>,
+    config: Res<GameConfig>,
+) {
+    *difficulty = config.difficulty.sample(0.0);
+    spawn_timer
+        .0
+        .set_duration(std::time::Duration::from_secs_f32(
+            config.enemy_spawn_interval / difficulty.spawn_rate,
+        ));
     spawn_timer.0.reset();
 }
 
+/// 난이도 곡선을 진행하고 랭크 효과를 적용하는 시스템입니다.
+///
+/// 곡선은 무한 모드(스테이지가 없는 동안)에만 진행되고, 랭크 효과는 항상 적용됩니다.
+/// 스폰 간격은 바로 반영하고, 나머지 배율은 새로 스폰되는 적과 투사체부터 적용됩니다.
+pub(crate) fn update_difficulty(
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    progress: Res<StageProgress>,
+    rank: Res<Rank>,
+    mut difficulty: ResMut<Difficulty>,
+    mut spawn_timer: ResMut<EnemySpawnTimer>,
+) {
+    let mut elapsed = difficulty.elapsed;
+    if progress.current.is_none() {
+        elapsed += time.delta_secs();
+    }
+    *difficulty = config.difficulty.sample(elapsed);
+    rank.apply(&mut difficulty, &config.rank);
+    spawn_timer
+        .0
+        .set_duration(std::time::Duration::from_secs_f32(
+            config.enemy_spawn_interval / difficulty.spawn_rate,
+        ));
+}
+
 /// 모든 적 엔티티를 정리하는 시스템입니다.
 fn cleanup_enemies(mut commands: Commands, query: Query<Entity, With<Enemy>>) {
     for entity in query.iter() {
@@ -56,68 +154,291 @@ fn cleanup_enemies(mut commands: Commands, query: Query<Entity, With<Enemy>>) {
 }
 
 /// 주기적으로 적을 스폰하는 시스템입니다.
-fn enemy_spawning(
+///
+/// 종류는 현재 난이도 레벨에서 등장할 수 있는 종류 중 설정의 스폰 가중치에 따라 고르고,
+/// 능력치도 종류별 설정을 사용합니다.
+/// 스테이지가 진행 중일 때는 스테이지 타임라인이 대신 적을 스폰합니다 (`stage.rs`).
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn enemy_spawning(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    sprites: Res<SpriteAssets>,
     time: Res<Time>,
+    playfield: Res<Playfield>,
+    config: Res<GameConfig>,
+    difficulty: Res<Difficulty>,
     mut spawn_timer: ResMut<EnemySpawnTimer>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut game_rng: ResMut<GameRng>,
 ) {
-    let Ok(window) = window_query.single() else {
-        return;
-    };
-
     spawn_timer.0.tick(time.delta());
 
     if spawn_timer.0.just_finished() {
-        // 랜덤 X 위치 생성
-        let mut rng = rand::rng();
-        let half_width = window.width() / 2.0 - ENEMY_COLLISION_RADIUS * 2.0;
-        let spawn_x = rng.random_range(-half_width..half_width);
-        let spawn_y = window.height() / 2.0 + 50.0;
-
-        // enemy.png 이미지 로드
-        let texture: Handle<Image> = asset_server.load("enemy.png");
-
-        commands.spawn((
-            Sprite {
-                image: texture,
-                ..default()
-            },
-            Transform {
-                translation: Vec3::new(spawn_x, spawn_y, 0.0),
-                scale: Vec3::splat(ENEMY_SCALE),
-                ..default()
-            },
-            Enemy,
-            Velocity(Vec2::new(0.0, -ENEMY_SPEED)),
-            CollisionRadius(ENEMY_COLLISION_RADIUS),
-        ));
+        // 가중치에 따라 종류 선택 (시드 기반)
+        let total_weight = config.enemies.total_spawn_weight(difficulty.level);
+        if total_weight == 0 {
+            return;
+        }
+        let kind = config.enemies.pick(
+            game_rng.rng().random_range(0..total_weight),
+            difficulty.level,
+        );
+        let stats = config.enemies.get(kind);
+
+        // 랜덤 X 위치 생성 (시드 기반)
+        // 판정이 플레이 영역보다 넓으면 놓을 자리가 없으므로 이번 스폰을 건너뜀
+        let half_width = playfield.half_extents().x - stats.collision_radius * 2.0;
+        if half_width <= 0.0 {
+            warn_once!("{kind:?} is too large to spawn inside the playfield");
+            return;
+        }
+        let spawn_x = game_rng.rng().random_range(-half_width..half_width);
+        let spawn_y = playfield.half_extents().y + 50.0;
+
+        spawn_enemy(
+            &mut commands,
+            &sprites,
+            &config,
+            &difficulty,
+            &mut game_rng,
+            kind,
+            Vec2::new(spawn_x, spawn_y),
+        );
+    }
+}
+
+/// 종류별 설정 능력치로 적 하나를 스폰합니다.
+///
+/// 이동 속도, 체력, 사격 간격에는 [`Difficulty`] 배율을 적용합니다.
+/// 사격하는 종류는 첫 발 시점을 무작위로 당겨 동시에 쏘지 않게 합니다.
+pub fn spawn_enemy(
+    commands: &mut Commands,
+    sprites: &SpriteAssets,
+    config: &GameConfig,
+    difficulty: &Difficulty,
+    game_rng: &mut GameRng,
+    kind: EnemyKind,
+    position: Vec2,
+) {
+    let stats = config.enemies.get(kind);
+    let max_health = ((stats.max_health as f32 * difficulty.enemy_health).round() as u32).max(1);
+
+    let mut enemy = commands.spawn((
+        Sprite {
+            image: sprites.enemy_sprite(kind),
+            ..default()
+        },
+        Transform {
+            translation: position.extend(0.0),
+            scale: Vec3::splat(stats.scale),
+            ..default()
+        },
+        Enemy,
+        kind,
+        Velocity(initial_velocity(
+            kind,
+            position.x,
+            stats.speed * difficulty.enemy_speed,
+        )),
+        Collider::enemy(ColliderShape::Circle {
+            radius: stats.collision_radius,
+        }),
+        Damage(config.enemy_contact_damage),
+        Health {
+            current: max_health,
+            max: max_health,
+        },
+        ScoreValue(stats.score),
+    ));
+
+    if let Some(fire) = &stats.fire {
+        let interval = fire.interval / difficulty.fire_rate;
+        let mut timer = Timer::from_seconds(interval, TimerMode::Repeating);
+        let head_start = game_rng.rng().random_range(0.0..interval * 0.5);
+        timer.set_elapsed(std::time::Duration::from_secs_f32(head_start));
+        enemy.insert(EnemyGun {
+            timer,
+            aimed: fire.aimed,
+        });
+    }
+}
+
+/// 사격 주기가 된 적이 적 투사체를 발사하는 시스템입니다.
+///
+/// 플레이 영역 안에 있는 적만 쏩니다.
+/// 조준 사격은 발사 시점의 플레이어 위치를 향하고, 플레이어가 없으면 아래로 쏩니다.
+#[allow(clippy::too_many_arguments)]
+fn enemy_shooting(
+    mut commands: Commands,
+    time: Res<Time>,
+    sprites: Res<SpriteAssets>,
+    playfield: Res<Playfield>,
+    config: Res<GameConfig>,
+    difficulty: Res<Difficulty>,
+    player: Query<&Transform, With<Player>>,
+    mut enemies: Query<(&Transform, &mut EnemyGun), With<Enemy>>,
+) {
+    let player_position = player.single().ok().map(|t| t.translation.truncate());
+    let half_extents = playfield.half_extents();
+
+    for (transform, mut gun) in enemies.iter_mut() {
+        if !gun.timer.tick(time.delta()).just_finished() {
+            continue;
+        }
+
+        let position = transform.translation.truncate();
+        if position.x.abs() > half_extents.x || position.y.abs() > half_extents.y {
+            continue;
+        }
+
+        let direction = match player_position {
+            Some(target) if gun.aimed => (target - position).normalize_or(Vec2::NEG_Y),
+            _ => Vec2::NEG_Y,
+        };
+
+        spawn_hostile_projectile(
+            &mut commands,
+            &sprites,
+            &config,
+            position,
+            direction,
+            config.enemy_projectile_speed * difficulty.bullet_speed,
+        );
     }
 }
 
-/// 모든 적을 속도에 따라 이동시키는 시스템입니다.
-fn enemy_movement(time: Res<Time>, mut query: Query<(&mut Transform, &Velocity), With<Enemy>>) {
-    for (mut transform, velocity) in query.iter_mut() {
-        transform.translation.x += velocity.0.x * time.delta_secs();
-        transform.translation.y += velocity.0.y * time.delta_secs();
+/// 적 종류에 따른 스폰 직후의 속도를 반환합니다.
+///
+/// Diver는 화면 중앙 쪽으로 기울어져 내려오고, 나머지는 똑바로 내려옵니다.
+fn initial_velocity(kind: EnemyKind, spawn_x: f32, speed: f32) -> Vec2 {
+    match kind {
+        EnemyKind::Diver => {
+            let side = if spawn_x > 0.0 { -1.0 } else { 1.0 };
+            Vec2::new(side * DIVE_ANGLE.sin(), -DIVE_ANGLE.cos()) * speed
+        }
+        _ => Vec2::new(0.0, -speed),
     }
 }
 
-/// 화면 아래로 나간 적을 제거하는 시스템입니다.
+/// 종류별 이동 방식에 따라 적의 속도를 바꾸는 시스템입니다.
+///
+/// - Weaver: 내려온 거리에 따라 좌우 속도를 바꿔 사인파 경로를 그립니다.
+///   위치만으로 속도가 정해지므로 별도 상태가 필요 없습니다.
+/// - Kamikaze: 플레이어보다 위에 있는 동안 플레이어 쪽으로 조금씩 방향을 틉니다.
+///   지나친 뒤에는 그대로 직진해 화면 밖으로 나갑니다.
+fn steer_enemies(
+    time: Res<Time>,
+    player: Query<&Transform, With<Player>>,
+    mut enemies: Query<(&EnemyKind, &Transform, &mut Velocity), With<Enemy>>,
+) {
+    let player_position = player.single().ok().map(|t| t.translation.truncate());
+
+    for (kind, transform, mut velocity) in enemies.iter_mut() {
+        let position = transform.translation.truncate();
+
+        match kind {
+            EnemyKind::Weaver => {
+                let wave_number = std::f32::consts::TAU / WEAVE_WAVELENGTH;
+                let fall_speed = -velocity.0.y;
+                velocity.0.x =
+                    WEAVE_AMPLITUDE * wave_number * fall_speed * (wave_number * position.y).cos();
+            }
+            EnemyKind::Kamikaze => {
+                let Some(target) = player_position else {
+                    continue;
+                };
+                if position.y <= target.y {
+                    continue;
+                }
+
+                let max_turn = KAMIKAZE_TURN_RATE * time.delta_secs();
+                let turn = velocity
+                    .0
+                    .angle_to(target - position)
+                    .clamp(-max_turn, max_turn);
+                velocity.0 = Vec2::from_angle(turn).rotate(velocity.0);
+            }
+            EnemyKind::Drone | EnemyKind::Diver | EnemyKind::Bruiser => {}
+        }
+    }
+}
+
+/// 화면 밖으로 나간 적을 제거하는 시스템입니다.
+///
+/// Kamikaze와 Diver는 옆으로도 빠져나가므로 아래쪽과 양옆을 모두 검사합니다.
+/// 위쪽은 대형의 뒤쪽 적이 아직 내려오는 중일 수 있으므로 위로 움직이는 적만 제거합니다.
+/// 보스와 부위는 보스 모듈이 격파와 퇴장 연출 뒤에 정리합니다.
+#[allow(clippy::type_complexity)]
 fn despawn_offscreen_enemies(
     mut commands: Commands,
-    query: Query<(Entity, &Transform), With<Enemy>>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
+    playfield: Res<Playfield>,
+    query: Query<(Entity, &Transform, &Velocity), (With<Enemy>, Without<Boss>, Without<BossPart>)>,
 ) {
-    let Ok(window) = window_query.single() else {
-        return;
-    };
-    let min_y = -window.height() / 2.0 - 50.0;
+    let max = playfield.half_extents() + Vec2::splat(OFFSCREEN_MARGIN);
 
-    for (entity, transform) in query.iter() {
-        if transform.translation.y < min_y {
+    for (entity, transform, velocity) in query.iter() {
+        let position = transform.translation.truncate();
+        let leaving_top = position.y > max.y && velocity.0.y > 0.0;
+        if position.x.abs() > max.x || position.y < -max.y || leaving_top {
             commands.entity(entity).despawn();
         }
     }
 }
+
+/// 피격 번쩍임 시간을 줄이고 스프라이트 색을 바꾸는 시스템입니다.
+///
+/// 번쩍임이 끝나면 원래 색으로 되돌리고 [`HitFlash`]를 제거합니다.
+fn update_hit_flash(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut HitFlash, &mut Sprite)>,
+) {
+    for (entity, mut flash, mut sprite) in query.iter_mut() {
+        if flash.0.tick(time.delta()).is_finished() {
+            sprite.color = Color::WHITE;
+            commands.entity(entity).remove::<HitFlash>();
+        } else {
+            sprite.color = HIT_FLASH_COLOR;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    #[test]
+    fn enemies_are_despawned_past_every_edge() {
+        let mut world = World::new();
+        world.init_resource::<Playfield>();
+        let max = Playfield::default().half_extents() + Vec2::splat(OFFSCREEN_MARGIN);
+        let mut spawn = |position: Vec2, velocity: Vec2| {
+            world
+                .spawn((
+                    Enemy,
+                    Transform::from_translation(position.extend(0.0)),
+                    Velocity(velocity),
+                ))
+                .id()
+        };
+
+        let inside = spawn(Vec2::ZERO, Vec2::NEG_Y);
+        let barely_out = spawn(Vec2::new(max.x - 1.0, 0.0), Vec2::X);
+        // 대형 뒤쪽에서 아직 내려오는 중
+        let entering = spawn(Vec2::new(0.0, max.y + 200.0), Vec2::NEG_Y);
+        let gone = [
+            spawn(Vec2::new(0.0, -max.y - 1.0), Vec2::NEG_Y),
+            spawn(Vec2::new(-max.x - 1.0, 0.0), Vec2::NEG_X),
+            spawn(Vec2::new(max.x + 1.0, -100.0), Vec2::new(1.0, -1.0)),
+            spawn(Vec2::new(0.0, max.y + 1.0), Vec2::Y),
+        ];
+
+        world.run_system_once(despawn_offscreen_enemies).unwrap();
+        for entity in [inside, barely_out, entering] {
+            assert!(world.get_entity(entity).is_ok());
+        }
+        for entity in gone {
+            assert!(world.get_entity(entity).is_err());
+        }
+    }
+}
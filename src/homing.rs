@@ -0,0 +1,112 @@
+//! 유도 모듈
+//!
+//! [`Homing`]이 붙은 플레이어 투사체(유도탄)가 가장 가까운 적을 쫓아가게 합니다.
+//! 유도탄은 보조 무기(미사일 발사기)와 유도 무기가 쏘며, 발사는 `weapon.rs`에서 처리합니다.
+//!
+//! # 유도 방식
+//! 1. 목표 선택: 목표가 없거나 사라졌으면(격추, 보스 격파) 가장 가까운 적을 새 목표로 고름
+//! 2. 방향 전환: 목표 쪽으로 [`Velocity`]를 돌리되, 1틱에 `회전 속도 × 틱 간격`까지만 돌림 (속력은 유지)
+//!
+//! 이동과 스프라이트 방향은 다른 투사체처럼 운동 모듈(`kinematics.rs`)이 처리합니다.
+//!
+//! 적이 하나도 없으면 곧게 날아가며, 유도탄은 수명([`Lifetime`](crate::components::Lifetime))이 다하면 사라집니다.
+
+use bevy::prelude::*;
+
+use crate::components::{Collider, Enemy, Velocity};
+use crate::GameplaySet;
+
+// =============================================================================
+// 유도 컴포넌트
+// =============================================================================
+
+/// 적을 쫓아가는 투사체 컴포넌트입니다.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Homing {
+    /// 쫓고 있는 적
+    pub target: Option<Entity>,
+    /// 1초에 돌 수 있는 최대 각도 (라디안)
+    pub turn_rate: f32,
+}
+
+impl Homing {
+    /// 목표 없이 `turn_rate_degrees`(도/초)로 도는 유도탄을 만듭니다.
+    pub fn new(turn_rate_degrees: f32) -> Self {
+        Self {
+            target: None,
+            turn_rate: turn_rate_degrees.to_radians(),
+        }
+    }
+}
+
+// =============================================================================
+// 유도 플러그인
+// =============================================================================
+
+/// 유도탄의 목표 선택과 방향 전환을 담당하는 플러그인입니다.
+pub struct HomingPlugin;
+
+impl Plugin for HomingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(FixedUpdate, steer_homing.in_set(GameplaySet::Pattern));
+    }
+}
+
+// =============================================================================
+// 시스템 (Systems)
+// =============================================================================
+
+/// 유도탄의 목표를 고르고 목표 쪽으로 방향을 트는 시스템입니다.
+///
+/// 충돌 판정이 없는 적(격파 연출 중인 보스)은 목표로 삼지 않습니다.
+/// 같은 거리의 적이 여럿이면 위치(x, y) 순서로 골라 쿼리 순회 순서와 무관하게 결과가 같습니다.
+/// 엔티티 순서는 위치까지 같을 때만 씁니다.
+#[allow(clippy::type_complexity)]
+fn steer_homing(
+    time: Res<Time>,
+    mut missiles: Query<(&Transform, &mut Velocity, &mut Homing)>,
+    enemies: Query<(Entity, &Transform), (With<Enemy>, With<Collider>, Without<Homing>)>,
+) {
+    let delta = time.delta_secs();
+
+    for (transform, mut velocity, mut homing) in missiles.iter_mut() {
+        let position = transform.translation.truncate();
+
+        let target_position = homing
+            .target
+            .and_then(|target| enemies.get(target).ok())
+            .map(|(_, target_transform)| target_transform.translation.truncate());
+        let target_position = match target_position {
+            Some(target_position) => Some(target_position),
+            None => {
+                let nearest = enemies
+                    .iter()
+                    .map(|(entity, enemy_transform)| {
+                        let enemy_position = enemy_transform.translation.truncate();
+                        (
+                            entity,
+                            enemy_position,
+                            position.distance_squared(enemy_position),
+                        )
+                    })
+                    .min_by(|a, b| {
+                        a.2.total_cmp(&b.2)
+                            .then(a.1.x.total_cmp(&b.1.x))
+                            .then(a.1.y.total_cmp(&b.1.y))
+                            .then(a.0.cmp(&b.0))
+                    });
+                homing.target = nearest.map(|(entity, _, _)| entity);
+                nearest.map(|(_, enemy_position, _)| enemy_position)
+            }
+        };
+
+        if let Some(target_position) = target_position {
+            let desired = target_position - position;
+            if desired != Vec2::ZERO && velocity.0 != Vec2::ZERO {
+                let max_turn = homing.turn_rate * delta;
+                let angle = velocity.0.angle_to(desired).clamp(-max_turn, max_turn);
+                velocity.0 = Vec2::from_angle(angle).rotate(velocity.0);
+            }
+        }
+    }
+}
@@ -0,0 +1,266 @@
+//! 운동 모듈
+//!
+//! [`Velocity`]를 가진 모든 엔티티(투사체, 적, 보스, 아이템)를 한 곳에서 움직입니다.
+//! 어떤 엔티티든 컴포넌트를 붙이기만 하면 같은 규칙으로 움직이므로,
+//! 휘는 탄, 서서히 멈추는 적, 관성이 있는 기체를 이동 시스템 없이 만들 수 있습니다.
+//!
+//! # 적분 순서 (한 틱)
+//! 1. 이전 위치 기록: [`PreviousPosition`]에 이동 전 위치 저장 (연속 충돌 검사용)
+//! 2. 회전: [`AngularVelocity`]만큼 회전
+//! 3. 속도: [`Acceleration`]을 더하고, [`Drag`]만큼 줄이고, [`MaxSpeed`]로 자름
+//! 4. 위치: 바뀐 속도만큼 이동 (준암시적 오일러)
+//! 5. 방향: [`FaceVelocity`]가 있으면 스프라이트가 진행 방향을 향함
+//!
+//! 속도를 정하는 시스템(적 조종, 유도, 탄막 패턴, 보스 연출)은 이동 단계 전이나
+//! [`integrate_velocity`]보다 앞에서 실행되어야 합니다.
+
+use bevy::prelude::*;
+
+use crate::components::{
+    Acceleration, AngularVelocity, Drag, FaceVelocity, MaxSpeed, PreviousPosition, Velocity,
+};
+use crate::GameplaySet;
+
+// =============================================================================
+// 운동 플러그인
+// =============================================================================
+
+/// 속도, 가속도, 저항, 회전을 적분하는 플러그인입니다.
+pub struct KinematicsPlugin;
+
+impl Plugin for KinematicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            (
+                record_previous_positions,
+                integrate_angular_velocity,
+                integrate_velocity,
+            )
+                .chain()
+                .in_set(GameplaySet::Movement),
+        );
+    }
+}
+
+// =============================================================================
+// 시스템 (Systems)
+// =============================================================================
+
+/// 이동하기 전의 위치를 [`PreviousPosition`]에 기록하는 시스템입니다.
+///
+/// 충돌 단계는 기록된 위치에서 이동한 위치까지의 경로로 연속 충돌 검사를 합니다.
+fn record_previous_positions(mut query: Query<(&Transform, &mut PreviousPosition)>) {
+    for (transform, mut previous) in query.iter_mut() {
+        previous.0 = transform.translation.truncate();
+    }
+}
+
+/// [`AngularVelocity`]만큼 엔티티를 회전시키는 시스템입니다.
+fn integrate_angular_velocity(
+    time: Res<Time>,
+    mut query: Query<(&mut Transform, &AngularVelocity)>,
+) {
+    for (mut transform, angular_velocity) in query.iter_mut() {
+        transform.rotate_z(angular_velocity.0 * time.delta_secs());
+    }
+}
+
+/// 가속도, 저항, 최대 속력을 속도에 적용하고 속도만큼 엔티티를 이동시키는 시스템입니다.
+///
+/// 속도를 먼저 바꾸고 바뀐 속도로 이동하므로, 가속도가 없으면 `위치 += 속도 × 틱 간격`과 같습니다.
+#[allow(clippy::type_complexity)]
+pub(crate) fn integrate_velocity(
+    time: Res<Time>,
+    mut query: Query<(
+        &mut Transform,
+        &mut Velocity,
+        Option<&Acceleration>,
+        Option<&Drag>,
+        Option<&MaxSpeed>,
+        Has<FaceVelocity>,
+    )>,
+) {
+    let delta = time.delta_secs();
+
+    for (mut transform, mut velocity, acceleration, drag, max_speed, face_velocity) in
+        query.iter_mut()
+    {
+        if let Some(acceleration) = acceleration {
+            velocity.0 += acceleration.0 * delta;
+        }
+        if let Some(drag) = drag {
+            velocity.0 *= (-drag.0 * delta).exp();
+        }
+        if let Some(max_speed) = max_speed {
+            velocity.0 = velocity.0.clamp_length_max(max_speed.0);
+        }
+
+        transform.translation.x += velocity.0.x * delta;
+        transform.translation.y += velocity.0.y * delta;
+
+        if face_velocity {
+            // 스프라이트는 위쪽을 향하므로 진행 방향으로 회전
+            if let Some(direction) = velocity.0.try_normalize() {
+                transform.rotation = Quat::from_rotation_arc_2d(Vec2::Y, direction);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    /// 한 틱 간격 (초)
+    const DELTA: f32 = 0.5;
+
+    fn world() -> World {
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs_f32(DELTA));
+        world.insert_resource(time);
+        world
+    }
+
+    /// 플러그인과 같은 순서로 한 틱을 진행합니다.
+    fn tick(world: &mut World) {
+        world.run_system_once(record_previous_positions).unwrap();
+        world.run_system_once(integrate_angular_velocity).unwrap();
+        world.run_system_once(integrate_velocity).unwrap();
+    }
+
+    fn assert_close(actual: Vec2, expected: Vec2) {
+        assert!(actual.abs_diff_eq(expected, 1e-3), "{actual} != {expected}");
+    }
+
+    fn state(world: &World, entity: Entity) -> (Vec2, Vec2) {
+        let entity = world.entity(entity);
+        (
+            entity.get::<Transform>().unwrap().translation.truncate(),
+            entity.get::<Velocity>().unwrap().0,
+        )
+    }
+
+    #[test]
+    fn acceleration_changes_velocity_before_moving() {
+        let mut world = world();
+        let entity = world
+            .spawn((
+                Transform::default(),
+                Velocity(Vec2::new(10.0, 0.0)),
+                Acceleration(Vec2::new(0.0, -20.0)),
+                PreviousPosition(Vec2::splat(99.0)),
+            ))
+            .id();
+
+        tick(&mut world);
+        assert_eq!(
+            state(&world, entity),
+            (Vec2::new(5.0, -5.0), Vec2::new(10.0, -10.0))
+        );
+        assert_eq!(world.get::<PreviousPosition>(entity).unwrap().0, Vec2::ZERO);
+
+        tick(&mut world);
+        assert_eq!(
+            state(&world, entity),
+            (Vec2::new(10.0, -15.0), Vec2::new(10.0, -20.0))
+        );
+        assert_eq!(
+            world.get::<PreviousPosition>(entity).unwrap().0,
+            Vec2::new(5.0, -5.0)
+        );
+    }
+
+    #[test]
+    fn drag_halves_speed_every_ln2_seconds() {
+        let mut world = world();
+        let entity = world
+            .spawn((
+                Transform::default(),
+                Velocity(Vec2::new(100.0, 0.0)),
+                Drag(std::f32::consts::LN_2),
+            ))
+            .id();
+
+        // 틱 간격 0.5초 두 번 = 1초
+        tick(&mut world);
+        let (position, velocity) = state(&world, entity);
+        assert_close(velocity, Vec2::new(100.0 / 2f32.sqrt(), 0.0));
+        assert_close(position, velocity * DELTA);
+        tick(&mut world);
+        assert_close(state(&world, entity).1, Vec2::new(50.0, 0.0));
+    }
+
+    #[test]
+    fn max_speed_clamps_after_acceleration_and_keeps_direction() {
+        let mut world = world();
+        let entity = world
+            .spawn((
+                Transform::default(),
+                Velocity(Vec2::new(0.0, 300.0)),
+                Acceleration(Vec2::new(0.0, 400.0)),
+                MaxSpeed(250.0),
+            ))
+            .id();
+        let slow = world
+            .spawn((
+                Transform::default(),
+                Velocity(Vec2::new(30.0, 40.0)),
+                MaxSpeed(250.0),
+            ))
+            .id();
+
+        tick(&mut world);
+        assert_close(state(&world, entity).1, Vec2::new(0.0, 250.0));
+        assert_close(state(&world, entity).0, Vec2::new(0.0, 125.0));
+        // 최대 속력보다 느리면 그대로
+        assert_eq!(state(&world, slow).1, Vec2::new(30.0, 40.0));
+
+        world.get_mut::<Velocity>(entity).unwrap().0 = Vec2::new(-600.0, 800.0);
+        world.entity_mut(entity).remove::<Acceleration>();
+        tick(&mut world);
+        assert_close(state(&world, entity).1, Vec2::new(-150.0, 200.0));
+    }
+
+    #[test]
+    fn face_velocity_points_sprite_along_velocity() {
+        let mut world = world();
+        let entity = world
+            .spawn((
+                Transform::default(),
+                Velocity(Vec2::new(-10.0, 0.0)),
+                // 진행 방향이 회전보다 우선
+                AngularVelocity(1.0),
+                FaceVelocity,
+            ))
+            .id();
+        let spinning = world
+            .spawn((
+                Transform::default(),
+                Velocity(Vec2::ZERO),
+                AngularVelocity(1.0),
+            ))
+            .id();
+
+        tick(&mut world);
+        let facing = |world: &World, entity: Entity| {
+            (world.get::<Transform>(entity).unwrap().rotation * Vec3::Y).truncate()
+        };
+        assert_close(facing(&world, entity), Vec2::NEG_X);
+        assert_close(
+            facing(&world, spinning),
+            Vec2::from_angle(DELTA).rotate(Vec2::Y),
+        );
+
+        // 멈추면 마지막 방향을 유지
+        world.get_mut::<Velocity>(entity).unwrap().0 = Vec2::ZERO;
+        world.entity_mut(entity).remove::<AngularVelocity>();
+        tick(&mut world);
+        assert_close(facing(&world, entity), Vec2::NEG_X);
+    }
+}
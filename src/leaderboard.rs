@@ -0,0 +1,441 @@
+//! 리더보드 모듈
+//!
+//! 상위 N개의 기록(닉네임, 점수, 날짜, 플레이 시간)을 디스크에 저장하고 불러옵니다.
+//! 파일은 플랫폼 데이터 폴더 아래에 저장됩니다.
+//! - Windows: `%APPDATA%\oxide-rain\leaderboard.ron`
+//! - macOS: `~/Library/Application Support/oxide-rain/leaderboard.ron`
+//! - Linux: `~/.local/share/oxide-rain/leaderboard.ron`
+//!
+//! 저장은 임시 파일에 쓰고 디스크에 확실히 기록(`sync_all`)한 뒤 이름을 바꾸는 방식이라
+//! 중간에 꺼져도 기존 파일이 깨지지 않습니다.
+//! 파일이 손상되었으면 `.corrupt`로 옮겨 두고 빈 리더보드로 시작합니다.
+//! 다른 버전(더 새 빌드)이 쓴 파일은 건드리지 않고, 그 실행 동안에는 기록을 저장하지 않습니다.
+
+use std::fmt;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::HighScore;
+
+// =============================================================================
+// 상수
+// =============================================================================
+
+/// 현재 리더보드 파일 버전
+pub const LEADERBOARD_VERSION: u32 = 1;
+/// 리더보드에 남기는 최대 기록 수
+pub const LEADERBOARD_SIZE: usize = 10;
+/// 데이터 폴더 아래의 게임 폴더 이름
+pub const DATA_DIR_NAME: &str = "oxide-rain";
+/// 리더보드 파일 이름
+pub const LEADERBOARD_FILE: &str = "leaderboard.ron";
+
+// =============================================================================
+// 리더보드 데이터
+// =============================================================================
+
+/// 리더보드의 기록 하나입니다.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LeaderboardEntry {
+    /// 플레이어 닉네임
+    pub name: String,
+    /// 최종 점수
+    pub score: u32,
+    /// 기록 시각 (유닉스 시간, 초)
+    pub recorded_at: u64,
+    /// 플레이 시간 (초)
+    pub duration_secs: f32,
+}
+
+impl LeaderboardEntry {
+    /// 지금 시각으로 새 기록을 만듭니다.
+    pub fn now(name: String, score: u32, duration: Duration) -> Self {
+        let recorded_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+
+        Self {
+            name,
+            score,
+            recorded_at,
+            duration_secs: duration.as_secs_f32(),
+        }
+    }
+}
+
+/// 파일에 저장되는 형식입니다.
+#[derive(Serialize, Deserialize)]
+struct LeaderboardFile {
+    version: u32,
+    entries: Vec<LeaderboardEntry>,
+}
+
+/// 기록 형식과 상관없이 버전만 먼저 읽기 위한 형식입니다.
+#[derive(Deserialize)]
+struct LeaderboardHeader {
+    version: u32,
+}
+
+/// 점수 순으로 정렬된 상위 기록을 저장하는 리소스입니다.
+#[derive(Resource, Debug, Default)]
+pub struct Leaderboard {
+    /// 점수 내림차순 기록 (최대 [`LEADERBOARD_SIZE`]개)
+    pub entries: Vec<LeaderboardEntry>,
+    /// 저장 파일 경로. `None`이면 디스크에 저장하지 않습니다.
+    pub path: Option<PathBuf>,
+    /// 마지막으로 제출한 판의 순위 (순위에 들지 못했으면 `None`, 파일에는 저장하지 않음)
+    pub last_rank: Option<usize>,
+}
+
+impl Leaderboard {
+    /// 플랫폼 데이터 폴더의 리더보드 파일 경로를 반환합니다.
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::data_dir().map(|dir| dir.join(DATA_DIR_NAME).join(LEADERBOARD_FILE))
+    }
+
+    /// 파일에서 리더보드를 읽어옵니다.
+    ///
+    /// 파일이 없으면 빈 리더보드를 반환합니다.
+    /// 파일이 손상되었으면 `.corrupt` 확장자로 옮겨 두고 빈 리더보드를 반환합니다.
+    /// 버전이 다르면 파일을 그대로 두고, 저장 경로가 없는(저장하지 않는) 빈 리더보드를 반환합니다.
+    pub fn load_or_default(path: Option<PathBuf>) -> Self {
+        let Some(path) = path else {
+            return Self::default();
+        };
+
+        let entries = match read_entries(&path) {
+            Ok(entries) => entries,
+            Err(LeaderboardError::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+                Vec::new()
+            }
+            Err(err @ LeaderboardError::UnsupportedVersion(_)) => {
+                warn!(
+                    "Leaderboard file {} was written by another version, records will not be saved: {err}",
+                    path.display()
+                );
+                return Self::default();
+            }
+            Err(err) => {
+                warn!(
+                    "Leaderboard file {} is unreadable, starting fresh: {err}",
+                    path.display()
+                );
+                let backup = path.with_extension("ron.corrupt");
+                if let Err(err) = fs::rename(&path, &backup) {
+                    warn!("Failed to back up corrupt leaderboard: {err}");
+                }
+                Vec::new()
+            }
+        };
+
+        let mut leaderboard = Self {
+            entries,
+            path: Some(path),
+            last_rank: None,
+        };
+        leaderboard.normalize();
+        leaderboard
+    }
+
+    /// 리더보드를 파일에 저장합니다.
+    ///
+    /// 임시 파일에 먼저 쓰고 디스크에 기록을 마친 뒤 이름을 바꾸므로
+    /// 저장 도중 실패하거나 전원이 꺼져도 기존 파일은 그대로 남습니다.
+    pub fn save(&self) -> Result<(), LeaderboardError> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        let file = LeaderboardFile {
+            version: LEADERBOARD_VERSION,
+            entries: self.entries.clone(),
+        };
+        let text = ron::ser::to_string_pretty(&file, ron::ser::PrettyConfig::default())?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let temp_path = path.with_extension("ron.tmp");
+        let mut temp_file = fs::File::create(&temp_path)?;
+        temp_file.write_all(text.as_bytes())?;
+        temp_file.sync_all()?;
+        drop(temp_file);
+        fs::rename(&temp_path, path)?;
+        Ok(())
+    }
+
+    /// 새 기록을 추가하고, 순위에 들었으면 0부터 시작하는 순위를 반환합니다.
+    ///
+    /// 같은 점수라면 먼저 세운 기록이 앞에 옵니다.
+    /// 결과는 [`Leaderboard::last_rank`]에도 기록됩니다.
+    pub fn submit(&mut self, entry: LeaderboardEntry) -> Option<usize> {
+        let rank = self
+            .entries
+            .iter()
+            .position(|existing| existing.score < entry.score)
+            .unwrap_or(self.entries.len());
+
+        if rank >= LEADERBOARD_SIZE {
+            self.last_rank = None;
+            return None;
+        }
+
+        self.entries.insert(rank, entry);
+        self.entries.truncate(LEADERBOARD_SIZE);
+        self.last_rank = Some(rank);
+        Some(rank)
+    }
+
+    /// 최고 점수를 반환합니다. 기록이 없으면 0입니다.
+    pub fn best_score(&self) -> u32 {
+        self.entries.first().map_or(0, |entry| entry.score)
+    }
+
+    /// 손으로 고친 파일도 올바르게 보이도록 정렬하고 개수를 제한합니다.
+    fn normalize(&mut self) {
+        self.entries
+            .sort_by_key(|entry| std::cmp::Reverse(entry.score));
+        self.entries.truncate(LEADERBOARD_SIZE);
+    }
+}
+
+/// 파일에서 기록 목록을 읽습니다.
+///
+/// 다른 버전의 기록 형식은 읽을 수 없을 수도 있으므로 버전을 먼저 확인합니다.
+fn read_entries(path: &Path) -> Result<Vec<LeaderboardEntry>, LeaderboardError> {
+    let text = fs::read_to_string(path)?;
+    let header: LeaderboardHeader = ron::from_str(&text)?;
+    if header.version != LEADERBOARD_VERSION {
+        return Err(LeaderboardError::UnsupportedVersion(header.version));
+    }
+    let file: LeaderboardFile = ron::from_str(&text)?;
+    Ok(file.entries)
+}
+
+/// 리더보드를 읽거나 쓸 때 발생하는 오류입니다.
+#[derive(Debug)]
+pub enum LeaderboardError {
+    /// 파일 입출력 오류
+    Io(std::io::Error),
+    /// 파일 형식 오류
+    Parse(ron::error::SpannedError),
+    /// 직렬화 오류
+    Serialize(ron::Error),
+    /// 지원하지 않는 파일 버전
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for LeaderboardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "입출력 오류: {err}"),
+            Self::Parse(err) => write!(f, "리더보드 파일 형식 오류: {err}"),
+            Self::Serialize(err) => write!(f, "리더보드 저장 오류: {err}"),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "지원하지 않는 리더보드 버전입니다: {version} (현재 {LEADERBOARD_VERSION})"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LeaderboardError {}
+
+impl From<std::io::Error> for LeaderboardError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<ron::error::SpannedError> for LeaderboardError {
+    fn from(err: ron::error::SpannedError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+impl From<ron::Error> for LeaderboardError {
+    fn from(err: ron::Error) -> Self {
+        Self::Serialize(err)
+    }
+}
+
+// =============================================================================
+// 리더보드 플러그인
+// =============================================================================
+
+/// 리더보드를 시작 시 디스크에서 불러오는 플러그인입니다.
+///
+/// 디스크에 접근하므로 [`crate::GamePlugins`]에는 포함되지 않습니다.
+/// 게임 바이너리에서 UI와 함께 추가합니다.
+pub struct LeaderboardPlugin;
+
+impl Plugin for LeaderboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Leaderboard::load_or_default(Leaderboard::default_path()))
+            .add_systems(Startup, sync_high_score);
+    }
+}
+
+/// 리더보드의 최고 점수를 [`HighScore`]에 반영하는 시스템입니다.
+fn sync_high_score(leaderboard: Res<Leaderboard>, mut high_score: ResMut<HighScore>) {
+    high_score.0 = leaderboard.best_score();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 테스트가 끝나면 지워지는 임시 폴더입니다.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!(
+                "oxide-rain-leaderboard-{}-{name}",
+                std::process::id()
+            ));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+
+        fn file(&self) -> PathBuf {
+            self.0.join(LEADERBOARD_FILE)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn entry(name: &str, score: u32) -> LeaderboardEntry {
+        LeaderboardEntry {
+            name: name.to_string(),
+            score,
+            recorded_at: 1_700_000_000,
+            duration_secs: 61.5,
+        }
+    }
+
+    fn names(leaderboard: &Leaderboard) -> Vec<&str> {
+        leaderboard
+            .entries
+            .iter()
+            .map(|entry| entry.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn submit_orders_by_score_and_keeps_earlier_ties_first() {
+        let mut leaderboard = Leaderboard::default();
+        assert_eq!(leaderboard.submit(entry("a", 100)), Some(0));
+        assert_eq!(leaderboard.submit(entry("b", 300)), Some(0));
+        assert_eq!(leaderboard.submit(entry("c", 200)), Some(1));
+        assert_eq!(leaderboard.submit(entry("d", 200)), Some(2));
+        assert_eq!(leaderboard.submit(entry("e", 100)), Some(4));
+
+        assert_eq!(names(&leaderboard), ["b", "c", "d", "a", "e"]);
+        assert_eq!(leaderboard.last_rank, Some(4));
+        assert_eq!(leaderboard.best_score(), 300);
+    }
+
+    #[test]
+    fn submit_keeps_only_the_top_entries() {
+        let mut leaderboard = Leaderboard::default();
+        for score in 1..=LEADERBOARD_SIZE as u32 + 2 {
+            leaderboard.submit(entry(&score.to_string(), score * 10));
+        }
+        assert_eq!(leaderboard.entries.len(), LEADERBOARD_SIZE);
+        assert_eq!(leaderboard.entries.last().unwrap().score, 30);
+
+        // 꼴찌와 같은 점수는 뒤에 붙으므로 순위 밖
+        assert_eq!(leaderboard.submit(entry("tie", 30)), None);
+        assert_eq!(leaderboard.last_rank, None);
+        assert_eq!(leaderboard.submit(entry("low", 5)), None);
+        assert_eq!(leaderboard.submit(entry("top", 1000)), Some(0));
+        assert_eq!(leaderboard.entries.len(), LEADERBOARD_SIZE);
+        assert_eq!(leaderboard.entries.last().unwrap().score, 40);
+    }
+
+    #[test]
+    fn save_then_load_round_trips() {
+        let dir = TempDir::new("round-trip");
+        let mut leaderboard = Leaderboard::load_or_default(Some(dir.file()));
+        assert!(leaderboard.entries.is_empty());
+        assert_eq!(leaderboard.path, Some(dir.file()));
+
+        leaderboard.submit(entry("a", 100));
+        leaderboard.submit(entry("b", 300));
+        leaderboard.save().unwrap();
+        assert!(!dir.file().with_extension("ron.tmp").exists());
+
+        let loaded = Leaderboard::load_or_default(Some(dir.file()));
+        assert_eq!(loaded.entries, leaderboard.entries);
+        assert_eq!(loaded.last_rank, None);
+    }
+
+    #[test]
+    fn hand_edited_file_is_sorted_and_truncated() {
+        let dir = TempDir::new("hand-edited");
+        let file = LeaderboardFile {
+            version: LEADERBOARD_VERSION,
+            entries: (0..LEADERBOARD_SIZE as u32 + 3)
+                .map(|score| entry(&score.to_string(), score))
+                .collect(),
+        };
+        fs::write(dir.file(), ron::to_string(&file).unwrap()).unwrap();
+
+        let loaded = Leaderboard::load_or_default(Some(dir.file()));
+        assert_eq!(loaded.entries.len(), LEADERBOARD_SIZE);
+        assert_eq!(loaded.best_score(), LEADERBOARD_SIZE as u32 + 2);
+        assert!(loaded
+            .entries
+            .windows(2)
+            .all(|pair| pair[0].score >= pair[1].score));
+    }
+
+    #[test]
+    fn corrupt_file_is_moved_aside_and_replaced() {
+        let dir = TempDir::new("corrupt");
+        fs::write(dir.file(), "(version: 1, entries: [").unwrap();
+
+        let mut leaderboard = Leaderboard::load_or_default(Some(dir.file()));
+        assert!(leaderboard.entries.is_empty());
+        let backup = dir.file().with_extension("ron.corrupt");
+        assert_eq!(
+            fs::read_to_string(&backup).unwrap(),
+            "(version: 1, entries: ["
+        );
+
+        leaderboard.submit(entry("a", 100));
+        leaderboard.save().unwrap();
+        assert_eq!(
+            Leaderboard::load_or_default(Some(dir.file())).entries,
+            leaderboard.entries
+        );
+    }
+
+    #[test]
+    fn other_version_is_left_untouched() {
+        let dir = TempDir::new("newer");
+        let newer = "(version: 2, entries: [(name: \"a\", points: 5)], extra: true)";
+        fs::write(dir.file(), newer).unwrap();
+
+        let mut leaderboard = Leaderboard::load_or_default(Some(dir.file()));
+        assert!(leaderboard.entries.is_empty());
+        assert_eq!(leaderboard.path, None);
+
+        leaderboard.submit(entry("b", 100));
+        leaderboard.save().unwrap();
+        assert_eq!(fs::read_to_string(dir.file()).unwrap(), newer);
+        assert!(!dir.file().with_extension("ron.corrupt").exists());
+    }
+}
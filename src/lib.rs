@@ -0,0 +1,406 @@
+//! Oxide Rain - 2D 탑다운 슈팅 게임
+//!
+//! Bevy 엔진을 사용한 완전한 게임 루프를 갖춘 슈팅 게임입니다.
+//! 게임 로직은 이 라이브러리 크레이트에 있으며, `main.rs` 바이너리는
+//! 창과 렌더링, UI를 붙여 실행하는 역할만 합니다.
+//!
+//! # 게임 상태 (AppState)
+//! - MainMenu: 닉네임 입력 및 게임 시작 화면
+//! - InGame: 게임 플레이 중
+//! - GameOver: 게임 오버 화면 (닉네임과 함께 결과 표시)
+//! - Replay: 녹화된 입력으로 판을 재생
+//! - Leaderboard: 저장된 상위 기록 목록
+//!
+//! InGame과 Replay는 계산된 상태 [`resources::Playing`]으로 묶여 같은 게임플레이 로직을 공유합니다.
+//! InGame에는 일시정지를 위한 하위 상태 [`resources::PauseState`]가 있습니다.
+//!
+//! # 고정 틱 시뮬레이션
+//! 이동, 스폰, 충돌, 점수 등 모든 게임플레이는 `FixedUpdate`에서
+//! [`GameplaySet`] 순서대로 실행됩니다. 난수는 [`resources::GameRng`]에서만 뽑으므로
+//! 같은 시드와 같은 입력이면 항상 같은 판이 재현됩니다.
+//!
+//! # 헤드리스 실행
+//! [`headless_app`]은 `DefaultPlugins` 없이 `MinimalPlugins` 위에
+//! [`GamePlugins`]만 올린 앱을 만듭니다. 창이나 GPU 없이 시뮬레이션을
+//! 돌릴 수 있으므로 통합 테스트나 외부 도구에서 사용할 수 있습니다.
+//!
+//! # 프로젝트 구조
+//! ```text
+//! src/
+//! ├── lib.rs         - 라이브러리 진입점, GamePlugins, 헤드리스 앱
+//! ├── main.rs        - 바이너리 진입점, 창/카메라/UI 설정
+//! ├── components.rs  - 공유 컴포넌트 정의
+//! ├── resources.rs   - 전역 리소스 및 기본 상수, AppState, PlayerName
+//! ├── config.rs      - 게임 설정 파일 (GameConfig) 로드 및 핫 리로드
+//! ├── player.rs      - 플레이어 로직
+//! ├── weapon.rs      - 플레이어 무기 (발사 간격, 탄 배치, 강화 단계, 무기 교체, 보조 미사일)
+//! ├── homing.rs      - 유도탄 (목표 선택, 회전 속도 제한, 재조준)
+//! ├── kinematics.rs  - 운동 적분 (속도, 가속도, 저항, 최대 속력, 회전, 진행 방향)
+//! ├── projectile.rs  - 투사체 로직
+//! ├── enemy.rs       - 적 로직
+//! ├── pattern.rs     - 탄막 패턴 에셋과 실행기
+//! ├── stage.rs       - 스테이지 타임라인 에셋과 실행기
+//! ├── boss.rs        - 다단계 보스 (등장/퇴장 연출, 부위 판정, 공격 단계)
+//! ├── rank.rs        - 숨은 랭크 (실력에 따른 난이도 조절)와 판 기록
+//! ├── powerup.rs     - 적이 떨어뜨리는 파워업 아이템과 효과
+//! ├── shape.rs       - 충돌 도형 (원, AABB, OBB, 캡슐) 겹침 검사와 선분 질의
+//! ├── spatial.rs     - 충돌 후보를 찾는 공간 격자 (매 틱 재구성)
+//! ├── collision.rs   - 충돌 감지 (Hit 메시지)
+//! ├── damage.rs      - 충돌 결과 처리 (피해, 공격자 소모, 번쩍임, 점수)
+//! ├── pause.rs       - 일시정지 (PauseState, 가상 시간 정지)
+//! ├── replay.rs      - 입력 녹화 및 리플레이 재생
+//! ├── leaderboard.rs - 디스크에 저장되는 상위 기록 (리더보드)
+//! └── ui.rs          - 메뉴, HUD, 게임 오버 UI
+//! ```
+//!
+//! # 에셋 구조
+//! ```text
+//! assets/
+//! ├── config/
+//! │   └── game.ron   - 게임 밸런스 설정 (핫 리로드)
+//! ├── patterns/      - 탄막 패턴 (*.pattern.ron)
+//! ├── stages/        - 스테이지 타임라인 (*.stage.ron)
+//! ├── fonts/
+//! │   └── font.ttf   - 한글 지원 폰트
+//! ├── player.png     - 플레이어 스프라이트
+//! ├── enemy.png      - 적 스프라이트 (기본 드론)
+//! ├── enemies/       - 적 종류별 스프라이트 (weaver, diver, kamikaze, bruiser, boss)
+//! └── bullet.png     - 투사체 스프라이트
+//! ```
+
+use bevy::{app::PluginGroupBuilder, input::InputPlugin, prelude::*, state::app::StatesPlugin};
+
+// =============================================================================
+// 모듈 선언
+// =============================================================================
+
+pub mod boss;
+pub mod collision;
+pub mod components;
+pub mod config;
+pub mod damage;
+pub mod enemy;
+pub mod homing;
+pub mod kinematics;
+pub mod leaderboard;
+pub mod pattern;
+pub mod pause;
+pub mod player;
+pub mod powerup;
+pub mod projectile;
+pub mod rank;
+pub mod replay;
+pub mod resources;
+pub mod shape;
+pub mod spatial;
+pub mod stage;
+pub mod ui;
+pub mod weapon;
+
+use rand::RngExt;
+
+use replay::ReplayPlayback;
+
+use resources::{
+    AppState, Difficulty, EnemySpawnTimer, FixedSeed, GameRng, HighScore, IsNewRecord, PauseState,
+    PlayerInput, PlayerName, Playfield, Playing, RunDuration, Score, SpriteAssets, FIXED_TICK_RATE,
+};
+
+// =============================================================================
+// 시스템 세트
+// =============================================================================
+
+/// `FixedUpdate`에서 실행되는 게임플레이 시스템의 실행 순서입니다.
+///
+/// 세트는 선언 순서대로 체인되어 매 틱 같은 순서로 실행됩니다.
+/// 실행 순서가 프레임마다 달라지면 결과도 달라지므로,
+/// 새 게임플레이 시스템은 반드시 이 중 하나에 넣어야 합니다.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameplaySet {
+    /// 이번 틱 입력 확정 (녹화 또는 리플레이 재생)
+    TickInput,
+    /// 플레이어 입력 적용 (이동, 발사)
+    Input,
+    /// 적 스폰 (스테이지 타임라인 또는 무작위)
+    Spawn,
+    /// 탄막 패턴 실행 (패턴 탄 발사, 탄의 속도/방향 변화)
+    Pattern,
+    /// 속도에 따른 이동
+    Movement,
+    /// 충돌 감지 및 점수
+    Collision,
+    /// 화면 밖 엔티티 정리
+    Cleanup,
+}
+
+// =============================================================================
+// 게임 플러그인 그룹
+// =============================================================================
+
+/// 게임플레이에 필요한 플러그인을 모아놓은 플러그인 그룹입니다.
+///
+/// 창, 렌더링, UI에 의존하지 않으므로 `DefaultPlugins`와 `MinimalPlugins`
+/// 어느 쪽 위에도 올릴 수 있습니다. UI가 필요하면 [`ui::UiPlugin`]을
+/// 따로 추가합니다.
+pub struct GamePlugins;
+
+impl PluginGroup for GamePlugins {
+    fn build(self) -> PluginGroupBuilder {
+        PluginGroupBuilder::start::<Self>()
+            .add(CorePlugin::default())
+            .add(config::ConfigPlugin)
+            .add(player::PlayerPlugin)
+            .add(weapon::WeaponPlugin)
+            .add(homing::HomingPlugin)
+            .add(kinematics::KinematicsPlugin)
+            .add(projectile::ProjectilePlugin)
+            .add(enemy::EnemyPlugin)
+            .add(pattern::PatternPlugin)
+            .add(stage::StagePlugin)
+            .add(boss::BossPlugin)
+            .add(rank::RankPlugin)
+            .add(powerup::PowerUpPlugin)
+            .add(spatial::SpatialPlugin)
+            .add(collision::CollisionPlugin)
+            .add(damage::DamagePlugin)
+            .add(replay::ReplayPlugin)
+            .add(pause::PausePlugin)
+    }
+}
+
+/// 게임 상태와 전역 리소스, 고정 틱 설정을 등록하는 플러그인입니다.
+///
+/// 틱 속도를 바꾸려면 `GamePlugins.set(CorePlugin { tick_rate_hz: 120.0 })`처럼 설정합니다.
+pub struct CorePlugin {
+    /// 초당 고정 틱 수
+    pub tick_rate_hz: f64,
+}
+
+impl Default for CorePlugin {
+    fn default() -> Self {
+        Self {
+            tick_rate_hz: FIXED_TICK_RATE,
+        }
+    }
+}
+
+impl Plugin for CorePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // ─────────────────────────────────────────────────────────────────
+            // 상태 설정
+            // ─────────────────────────────────────────────────────────────────
+            .init_state::<AppState>()
+            .add_computed_state::<Playing>()
+            // ─────────────────────────────────────────────────────────────────
+            // 고정 틱 설정
+            // ─────────────────────────────────────────────────────────────────
+            .insert_resource(Time::<Fixed>::from_hz(self.tick_rate_hz))
+            .configure_sets(
+                FixedUpdate,
+                (
+                    GameplaySet::TickInput,
+                    GameplaySet::Input,
+                    GameplaySet::Spawn,
+                    GameplaySet::Pattern,
+                    GameplaySet::Movement,
+                    GameplaySet::Collision,
+                    GameplaySet::Cleanup,
+                )
+                    .chain()
+                    .run_if(
+                        in_state(Playing)
+                            .and(not(in_state(PauseState::Paused)))
+                            .and(no_pending_transition),
+                    ),
+            )
+            // ─────────────────────────────────────────────────────────────────
+            // 리소스 초기화
+            // ─────────────────────────────────────────────────────────────────
+            .init_resource::<Playfield>()
+            .init_resource::<SpriteAssets>()
+            .init_resource::<GameRng>()
+            .init_resource::<FixedSeed>()
+            .init_resource::<PlayerInput>()
+            .init_resource::<EnemySpawnTimer>()
+            .init_resource::<Difficulty>()
+            .init_resource::<Score>()
+            .init_resource::<RunDuration>()
+            .init_resource::<HighScore>()
+            .init_resource::<IsNewRecord>()
+            .init_resource::<PlayerName>() // 플레이어 닉네임 리소스
+            .add_systems(
+                PreStartup,
+                load_sprite_assets.run_if(resource_exists::<Assets<Image>>),
+            )
+            .add_systems(OnEnter(Playing), (reseed_game_rng, reset_score))
+            .add_systems(
+                FixedUpdate,
+                advance_run_duration.in_set(GameplaySet::Cleanup),
+            );
+    }
+}
+
+/// 창 없이 게임 시뮬레이션만 돌리는 앱을 생성합니다.
+///
+/// `MinimalPlugins`에 상태와 입력 플러그인, [`GamePlugins`]를 더한 구성입니다.
+/// `app.update()`를 호출할 때마다 한 프레임씩 진행되며,
+/// `ButtonInput<KeyCode>`에 직접 키를 눌러 플레이어를 조작할 수 있습니다.
+pub fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin, InputPlugin, GamePlugins));
+    app
+}
+
+// =============================================================================
+// 전역 시스템
+// =============================================================================
+
+/// 상태 전환이 대기 중이 아닐 때만 참인 실행 조건입니다.
+///
+/// 게임 오버가 요청된 뒤에도 같은 프레임 안에서 고정 틱이 더 돌면
+/// 프레임 속도에 따라 점수가 달라지므로, 전환이 끝날 때까지 틱을 멈춥니다.
+fn no_pending_transition(next_state: Res<NextState<AppState>>) -> bool {
+    matches!(*next_state, NextState::Unchanged)
+}
+
+/// 판 시작 시 난수 생성기를 새 시드로 초기화하는 시스템입니다.
+///
+/// 리플레이 중이면 녹화된 시드를, [`FixedSeed`]가 설정되어 있으면 그 시드를,
+/// 아니면 무작위 시드를 사용합니다.
+fn reseed_game_rng(
+    fixed_seed: Res<FixedSeed>,
+    playback: Option<Res<ReplayPlayback>>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    let seed = match (playback, fixed_seed.0) {
+        (Some(playback), _) => playback.replay.seed,
+        (None, Some(seed)) => seed,
+        (None, None) => rand::rng().random(),
+    };
+    *game_rng = GameRng::new(seed);
+}
+
+/// 판 시작 시 점수와 플레이 시간을 0으로 초기화하는 시스템입니다.
+fn reset_score(mut score: ResMut<Score>, mut run_duration: ResMut<RunDuration>) {
+    score.0 = 0;
+    run_duration.0 = std::time::Duration::ZERO;
+}
+
+/// 고정 틱마다 플레이 시간을 늘리는 시스템입니다.
+fn advance_run_duration(time: Res<Time>, mut run_duration: ResMut<RunDuration>) {
+    run_duration.0 += time.delta();
+}
+
+/// 스프라이트 이미지를 로드하여 [`SpriteAssets`]에 저장하는 시스템입니다.
+///
+/// 이미지 에셋을 다룰 수 없는 헤드리스 앱에서는 실행되지 않으며, 기본 핸들을 그대로 둡니다.
+fn load_sprite_assets(asset_server: Res<AssetServer>, mut sprites: ResMut<SpriteAssets>) {
+    sprites.player = asset_server.load("player.png");
+    sprites.enemy = asset_server.load("enemy.png");
+    sprites.enemy_weaver = asset_server.load("enemies/weaver.png");
+    sprites.enemy_diver = asset_server.load("enemies/diver.png");
+    sprites.enemy_kamikaze = asset_server.load("enemies/kamikaze.png");
+    sprites.enemy_bruiser = asset_server.load("enemies/bruiser.png");
+    sprites.boss = asset_server.load("enemies/boss.png");
+    sprites.bullet = asset_server.load("bullet.png");
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::components::{Enemy, Player, Projectile};
+
+    /// 프레임마다 고정 틱이 정확히 한 번 도는 헤드리스 앱을 `seed`로 만듭니다.
+    pub(crate) fn stepped_app(seed: u64) -> App {
+        let mut app = headless_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+            1.0 / FIXED_TICK_RATE,
+        )))
+        .insert_resource(FixedSeed(Some(seed)));
+        app.update();
+        app
+    }
+
+    /// `frame`번째 프레임에 누르고 있을 키 (발사는 계속, 좌우로 오가며 가끔 무기 교체)
+    pub(crate) fn scripted_keys(frame: usize) -> Vec<KeyCode> {
+        let mut keys = vec![KeyCode::Space];
+        match frame % 240 {
+            0..60 => keys.push(KeyCode::ArrowLeft),
+            60..180 => keys.push(KeyCode::ArrowRight),
+            180..200 => keys.push(KeyCode::ArrowUp),
+            _ => keys.push(KeyCode::ArrowLeft),
+        }
+        if frame % 600 == 300 {
+            keys.push(KeyCode::KeyQ);
+        }
+        keys
+    }
+
+    /// 키보드를 `scripted_keys`대로 누르며 `frames` 프레임을 진행합니다.
+    pub(crate) fn play_scripted(app: &mut App, frames: usize) {
+        for frame in 0..frames {
+            let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keyboard.release_all();
+            for key in scripted_keys(frame) {
+                keyboard.press(key);
+            }
+            app.update();
+        }
+    }
+
+    /// 플레이어, 적, 투사체의 위치를 정렬해 모읍니다.
+    fn snapshot(app: &mut App) -> (u32, Vec<(u8, Vec3)>) {
+        let world = app.world_mut();
+        let mut positions: Vec<(u8, Vec3)> = world
+            .query::<(&Transform, Has<Player>, Has<Enemy>, Has<Projectile>)>()
+            .iter(world)
+            .filter_map(|(transform, player, enemy, projectile)| {
+                let tag = match (player, enemy, projectile) {
+                    (true, _, _) => 0,
+                    (_, true, _) => 1,
+                    (_, _, true) => 2,
+                    _ => return None,
+                };
+                Some((tag, transform.translation))
+            })
+            .collect();
+        positions.sort_by(|a, b| {
+            a.0.cmp(&b.0)
+                .then(a.1.x.total_cmp(&b.1.x))
+                .then(a.1.y.total_cmp(&b.1.y))
+        });
+        (world.resource::<Score>().0, positions)
+    }
+
+    fn run(seed: u64) -> (u32, Vec<(u8, Vec3)>) {
+        let mut app = stepped_app(seed);
+        app.world_mut()
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::InGame);
+        play_scripted(&mut app, 1200);
+        snapshot(&mut app)
+    }
+
+    #[test]
+    fn same_seed_and_inputs_reproduce_the_run() {
+        let (score, positions) = run(42);
+        assert!(score > 0, "no enemy destroyed");
+        assert!(
+            positions.iter().any(|(tag, _)| *tag == 1),
+            "no enemies spawned"
+        );
+        assert!(
+            positions.iter().any(|(tag, _)| *tag == 2),
+            "no projectiles fired"
+        );
+
+        assert_eq!(run(42), (score, positions.clone()));
+        // 시드가 다르면 다른 판
+        assert_ne!(run(7).1, positions);
+    }
+}
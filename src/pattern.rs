@@ -0,0 +1,1112 @@
+//! 탄막 패턴 모듈
+//!
+//! BulletML을 본뜬 작은 선언형 탄막 언어입니다.
+//! 패턴은 `assets/patterns/*.pattern.ron` 파일에서 [`BulletPattern`] 에셋으로 읽고,
+//! 이미터 엔티티마다 [`PatternRunner`]가 한 고정 틱씩 실행합니다.
+//!
+//! # 동작 ([`Action`])
+//! - `Fire`: 탄 발사. `count`와 `spread`로 부채꼴이나 원형(360도)으로 여러 발을 쏩니다.
+//!   `actions`를 주면 발사된 탄이 그 동작을 직접 실행합니다 (속도/방향 변화, 하위 탄 발사).
+//! - `Wait`: 지정한 시간(초)만큼 대기
+//! - `Repeat`: 동작 목록을 정해진 횟수만큼 반복 (중첩 가능)
+//! - `Loop`: 동작 목록을 끝없이 반복
+//! - `ChangeSpeed`, `ChangeDirection`: 주어진 시간 동안 속력이나 방향을 서서히 바꿈
+//! - `Vanish`: 실행 중인 탄을 제거
+//!
+//! # 방향 ([`PatternDirection`])
+//! 각도는 도(degree) 단위이며 0도는 아래, 90도는 오른쪽입니다 (반시계 방향이 양수).
+//! - `Absolute(각도)`: 화면 기준 절대 방향
+//! - `Aim(오프셋)`: 플레이어를 향한 방향 + 오프셋
+//! - `Relative(오프셋)`: 실행 중인 엔티티의 진행 방향 + 오프셋
+//! - `Sequence(증분)`: 직전에 발사한 방향 + 증분 (나선 패턴용)
+//!
+//! # 패턴 예시
+//! ```text
+//! (
+//!     actions: [
+//!         // 10도씩 돌아가며 쏘는 나선
+//!         Repeat(times: 36, actions: [
+//!             Fire(direction: Sequence(10.0), speed: 150.0),
+//!             Wait(0.05),
+//!         ]),
+//!         // 내려가다 멈춘 뒤 8방향으로 터지는 탄
+//!         Fire(direction: Aim(0.0), speed: 160.0, actions: [
+//!             ChangeSpeed(speed: 0.0, duration: 0.5),
+//!             Wait(0.5),
+//!             Fire(direction: Relative(0.0), speed: 180.0, count: 8, spread: 360.0),
+//!             Vanish,
+//!         ]),
+//!     ],
+//! )
+//! ```
+//!
+//! # 결정성
+//! 실행기는 고정 틱의 경과 시간만 사용하고 난수를 쓰지 않으므로,
+//! 같은 패턴과 같은 플레이어 위치면 항상 같은 탄이 같은 위치에 나옵니다.
+//! [`PatternRunner::step`]은 ECS 없이도 호출할 수 있어 발사된 탄의 수와 위치를 바로 검사할 수 있고,
+//! 헤드리스 앱에서는 엔티티에 [`PatternRunner`]를 직접 붙여 실행할 수 있습니다.
+
+use std::fmt;
+use std::sync::Arc;
+
+use bevy::{
+    asset::{io::Reader, AssetLoadFailedEvent, AssetLoader, LoadContext},
+    ecs::message::MessageReader,
+    platform::collections::HashMap,
+    prelude::*,
+};
+use serde::Deserialize;
+
+use crate::components::{EnemyKind, Player, Projectile, Velocity};
+use crate::config::GameConfig;
+use crate::projectile::spawn_hostile_projectile;
+use crate::resources::{Difficulty, Playfield, SpriteAssets};
+use crate::GameplaySet;
+
+/// 한 틱에 실행할 수 있는 최대 동작 수
+///
+/// 대기 없이 끝없이 도는 패턴이 게임을 멈추지 않도록 막습니다.
+const MAX_ACTIONS_PER_STEP: usize = 1024;
+
+// =============================================================================
+// 패턴 정의
+// =============================================================================
+
+/// 탄막 패턴 에셋입니다.
+#[derive(Asset, TypePath, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct BulletPattern {
+    /// 이미터가 실행할 최상위 동작 목록
+    pub actions: Arc<[Action]>,
+}
+
+/// 패턴을 이루는 동작 하나입니다.
+#[derive(Deserialize, Debug, Clone)]
+pub enum Action {
+    /// 탄 발사
+    Fire {
+        /// 발사 방향 (여러 발이면 부채꼴의 중심)
+        direction: PatternDirection,
+        /// 탄 속력 (픽셀/초)
+        speed: f32,
+        /// 한 번에 쏘는 탄 수
+        #[serde(default = "default_count")]
+        count: u32,
+        /// 여러 발을 펼치는 각도 (도). 360 이상이면 원형으로 고르게 쏩니다.
+        #[serde(default)]
+        spread: f32,
+        /// 발사된 탄이 실행할 동작 목록
+        #[serde(default)]
+        actions: Arc<[Action]>,
+    },
+    /// 대기 (초)
+    Wait(f32),
+    /// 정해진 횟수만큼 반복
+    Repeat { times: u32, actions: Arc<[Action]> },
+    /// 끝없이 반복
+    Loop(Arc<[Action]>),
+    /// `duration`초 동안 속력을 `speed`로 서서히 바꿈
+    ChangeSpeed { speed: f32, duration: f32 },
+    /// `duration`초 동안 방향을 `direction`으로 서서히 바꿈 (짧은 쪽으로 회전)
+    ChangeDirection {
+        direction: PatternDirection,
+        duration: f32,
+    },
+    /// 실행 중인 탄 제거
+    Vanish,
+}
+
+fn default_count() -> u32 {
+    1
+}
+
+/// 패턴에서 방향을 정하는 방법입니다. 값은 모두 도(degree) 단위입니다.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum PatternDirection {
+    /// 화면 기준 절대 방향 (0도는 아래)
+    Absolute(f32),
+    /// 플레이어를 향한 방향 + 오프셋 (플레이어가 없으면 아래 기준)
+    Aim(f32),
+    /// 실행 중인 엔티티의 진행 방향 + 오프셋
+    Relative(f32),
+    /// 직전에 발사한 방향 + 증분 (처음이면 진행 방향 기준)
+    Sequence(f32),
+}
+
+impl BulletPattern {
+    /// RON 문자열에서 패턴을 읽고 검증합니다.
+    pub fn from_ron(text: &str) -> Result<Self, PatternError> {
+        let pattern: BulletPattern = ron::from_str(text)?;
+        pattern.validate()?;
+        Ok(pattern)
+    }
+
+    /// 패턴 값이 유효한지 검사합니다.
+    ///
+    /// 잘못된 동작을 모두 모아 한 번에 보고합니다.
+    /// `Loop`는 한 바퀴에 0초보다 오래 대기해야 합니다.
+    pub fn validate(&self) -> Result<(), PatternError> {
+        let mut problems = Vec::new();
+        validate_actions(&self.actions, "actions", &mut problems);
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(PatternError::Invalid(problems))
+        }
+    }
+}
+
+/// 동작 목록을 재귀적으로 검사해 문제를 `problems`에 모읍니다.
+fn validate_actions(actions: &[Action], path: &str, problems: &mut Vec<String>) {
+    for (index, action) in actions.iter().enumerate() {
+        let here = format!("{path}[{index}]");
+        match action {
+            Action::Fire {
+                direction,
+                speed,
+                count,
+                spread,
+                actions,
+            } => {
+                check_value(problems, format!("{here}.speed"), *speed, false);
+                check_value(problems, format!("{here}.spread"), *spread, false);
+                check_value(
+                    problems,
+                    format!("{here}.direction"),
+                    direction.value(),
+                    true,
+                );
+                if *count == 0 {
+                    problems.push(format!("{here}.count: 1 이상이어야 합니다 (현재 0)"));
+                }
+                validate_actions(actions, &format!("{here}.actions"), problems);
+            }
+            Action::Wait(seconds) => check_value(problems, format!("{here}.Wait"), *seconds, false),
+            Action::Repeat { actions, .. } => {
+                validate_actions(actions, &format!("{here}.actions"), problems);
+            }
+            Action::Loop(actions) => {
+                if total_wait(actions) <= 0.0 {
+                    problems.push(format!(
+                        "{here}.Loop: 한 바퀴에 0초보다 오래 대기해야 합니다 (Wait 필요)"
+                    ));
+                }
+                validate_actions(actions, &format!("{here}.Loop"), problems);
+            }
+            Action::ChangeSpeed { speed, duration } => {
+                check_value(problems, format!("{here}.speed"), *speed, false);
+                check_value(problems, format!("{here}.duration"), *duration, false);
+            }
+            Action::ChangeDirection {
+                direction,
+                duration,
+            } => {
+                check_value(
+                    problems,
+                    format!("{here}.direction"),
+                    direction.value(),
+                    true,
+                );
+                check_value(problems, format!("{here}.duration"), *duration, false);
+            }
+            Action::Vanish => {}
+        }
+    }
+}
+
+/// 값이 유한하고, `allow_negative`가 아니면 0 이상인지 검사합니다.
+fn check_value(problems: &mut Vec<String>, name: String, value: f32, allow_negative: bool) {
+    if !value.is_finite() || (!allow_negative && value < 0.0) {
+        problems.push(format!(
+            "{name}: 0 이상의 유한한 값이어야 합니다 (현재 {value})"
+        ));
+    }
+}
+
+/// 동작 목록을 한 번 실행하는 동안 대기하는 총 시간(초)입니다.
+///
+/// 발사된 탄이 실행하는 동작은 이미터의 시간에 포함되지 않습니다.
+fn total_wait(actions: &[Action]) -> f32 {
+    actions
+        .iter()
+        .map(|action| match action {
+            Action::Wait(seconds) => *seconds,
+            Action::Repeat { times, actions } => *times as f32 * total_wait(actions),
+            Action::Loop(actions) => total_wait(actions),
+            _ => 0.0,
+        })
+        .sum()
+}
+
+impl PatternDirection {
+    /// 방향에 적힌 각도 값입니다.
+    fn value(self) -> f32 {
+        match self {
+            Self::Absolute(value)
+            | Self::Aim(value)
+            | Self::Relative(value)
+            | Self::Sequence(value) => value,
+        }
+    }
+}
+
+/// 각도(도)를 진행 방향 단위 벡터로 바꿉니다. 0도는 아래, 90도는 오른쪽입니다.
+pub fn direction_from_angle(degrees: f32) -> Vec2 {
+    let radians = degrees.to_radians();
+    Vec2::new(radians.sin(), -radians.cos())
+}
+
+/// 벡터의 방향을 각도(도)로 바꿉니다. [`direction_from_angle`]의 역함수입니다.
+pub fn angle_of(vector: Vec2) -> f32 {
+    vector.x.atan2(-vector.y).to_degrees()
+}
+
+/// 발사 한 번에 나가는 탄들의 각도를 반환합니다.
+///
+/// 360도 이상 펼치면 원형으로 고르게, 아니면 `spread` 양 끝을 포함해 고르게 나눕니다.
+fn fan_angles(center: f32, count: u32, spread: f32) -> impl Iterator<Item = f32> {
+    (0..count).map(move |index| {
+        let index = index as f32;
+        if count == 1 {
+            center
+        } else if spread >= 360.0 {
+            center + 360.0 * index / count as f32
+        } else {
+            center - spread * 0.5 + spread * index / (count - 1) as f32
+        }
+    })
+}
+
+/// 패턴 파일을 읽을 때 발생하는 오류입니다.
+#[derive(Debug)]
+pub enum PatternError {
+    /// 파일 입출력 오류
+    Io(std::io::Error),
+    /// RON 문법 오류 또는 알 수 없는 항목
+    Parse(ron::error::SpannedError),
+    /// 값 검증 실패 (잘못된 항목 목록)
+    Invalid(Vec<String>),
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "입출력 오류: {err}"),
+            Self::Parse(err) => write!(f, "패턴 파일 문법 오류: {err}"),
+            Self::Invalid(problems) => {
+                write!(f, "잘못된 패턴 값 {}개", problems.len())?;
+                for problem in problems {
+                    write!(f, "\n  - {problem}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+impl From<std::io::Error> for PatternError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<ron::error::SpannedError> for PatternError {
+    fn from(err: ron::error::SpannedError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+/// `.pattern.ron` 파일을 [`BulletPattern`]으로 읽는 에셋 로더입니다.
+///
+/// 읽은 뒤 [`BulletPattern::validate`]로 검증하며, 실패하면 로드 자체가 실패합니다.
+#[derive(Default, TypePath)]
+pub struct BulletPatternLoader;
+
+impl AssetLoader for BulletPatternLoader {
+    type Asset = BulletPattern;
+    type Settings = ();
+    type Error = PatternError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let pattern: BulletPattern = ron::de::from_bytes(&bytes)?;
+        pattern.validate()?;
+        Ok(pattern)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["pattern.ron"]
+    }
+}
+
+// =============================================================================
+// 패턴 실행기
+// =============================================================================
+
+/// 실행기가 방향을 정할 때 참고하는 주변 정보입니다.
+#[derive(Debug, Clone, Copy)]
+pub struct PatternContext {
+    /// 실행 중인 엔티티의 위치 (탄이 나가는 위치)
+    pub position: Vec2,
+    /// 조준 대상(플레이어)의 위치
+    pub target: Option<Vec2>,
+}
+
+/// 실행기가 발사한 탄 하나입니다.
+#[derive(Debug, Clone)]
+pub struct BulletSpawn {
+    /// 발사 위치
+    pub position: Vec2,
+    /// 진행 방향 (도)
+    pub angle: f32,
+    /// 속력 (픽셀/초)
+    pub speed: f32,
+    /// 탄이 실행할 동작 목록 (비어 있으면 직진만 함)
+    pub actions: Arc<[Action]>,
+}
+
+impl BulletSpawn {
+    /// 탄의 초기 속도입니다.
+    pub fn velocity(&self) -> Vec2 {
+        direction_from_angle(self.angle) * self.speed
+    }
+}
+
+/// 동작 목록을 고정 틱 단위로 실행하는 컴포넌트입니다.
+///
+/// 이미터(적)와 동작이 있는 탄 모두 이 컴포넌트로 패턴을 실행합니다.
+#[derive(Component, Debug, Clone)]
+pub struct PatternRunner {
+    /// 실행 중인 동작 목록 스택 (중첩 반복)
+    frames: Vec<Frame>,
+    /// 다음 동작까지 남은 대기 시간 (초, 음수면 지난 틱에서 넘친 시간)
+    wait: f32,
+    /// 속도가 0일 때 쓰는 진행 방향 (도)
+    heading: f32,
+    /// 직전에 발사한 방향 (도)
+    last_fire: Option<f32>,
+    speed_change: Option<Change>,
+    direction_change: Option<Change>,
+    vanished: bool,
+    /// 패턴 단위 속도에 곱해 실제 속도를 만드는 배율 (탄이 발사될 때의 투사체 속도 배율)
+    speed_scale: f32,
+}
+
+/// 실행 중인 동작 목록 하나입니다.
+#[derive(Debug, Clone)]
+struct Frame {
+    actions: Arc<[Action]>,
+    next: usize,
+    /// 이번 회차를 포함해 남은 반복 횟수 (`None`이면 무한)
+    remaining: Option<u32>,
+}
+
+/// 시간에 따라 값을 선형으로 바꾸는 진행 중인 변화입니다.
+#[derive(Debug, Clone, Copy)]
+struct Change {
+    from: f32,
+    to: f32,
+    duration: f32,
+    elapsed: f32,
+}
+
+impl Change {
+    /// 시간을 진행하고 현재 값을 반환합니다.
+    fn advance(&mut self, delta: f32) -> f32 {
+        self.elapsed += delta;
+        let t = if self.duration > 0.0 {
+            (self.elapsed / self.duration).min(1.0)
+        } else {
+            1.0
+        };
+        self.from + (self.to - self.from) * t
+    }
+
+    fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+impl PatternRunner {
+    /// 동작 목록을 처음부터 실행하는 실행기를 만듭니다. 진행 방향은 아래입니다.
+    pub fn new(actions: Arc<[Action]>) -> Self {
+        Self {
+            frames: vec![Frame {
+                actions,
+                next: 0,
+                remaining: Some(1),
+            }],
+            wait: 0.0,
+            heading: 0.0,
+            last_fire: None,
+            speed_change: None,
+            direction_change: None,
+            vanished: false,
+            speed_scale: 1.0,
+        }
+    }
+
+    /// 발사된 탄의 동작을 실행하는 실행기를 만듭니다.
+    ///
+    /// `speed_scale`은 탄을 스폰할 때 속력에 곱한 배율입니다.
+    /// 발사 뒤에 배율이 바뀌어도 속도 변화는 발사 당시 배율을 기준으로 계산합니다.
+    pub fn for_bullet(spawn: &BulletSpawn, speed_scale: f32) -> Self {
+        Self {
+            heading: spawn.angle,
+            speed_scale,
+            ..Self::new(spawn.actions.clone())
+        }
+    }
+
+    /// 모든 동작과 속도/방향 변화가 끝났는지 여부입니다.
+    pub fn is_finished(&self) -> bool {
+        self.frames.is_empty() && self.speed_change.is_none() && self.direction_change.is_none()
+    }
+
+    /// `Vanish`를 실행해 엔티티를 제거해야 하는지 여부입니다.
+    pub fn is_vanished(&self) -> bool {
+        self.vanished
+    }
+
+    /// 패턴을 `delta`초만큼 실행합니다.
+    ///
+    /// 발사한 탄은 `spawns`에 추가하고, 속도/방향 변화는 `velocity`에 반영합니다.
+    /// 대기 시간이 틱 경계와 맞지 않아도 넘친 시간을 다음 틱으로 넘기므로 발사 간격이 밀리지 않습니다.
+    pub fn step(
+        &mut self,
+        delta: f32,
+        context: &PatternContext,
+        velocity: &mut Vec2,
+        spawns: &mut Vec<BulletSpawn>,
+    ) {
+        if self.vanished {
+            return;
+        }
+
+        self.wait -= delta;
+        let mut executed = 0;
+        while self.wait <= 0.0 {
+            let Some(action) = self.next_action() else {
+                self.wait = 0.0;
+                break;
+            };
+
+            executed += 1;
+            if executed > MAX_ACTIONS_PER_STEP {
+                warn!(
+                    "Bullet pattern ran {MAX_ACTIONS_PER_STEP} actions in one tick without waiting"
+                );
+                self.wait = 0.0;
+                break;
+            }
+
+            match action {
+                Action::Fire {
+                    direction,
+                    speed,
+                    count,
+                    spread,
+                    actions,
+                } => {
+                    let center = self.resolve(direction, context, *velocity);
+                    self.last_fire = Some(center);
+                    spawns.extend(fan_angles(center, count, spread).map(|angle| BulletSpawn {
+                        position: context.position,
+                        angle,
+                        speed,
+                        actions: actions.clone(),
+                    }));
+                }
+                Action::Wait(seconds) => self.wait += seconds,
+                Action::Repeat { times, actions } => {
+                    if times > 0 && !actions.is_empty() {
+                        self.frames.push(Frame {
+                            actions,
+                            next: 0,
+                            remaining: Some(times),
+                        });
+                    }
+                }
+                Action::Loop(actions) => {
+                    if !actions.is_empty() {
+                        self.frames.push(Frame {
+                            actions,
+                            next: 0,
+                            remaining: None,
+                        });
+                    }
+                }
+                Action::ChangeSpeed { speed, duration } => {
+                    self.speed_change = Some(Change {
+                        from: velocity.length(),
+                        to: speed,
+                        duration,
+                        elapsed: 0.0,
+                    });
+                }
+                Action::ChangeDirection {
+                    direction,
+                    duration,
+                } => {
+                    let from = self.current_heading(*velocity);
+                    let target = self.resolve(direction, context, *velocity);
+                    // 짧은 쪽으로 회전
+                    let turn = (target - from + 180.0).rem_euclid(360.0) - 180.0;
+                    self.direction_change = Some(Change {
+                        from,
+                        to: from + turn,
+                        duration,
+                        elapsed: 0.0,
+                    });
+                }
+                Action::Vanish => {
+                    self.vanished = true;
+                    return;
+                }
+            }
+        }
+
+        self.apply_changes(delta, velocity);
+    }
+
+    /// 다음에 실행할 동작을 꺼냅니다. 반복이 끝난 목록은 스택에서 뺍니다.
+    fn next_action(&mut self) -> Option<Action> {
+        loop {
+            let frame = self.frames.last_mut()?;
+            if let Some(action) = frame.actions.get(frame.next).cloned() {
+                frame.next += 1;
+                return Some(action);
+            }
+
+            match &mut frame.remaining {
+                Some(remaining) if *remaining > 1 => {
+                    *remaining -= 1;
+                    frame.next = 0;
+                }
+                None if !frame.actions.is_empty() => frame.next = 0,
+                _ => {
+                    self.frames.pop();
+                }
+            }
+        }
+    }
+
+    /// 현재 진행 방향(도)입니다. 멈춰 있으면 마지막 진행 방향을 씁니다.
+    fn current_heading(&self, velocity: Vec2) -> f32 {
+        if velocity.length_squared() > f32::EPSILON {
+            angle_of(velocity)
+        } else {
+            self.heading
+        }
+    }
+
+    /// 패턴의 방향을 실제 각도(도)로 바꿉니다.
+    fn resolve(
+        &self,
+        direction: PatternDirection,
+        context: &PatternContext,
+        velocity: Vec2,
+    ) -> f32 {
+        match direction {
+            PatternDirection::Absolute(angle) => angle,
+            PatternDirection::Aim(offset) => {
+                let aim = context
+                    .target
+                    .map(|target| target - context.position)
+                    .filter(|to_target| to_target.length_squared() > f32::EPSILON)
+                    .map_or(0.0, angle_of);
+                aim + offset
+            }
+            PatternDirection::Relative(offset) => self.current_heading(velocity) + offset,
+            PatternDirection::Sequence(step) => {
+                self.last_fire
+                    .unwrap_or_else(|| self.current_heading(velocity))
+                    + step
+            }
+        }
+    }
+
+    /// 진행 중인 속도/방향 변화를 `delta`초만큼 진행해 속도에 반영합니다.
+    fn apply_changes(&mut self, delta: f32, velocity: &mut Vec2) {
+        if self.speed_change.is_none() && self.direction_change.is_none() {
+            return;
+        }
+
+        let mut speed = velocity.length();
+        let mut heading = self.current_heading(*velocity);
+
+        if let Some(change) = self.speed_change.as_mut() {
+            speed = change.advance(delta);
+            if change.is_finished() {
+                self.speed_change = None;
+            }
+        }
+        if let Some(change) = self.direction_change.as_mut() {
+            heading = change.advance(delta);
+            if change.is_finished() {
+                self.direction_change = None;
+            }
+        }
+
+        self.heading = heading;
+        *velocity = direction_from_angle(heading) * speed;
+    }
+}
+
+// =============================================================================
+// 이미터
+// =============================================================================
+
+/// 탄막 패턴 에셋을 실행할 이미터 컴포넌트입니다.
+///
+/// 에셋이 로드되고 이미터가 플레이 영역에 들어오면 [`PatternRunner`]가 붙어 실행을 시작합니다.
+#[derive(Component, Debug, Clone)]
+pub struct BulletEmitter(pub Handle<BulletPattern>);
+
+/// 설정에 있는 탄막 패턴의 핸들을 경로별로 보관하는 리소스입니다.
+///
+/// 판 도중에 로드가 끝나 패턴 시작 시점이 달라지지 않도록 설정이 바뀔 때 미리 읽어 둡니다.
+#[derive(Resource, Debug, Default)]
+pub struct PatternLibrary {
+    pub handles: HashMap<String, Handle<BulletPattern>>,
+}
+
+// =============================================================================
+// 패턴 플러그인
+// =============================================================================
+
+/// 탄막 패턴 에셋과 실행 시스템을 모아놓은 플러그인입니다.
+///
+/// AssetPlugin이 없는 헤드리스 앱에서는 패턴 파일을 읽지 않지만,
+/// 엔티티에 [`PatternRunner`]를 직접 붙이면 똑같이 실행됩니다.
+pub struct PatternPlugin;
+
+impl Plugin for PatternPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PatternLibrary>().add_systems(
+            FixedUpdate,
+            (attach_enemy_emitters, start_emitters, run_patterns)
+                .chain()
+                .in_set(GameplaySet::Pattern),
+        );
+
+        if !app.is_plugin_added::<AssetPlugin>() {
+            return;
+        }
+
+        app.init_asset::<BulletPattern>()
+            .init_asset_loader::<BulletPatternLoader>()
+            .add_systems(
+                Update,
+                (
+                    load_enemy_patterns.run_if(resource_changed::<GameConfig>),
+                    report_pattern_errors,
+                ),
+            );
+    }
+}
+
+// =============================================================================
+// 시스템 (Systems)
+// =============================================================================
+
+/// 설정의 적 종류별, 보스 단계별 패턴 파일을 읽어 [`PatternLibrary`]에 보관하는 시스템입니다.
+fn load_enemy_patterns(
+    asset_server: Res<AssetServer>,
+    config: Res<GameConfig>,
+    mut library: ResMut<PatternLibrary>,
+) {
+    for path in config
+        .enemies
+        .pattern_paths()
+        .chain(config.boss.pattern_paths())
+    {
+        if !library.handles.contains_key(path) {
+            let handle = asset_server.load(path.to_string());
+            library.handles.insert(path.to_string(), handle);
+        }
+    }
+}
+
+/// 패턴 파일을 읽지 못했을 때 오류를 보고하는 시스템입니다.
+fn report_pattern_errors(mut events: MessageReader<AssetLoadFailedEvent<BulletPattern>>) {
+    for event in events.read() {
+        error!(
+            "Failed to load bullet pattern '{}': {}",
+            event.path, event.error
+        );
+    }
+}
+
+/// 새로 스폰된 적 중 설정에 패턴이 있는 종류에 [`BulletEmitter`]를 붙이는 시스템입니다.
+fn attach_enemy_emitters(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    library: Res<PatternLibrary>,
+    enemies: Query<(Entity, &EnemyKind), Added<EnemyKind>>,
+) {
+    for (entity, kind) in enemies.iter() {
+        let Some(path) = &config.enemies.get(*kind).pattern else {
+            continue;
+        };
+        if let Some(handle) = library.handles.get(path) {
+            commands
+                .entity(entity)
+                .insert(BulletEmitter(handle.clone()));
+        }
+    }
+}
+
+/// 패턴이 로드되었고 플레이 영역에 들어온 이미터의 실행을 시작하는 시스템입니다.
+fn start_emitters(
+    mut commands: Commands,
+    playfield: Res<Playfield>,
+    patterns: Option<Res<Assets<BulletPattern>>>,
+    emitters: Query<(Entity, &BulletEmitter, &Transform), Without<PatternRunner>>,
+) {
+    let Some(patterns) = patterns else {
+        return;
+    };
+    let half_extents = playfield.half_extents();
+
+    for (entity, emitter, transform) in emitters.iter() {
+        let position = transform.translation.truncate();
+        if position.x.abs() > half_extents.x || position.y.abs() > half_extents.y {
+            continue;
+        }
+        if let Some(pattern) = patterns.get(&emitter.0) {
+            commands
+                .entity(entity)
+                .insert(PatternRunner::new(pattern.actions.clone()));
+        }
+    }
+}
+
+/// 모든 [`PatternRunner`]를 한 틱 실행하는 시스템입니다.
+///
+/// - 발사된 탄은 적 투사체로 스폰하고, 동작이 있으면 탄에도 실행기를 붙입니다.
+/// - 속도/방향 변화는 엔티티의 [`Velocity`]에 반영합니다 (투사체 스프라이트는 운동 모듈이 진행 방향으로 돌림).
+/// - 탄의 속력에는 발사 시점의 [`Difficulty`] 적 투사체 속도 배율을 곱하고, 탄의 실행기에 그 배율을 기록합니다.
+/// - `Vanish`를 실행한 엔티티는 제거하고, 할 일을 마친 탄에서는 실행기를 뗍니다.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn run_patterns(
+    mut commands: Commands,
+    time: Res<Time>,
+    sprites: Res<SpriteAssets>,
+    config: Res<GameConfig>,
+    difficulty: Res<Difficulty>,
+    player: Query<&Transform, With<Player>>,
+    mut runners: Query<(
+        Entity,
+        &Transform,
+        Option<&mut Velocity>,
+        &mut PatternRunner,
+        Has<Projectile>,
+    )>,
+    mut spawns: Local<Vec<BulletSpawn>>,
+) {
+    let target = player.single().ok().map(|t| t.translation.truncate());
+
+    for (entity, transform, velocity, mut runner, is_projectile) in runners.iter_mut() {
+        let context = PatternContext {
+            position: transform.translation.truncate(),
+            target,
+        };
+
+        // 탄의 속도는 패턴 단위로 바꿔 실행하고, 다시 발사 당시의 속도 배율을 곱합니다
+        let scale = runner.speed_scale;
+        let current = velocity
+            .as_ref()
+            .map_or(Vec2::ZERO, |velocity| velocity.0 / scale);
+        let mut new_velocity = current;
+        runner.step(time.delta_secs(), &context, &mut new_velocity, &mut spawns);
+
+        if let Some(mut velocity) = velocity {
+            if current != new_velocity {
+                velocity.0 = new_velocity * scale;
+            }
+        }
+
+        if runner.is_vanished() {
+            commands.entity(entity).despawn();
+        } else if is_projectile && runner.is_finished() {
+            commands.entity(entity).remove::<PatternRunner>();
+        }
+
+        for spawn in spawns.drain(..) {
+            let mut bullet = spawn_hostile_projectile(
+                &mut commands,
+                &sprites,
+                &config,
+                spawn.position,
+                direction_from_angle(spawn.angle),
+                spawn.speed * difficulty.bullet_speed,
+            );
+            if !spawn.actions.is_empty() {
+                bullet.insert(PatternRunner::for_bullet(&spawn, difficulty.bullet_speed));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    const ORIGIN: PatternContext = PatternContext {
+        position: Vec2::new(10.0, 20.0),
+        target: None,
+    };
+
+    /// 패턴을 읽어 틱마다 실행하고, 틱별로 발사된 탄을 반환합니다.
+    fn run(pattern: &str, context: &PatternContext, deltas: &[f32]) -> Vec<Vec<BulletSpawn>> {
+        let pattern = BulletPattern::from_ron(pattern).expect("pattern should parse");
+        let mut runner = PatternRunner::new(pattern.actions);
+        let mut velocity = Vec2::ZERO;
+
+        deltas
+            .iter()
+            .map(|&delta| {
+                let mut spawns = Vec::new();
+                runner.step(delta, context, &mut velocity, &mut spawns);
+                spawns
+            })
+            .collect()
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-3,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    /// 탄마다 발사 위치, 각도, 속력을 확인합니다.
+    fn assert_bullets(spawns: &[BulletSpawn], position: Vec2, angles: &[f32], speed: f32) {
+        assert_eq!(spawns.len(), angles.len());
+        for (spawn, &angle) in spawns.iter().zip(angles) {
+            assert_eq!(spawn.position, position);
+            assert_close(spawn.angle, angle);
+            assert_close(spawn.speed, speed);
+        }
+    }
+
+    #[test]
+    fn ring_spreads_bullets_evenly_around_the_emitter() {
+        let ticks = run(
+            "(actions: [Fire(direction: Absolute(0.0), speed: 120.0, count: 8, spread: 360.0)])",
+            &ORIGIN,
+            &[0.0],
+        );
+
+        let angles: Vec<f32> = (0..8).map(|index| index as f32 * 45.0).collect();
+        assert_bullets(&ticks[0], ORIGIN.position, &angles, 120.0);
+
+        let down = ticks[0][0].velocity();
+        assert_close(down.x, 0.0);
+        assert_close(down.y, -120.0);
+    }
+
+    #[test]
+    fn spiral_advances_one_step_per_wait() {
+        let ticks = run(
+            "(actions: [
+                Repeat(times: 4, actions: [
+                    Fire(direction: Sequence(10.0), speed: 150.0),
+                    Wait(0.25),
+                ]),
+            ])",
+            &ORIGIN,
+            &[0.0, 0.25, 0.25, 0.25, 0.25],
+        );
+
+        for (tick, angle) in ticks.iter().zip([10.0, 20.0, 30.0, 40.0]) {
+            assert_bullets(tick, ORIGIN.position, &[angle], 150.0);
+        }
+        assert!(ticks[4].is_empty());
+    }
+
+    #[test]
+    fn aimed_fan_is_centered_on_the_target() {
+        let context = PatternContext {
+            position: Vec2::ZERO,
+            target: Some(Vec2::new(100.0, 0.0)),
+        };
+        let ticks = run(
+            "(actions: [Fire(direction: Aim(0.0), speed: 200.0, count: 3, spread: 30.0)])",
+            &context,
+            &[0.0],
+        );
+
+        // 오른쪽은 90도
+        assert_bullets(&ticks[0], Vec2::ZERO, &[75.0, 90.0, 105.0], 200.0);
+        assert_close(ticks[0][1].velocity().x, 200.0);
+    }
+
+    #[test]
+    fn nested_repeat_fires_inner_actions_for_every_outer_pass() {
+        let ticks = run(
+            "(actions: [
+                Repeat(times: 2, actions: [
+                    Repeat(times: 3, actions: [Fire(direction: Sequence(5.0), speed: 100.0)]),
+                    Wait(1.0),
+                ]),
+            ])",
+            &ORIGIN,
+            &[0.0, 1.0, 1.0],
+        );
+
+        assert_bullets(&ticks[0], ORIGIN.position, &[5.0, 10.0, 15.0], 100.0);
+        assert_bullets(&ticks[1], ORIGIN.position, &[20.0, 25.0, 30.0], 100.0);
+        assert!(ticks[2].is_empty());
+        assert_eq!(ticks.iter().map(Vec::len).sum::<usize>(), 6);
+    }
+
+    #[test]
+    fn fired_bullet_runs_its_own_actions_and_spawns_sub_bullets() {
+        let ticks = run(
+            "(actions: [
+                Fire(direction: Absolute(0.0), speed: 100.0, actions: [
+                    Wait(0.5),
+                    Fire(direction: Relative(90.0), speed: 50.0, count: 2, spread: 360.0),
+                    Vanish,
+                ]),
+            ])",
+            &ORIGIN,
+            &[0.0],
+        );
+        assert_bullets(&ticks[0], ORIGIN.position, &[0.0], 100.0);
+
+        let parent = &ticks[0][0];
+        let mut runner = PatternRunner::for_bullet(parent, 1.0);
+        let mut velocity = parent.velocity();
+        let context = PatternContext {
+            position: Vec2::new(10.0, -30.0),
+            target: None,
+        };
+        let mut spawns = Vec::new();
+
+        runner.step(0.25, &context, &mut velocity, &mut spawns);
+        assert!(spawns.is_empty());
+        assert!(!runner.is_vanished());
+
+        runner.step(0.25, &context, &mut velocity, &mut spawns);
+        assert_bullets(&spawns, context.position, &[90.0, 270.0], 50.0);
+        assert!(spawns.iter().all(|spawn| spawn.actions.is_empty()));
+        assert!(runner.is_vanished());
+    }
+
+    fn assert_velocity(actual: Vec2, expected: Vec2) {
+        assert!(
+            actual.abs_diff_eq(expected, 1e-3),
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    /// 아래로 100 속력 → 0.5초 동안 50으로 감속 → 0.5초 동안 오른쪽(90도)으로 회전하는 탄
+    const SLOW_AND_TURN: &str = "(actions: [
+        Fire(direction: Absolute(0.0), speed: 100.0, actions: [
+            ChangeSpeed(speed: 50.0, duration: 0.5),
+            Wait(0.5),
+            ChangeDirection(direction: Absolute(90.0), duration: 0.5),
+        ]),
+    ])";
+
+    #[test]
+    fn speed_and_direction_change_gradually() {
+        let bullet = run(SLOW_AND_TURN, &ORIGIN, &[0.0]).remove(0).remove(0);
+        let mut runner = PatternRunner::for_bullet(&bullet, 1.0);
+        let mut velocity = bullet.velocity();
+        let diagonal = Vec2::new(1.0, -1.0).normalize();
+
+        for expected in [
+            Vec2::new(0.0, -75.0),
+            diagonal * 50.0,
+            Vec2::new(50.0, 0.0),
+            Vec2::new(50.0, 0.0),
+        ] {
+            runner.step(0.25, &ORIGIN, &mut velocity, &mut Vec::new());
+            assert_velocity(velocity, expected);
+        }
+        assert!(runner.is_finished());
+    }
+
+    #[test]
+    fn direction_change_turns_the_short_way() {
+        let bullet = run(
+            "(actions: [Fire(direction: Absolute(0.0), speed: 100.0, actions: [
+                ChangeDirection(direction: Absolute(270.0), duration: 1.0),
+            ])])",
+            &ORIGIN,
+            &[0.0],
+        )
+        .remove(0)
+        .remove(0);
+        let mut runner = PatternRunner::for_bullet(&bullet, 1.0);
+        let mut velocity = bullet.velocity();
+
+        // 0도에서 270도로 가는 짧은 쪽은 -90도 (왼쪽 아래를 거쳐 왼쪽)
+        runner.step(0.5, &ORIGIN, &mut velocity, &mut Vec::new());
+        assert_velocity(velocity, Vec2::new(-1.0, -1.0).normalize() * 100.0);
+        runner.step(0.5, &ORIGIN, &mut velocity, &mut Vec::new());
+        assert_velocity(velocity, Vec2::new(-100.0, 0.0));
+    }
+
+    #[test]
+    fn bullet_keeps_the_speed_scale_it_was_fired_with() {
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(std::time::Duration::from_secs_f32(0.25));
+        world.insert_resource(time);
+        world.init_resource::<SpriteAssets>();
+        world.init_resource::<GameConfig>();
+        world.insert_resource(Difficulty {
+            bullet_speed: 2.0,
+            ..default()
+        });
+        let pattern = BulletPattern::from_ron(SLOW_AND_TURN).unwrap();
+        world.spawn((
+            Transform::from_translation(ORIGIN.position.extend(0.0)),
+            PatternRunner::new(pattern.actions),
+        ));
+
+        let step = |world: &mut World| {
+            world.run_system_once(run_patterns).unwrap();
+            let mut bullets =
+                world.query_filtered::<(&Velocity, Has<PatternRunner>), With<Projectile>>();
+            let bullets: Vec<(Vec2, bool)> = bullets
+                .iter(world)
+                .map(|(velocity, running)| (velocity.0, running))
+                .collect();
+            assert_eq!(bullets.len(), 1);
+            bullets[0]
+        };
+
+        // 발사: 패턴 속력 100 × 배율 2
+        let (velocity, _) = step(&mut world);
+        assert_velocity(velocity, Vec2::new(0.0, -200.0));
+
+        // 발사 뒤에 배율이 바뀌어도 이미 나간 탄은 발사 당시 배율을 따름
+        world.resource_mut::<Difficulty>().bullet_speed = 3.0;
+        let diagonal = Vec2::new(1.0, -1.0).normalize();
+        for expected in [Vec2::new(0.0, -75.0), diagonal * 50.0, Vec2::new(50.0, 0.0)] {
+            let (velocity, _) = step(&mut world);
+            assert_velocity(velocity, expected * 2.0);
+        }
+        // 변화를 모두 마친 탄에서는 실행기를 뗌
+        let (_, running) = step(&mut world);
+        assert!(!running);
+    }
+}
@@ -0,0 +1,57 @@
+//! 일시정지 모듈
+//!
+//! InGame 중 Escape 또는 P 키로 일시정지를 켜고 끕니다.
+//! 일시정지 중에는 [`Time<Virtual>`]이 멈추므로 고정 틱이 진행되지 않고,
+//! 게임플레이 시스템 세트도 실행되지 않습니다. 엔티티는 그대로 남습니다.
+//!
+//! 일시정지 메뉴 UI는 [`crate::ui::UiPlugin`]에서 표시합니다.
+
+use bevy::prelude::*;
+
+use crate::resources::{AppState, PauseState};
+
+// =============================================================================
+// 일시정지 플러그인
+// =============================================================================
+
+/// 일시정지 상태 전환과 가상 시간 정지를 담당하는 플러그인입니다.
+pub struct PausePlugin;
+
+impl Plugin for PausePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_sub_state::<PauseState>()
+            .add_systems(OnEnter(PauseState::Paused), pause_virtual_time)
+            .add_systems(OnExit(PauseState::Paused), resume_virtual_time)
+            .add_systems(Update, toggle_pause.run_if(in_state(AppState::InGame)));
+    }
+}
+
+// =============================================================================
+// 시스템 (Systems)
+// =============================================================================
+
+/// Escape 또는 P 키로 일시정지를 켜고 끄는 시스템입니다.
+fn toggle_pause(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    state: Res<State<PauseState>>,
+    mut next_state: ResMut<NextState<PauseState>>,
+) {
+    if !keyboard.any_just_pressed([KeyCode::Escape, KeyCode::KeyP]) {
+        return;
+    }
+
+    next_state.set(match state.get() {
+        PauseState::Running => PauseState::Paused,
+        PauseState::Paused => PauseState::Running,
+    });
+}
+
+/// 일시정지 시 가상 시간을 멈추는 시스템입니다.
+fn pause_virtual_time(mut time: ResMut<Time<Virtual>>) {
+    time.pause();
+}
+
+/// 일시정지가 풀리면 (재개, 재시작, 메인 메뉴) 가상 시간을 다시 진행시키는 시스템입니다.
+fn resume_virtual_time(mut time: ResMut<Time<Virtual>>) {
+    time.unpause();
+}
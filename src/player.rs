@@ -1,16 +1,27 @@
 //! 플레이어 모듈
 //!
-//! 플레이어의 스폰, 이동, 입력 처리를 담당합니다.
-//! InGame 상태에서만 동작하며, 상태 전환 시 자동으로 정리됩니다.
+//! 플레이어의 스폰, 이동, 입력 처리, 부활과 무적 시간을 담당합니다.
+//! 발사는 플레이어가 든 무기로 `weapon.rs`에서 처리합니다.
+//! 게임플레이 중(InGame, Replay)에만 동작하며, 상태 전환 시 자동으로 정리됩니다.
+//!
+//! # 사망과 부활
+//! 체력이 0이 되면 목숨을 하나 잃고 잠시 사라졌다가([`Respawning`]) 시작 위치에서 부활합니다.
+//! 부활 직후에는 [`Invulnerable`] 상태로 깜빡이며 피해를 받지 않습니다.
+//! 충돌 판정은 `collision.rs`, 피해와 사망 처리는 `damage.rs`에서 합니다.
 
 use bevy::prelude::*;
-use bevy::window::PrimaryWindow;
 
-use crate::components::{CollisionRadius, Player, Projectile, Velocity};
-use crate::resources::{
-    AppState, PLAYER_COLLISION_RADIUS, PLAYER_SCALE, PLAYER_SPEED, PROJECTILE_COLLISION_RADIUS,
-    PROJECTILE_SCALE, PROJECTILE_SPEED,
-};
+use crate::components::{Collider, ColliderShape, Health, Invulnerable, Lives, Player, Respawning};
+use crate::config::GameConfig;
+use crate::resources::{PauseState, PlayerInput, Playfield, Playing, SpriteAssets};
+use crate::weapon::{MissileLauncher, Weapon};
+use crate::GameplaySet;
+
+/// 플레이어가 스폰(부활)되는 위치
+const PLAYER_SPAWN_POSITION: Vec3 = Vec3::new(0.0, -200.0, 0.0);
+
+/// 무적 상태에서 스프라이트가 켜지고 꺼지는 간격 (초)
+const INVULNERABILITY_BLINK_INTERVAL: f32 = 0.1;
 
 // =============================================================================
 // 플레이어 플러그인
@@ -25,16 +36,29 @@ impl Plugin for PlayerPlugin {
             // ─────────────────────────────────────────────────────────────────
             // 상태 진입/종료 시스템
             // ─────────────────────────────────────────────────────────────────
-            // OnEnter(InGame): 게임 시작 시 플레이어 스폰
-            .add_systems(OnEnter(AppState::InGame), spawn_player)
-            // OnExit(InGame): 게임 종료 시 플레이어 정리
-            .add_systems(OnExit(AppState::InGame), cleanup_player)
+            // OnEnter(Playing): 게임 시작 시 플레이어 스폰, 입력 초기화
+            .add_systems(OnEnter(Playing), (spawn_player, reset_player_input))
+            // OnExit(Playing): 게임 종료 시 플레이어 정리
+            .add_systems(OnExit(Playing), cleanup_player)
+            // ─────────────────────────────────────────────────────────────────
+            // 키보드 입력 수집 (고정 틱 루프 직전, 프레임마다 실행)
+            // 리플레이 중에는 녹화된 입력을 사용하므로 InGame에서만 실행
+            // 일시정지 중 누른 발사 키가 재개 후 발사되지 않도록 Running에서만 실행
             // ─────────────────────────────────────────────────────────────────
-            // 게임플레이 시스템 (InGame 상태에서만 실행)
+            .add_systems(
+                RunFixedMainLoop,
+                read_player_input
+                    .in_set(RunFixedMainLoopSystems::BeforeFixedMainLoop)
+                    .run_if(in_state(PauseState::Running)),
+            )
+            // ─────────────────────────────────────────────────────────────────
+            // 게임플레이 시스템 (고정 틱, 게임플레이 중에만 실행)
             // ─────────────────────────────────────────────────────────────────
             .add_systems(
-                Update,
-                (player_movement, player_shooting).run_if(in_state(AppState::InGame)),
+                FixedUpdate,
+                (respawn_player, tick_invulnerability, player_movement)
+                    .chain()
+                    .in_set(GameplaySet::Input),
             );
     }
 }
@@ -45,31 +69,32 @@ impl Plugin for PlayerPlugin {
 
 /// 플레이어 엔티티를 스폰하는 시스템입니다.
 ///
-/// # AssetServer
-/// Bevy의 에셋 로딩 시스템입니다.
-/// `asset_server.load("파일명")`으로 assets/ 폴더의 파일을 로드합니다.
-/// 로딩은 비동기로 진행되며, Handle<T>을 즉시 반환합니다.
-///
-/// # SpriteBundle with Texture
-/// 이전에는 Sprite { color: ... }로 단색 사각형을 그렸지만,
-/// 이제는 texture 필드에 이미지 핸들을 전달하여 실제 이미지를 렌더링합니다.
-fn spawn_player(mut commands: Commands, asset_server: Res<AssetServer>) {
-    // player.png 이미지 로드
-    // assets/player.png 경로에서 자동으로 로드됩니다.
-    let texture: Handle<Image> = asset_server.load("player.png");
-
+/// # SpriteAssets
+/// 이미지 핸들은 시작 시 [`SpriteAssets`]에 미리 로드해 둡니다.
+/// 핸들을 복제(clone)해도 이미지를 다시 로드하지 않습니다.
+/// 헤드리스 실행에서는 기본 핸들이 들어 있어 렌더링 없이 스폰만 됩니다.
+fn spawn_player(mut commands: Commands, sprites: Res<SpriteAssets>, config: Res<GameConfig>) {
     commands.spawn((
         Sprite {
-            image: texture,
+            image: sprites.player.clone(),
             ..default()
         },
         Transform {
-            translation: Vec3::new(0.0, -200.0, 0.0),
-            scale: Vec3::splat(PLAYER_SCALE),
+            translation: PLAYER_SPAWN_POSITION,
+            scale: Vec3::splat(config.player_scale),
             ..default()
         },
         Player,
-        CollisionRadius(PLAYER_COLLISION_RADIUS),
+        Collider::player(ColliderShape::Circle {
+            radius: config.player_collision_radius,
+        }),
+        Health {
+            current: config.player_max_health,
+            max: config.player_max_health,
+        },
+        Lives(config.player_lives),
+        Weapon::new(config.player_weapon),
+        MissileLauncher::default(),
     ));
 }
 
@@ -82,21 +107,19 @@ fn cleanup_player(mut commands: Commands, query: Query<Entity, With<Player>>) {
     }
 }
 
-/// 키보드 입력을 받아 플레이어를 이동시키는 시스템입니다.
-fn player_movement(
-    keyboard: Res<ButtonInput<KeyCode>>,
-    time: Res<Time>,
-    mut query: Query<&mut Transform, With<Player>>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-) {
-    // 플레이어가 없으면 조기 종료 (안전 처리)
-    let Ok(mut transform) = query.single_mut() else {
-        return;
-    };
-    let Ok(window) = window_query.single() else {
-        return;
-    };
+/// 플레이어 입력을 초기화하는 시스템입니다.
+///
+/// 이전 판에서 남은 이동 방향이나 발사 요청이 새 판으로 넘어가지 않도록 합니다.
+fn reset_player_input(mut input: ResMut<PlayerInput>) {
+    *input = PlayerInput::default();
+}
 
+/// 키보드 상태를 [`PlayerInput`]에 모으는 시스템입니다.
+///
+/// 고정 틱은 한 프레임에 0번 또는 여러 번 실행될 수 있으므로,
+/// `just_pressed`를 고정 틱에서 직접 읽으면 발사가 누락되거나 중복됩니다.
+/// 발사와 무기 교체 요청은 소비될 때까지 유지합니다.
+fn read_player_input(keyboard: Res<ButtonInput<KeyCode>>, mut input: ResMut<PlayerInput>) {
     let mut direction = Vec2::ZERO;
 
     if keyboard.pressed(KeyCode::KeyW) || keyboard.pressed(KeyCode::ArrowUp) {
@@ -112,54 +135,108 @@ fn player_movement(
         direction.x += 1.0;
     }
 
-    if direction != Vec2::ZERO {
-        direction = direction.normalize();
-        let movement = direction * PLAYER_SPEED * time.delta_secs();
-        transform.translation.x += movement.x;
-        transform.translation.y += movement.y;
+    input.movement = direction;
+
+    if keyboard.just_pressed(KeyCode::Space) {
+        input.fire = true;
     }
+    input.fire_held = keyboard.pressed(KeyCode::Space);
 
-    // 화면 경계 제한 (충돌 반경 고려)
-    let half_width = window.width() / 2.0 - PLAYER_COLLISION_RADIUS;
-    let half_height = window.height() / 2.0 - PLAYER_COLLISION_RADIUS;
+    if keyboard.just_pressed(KeyCode::KeyQ) {
+        input.switch_weapon = true;
+    }
+}
 
-    transform.translation.x = transform.translation.x.clamp(-half_width, half_width);
-    transform.translation.y = transform.translation.y.clamp(-half_height, half_height);
+/// 부활 대기 시간이 끝난 플레이어를 시작 위치에서 부활시키는 시스템입니다.
+///
+/// 체력을 회복하고 무적 시간을 부여합니다.
+fn respawn_player(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    mut query: Query<
+        (
+            Entity,
+            &mut Respawning,
+            &mut Transform,
+            &mut Health,
+            &mut Visibility,
+        ),
+        With<Player>,
+    >,
+) {
+    for (entity, mut respawning, mut transform, mut health, mut visibility) in query.iter_mut() {
+        if !respawning.0.tick(time.delta()).is_finished() {
+            continue;
+        }
+
+        transform.translation = PLAYER_SPAWN_POSITION;
+        health.current = health.max;
+        *visibility = Visibility::Inherited;
+
+        commands
+            .entity(entity)
+            .remove::<Respawning>()
+            .insert(Invulnerable(Timer::from_seconds(
+                config.player_invulnerability_duration,
+                TimerMode::Once,
+            )));
+    }
 }
 
-/// 스페이스바를 누르면 투사체를 발사하는 시스템입니다.
-fn player_shooting(
-    keyboard: Res<ButtonInput<KeyCode>>,
+/// 무적 시간을 줄이고 스프라이트를 깜빡이게 하는 시스템입니다.
+///
+/// 무적 시간이 끝나면 [`Invulnerable`]을 제거하고 스프라이트를 다시 표시합니다.
+fn tick_invulnerability(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    query: Query<&Transform, With<Player>>,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Invulnerable, &mut Visibility), With<Player>>,
 ) {
-    if keyboard.just_pressed(KeyCode::Space) {
-        // 플레이어가 없으면 조기 종료
-        let Ok(player_transform) = query.single() else {
-            return;
+    for (entity, mut invulnerable, mut visibility) in query.iter_mut() {
+        if invulnerable.0.tick(time.delta()).is_finished() {
+            *visibility = Visibility::Inherited;
+            commands.entity(entity).remove::<Invulnerable>();
+            continue;
+        }
+
+        let blink_step = (invulnerable.0.elapsed_secs() / INVULNERABILITY_BLINK_INTERVAL) as u32;
+        let blink_on = blink_step.is_multiple_of(2);
+        *visibility = if blink_on {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
         };
+    }
+}
+
+/// [`PlayerInput`]에 따라 플레이어를 이동시키는 시스템입니다.
+///
+/// 부활 대기 중에는 움직이지 않습니다.
+pub(crate) fn player_movement(
+    input: Res<PlayerInput>,
+    time: Res<Time>,
+    playfield: Res<Playfield>,
+    config: Res<GameConfig>,
+    mut query: Query<&mut Transform, (With<Player>, Without<Respawning>)>,
+) {
+    // 플레이어가 없으면 조기 종료 (안전 처리)
+    let Ok(mut transform) = query.single_mut() else {
+        return;
+    };
+
+    let direction = input.movement;
 
-        // bullet.png 이미지 로드
-        let texture: Handle<Image> = asset_server.load("bullet.png");
-
-        commands.spawn((
-            Sprite {
-                image: texture,
-                ..default()
-            },
-            Transform {
-                translation: Vec3::new(
-                    player_transform.translation.x,
-                    player_transform.translation.y + 40.0,
-                    0.0,
-                ),
-                scale: Vec3::splat(PROJECTILE_SCALE),
-                ..default()
-            },
-            Projectile,
-            Velocity(Vec2::new(0.0, PROJECTILE_SPEED)),
-            CollisionRadius(PROJECTILE_COLLISION_RADIUS),
-        ));
+    if direction != Vec2::ZERO {
+        let direction = direction.normalize();
+        let movement = direction * config.player_speed * time.delta_secs();
+        transform.translation.x += movement.x;
+        transform.translation.y += movement.y;
     }
+
+    // 화면 경계 제한 (충돌 반경 고려)
+    let half_width = playfield.half_extents().x - config.player_collision_radius;
+    let half_height = playfield.half_extents().y - config.player_collision_radius;
+
+    transform.translation.x = transform.translation.x.clamp(-half_width, half_width);
+    transform.translation.y = transform.translation.y.clamp(-half_height, half_height);
 }
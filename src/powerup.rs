@@ -0,0 +1,471 @@
+//! 파워업 모듈
+//!
+//! 격추된 적이 종류별 확률표(설정의 `drops`)에 따라 파워업 아이템을 떨어뜨립니다.
+//! 아이템은 천천히 내려오며, 플레이어가 닿으면 효과가 적용됩니다.
+//!
+//! # 효과 ([`PowerUpKind`])
+//! - SpreadShot: 일정 시간 양옆으로 기울어진 탄을 함께 발사
+//! - RapidFire: 일정 시간 발사 속도 증가
+//! - Shield: 일정 시간 모든 피해를 막음
+//! - ScoreMultiplier: 일정 시간 얻는 점수에 배율을 곱함
+//! - ExtraLife: 목숨 하나 추가 (즉시)
+//! - Bomb: 화면의 적 탄과 보스를 뺀 적을 모두 제거하고 보스에게 피해 (즉시)
+//! - WeaponUp: 무기 강화 단계 하나 올림 (즉시)
+//!
+//! 지속 효과는 [`ActivePowerUps`]에 남은 시간과 함께 저장되며, 같은 효과를 다시 얻으면 시간이 처음부터 다시 흐릅니다.
+//! 효과 자체는 발사(`weapon.rs`), 피해 처리(`damage.rs`) 시스템이 [`ActivePowerUps`]를 읽어 적용합니다.
+//! 폭탄으로 제거한 적은 아이템을 떨어뜨리지 않습니다.
+
+use std::collections::BTreeSet;
+
+use bevy::{ecs::message::MessageReader, prelude::*};
+use rand::RngExt;
+
+use crate::boss::{Boss, BossPart};
+use crate::collision::Hit;
+use crate::components::{
+    AngularVelocity, Collider, ColliderShape, Enemy, Health, Hostile, Lives, Pickup, Player,
+    PowerUpKind, Projectile, ScoreValue, Velocity,
+};
+use crate::config::GameConfig;
+use crate::damage::EnemyDestroyed;
+use crate::rank::{tick_rank, Rank};
+use crate::resources::{GameRng, Playfield, Playing, Score, SpriteAssets};
+use crate::weapon::Weapon;
+use crate::GameplaySet;
+
+/// 아이템이 1초에 도는 각도 (라디안)
+const PICKUP_SPIN_SPEED: f32 = 2.0;
+/// 방어막이 켜진 플레이어 스프라이트 색
+const SHIELD_COLOR: Color = Color::srgb(0.4, 1.0, 1.0);
+
+// =============================================================================
+// 적용 중인 효과
+// =============================================================================
+
+/// 적용 중인 지속 효과 하나입니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ActiveEffect {
+    pub kind: PowerUpKind,
+    /// 남은 시간 (초)
+    pub remaining: f32,
+}
+
+/// 적용 중인 지속 효과 목록을 저장하는 리소스입니다.
+///
+/// 효과는 [`PowerUpKind::ALL`] 순서로 정렬되어 있으며, 판을 시작할 때 비워집니다.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct ActivePowerUps {
+    effects: Vec<ActiveEffect>,
+}
+
+impl ActivePowerUps {
+    /// 적용 중인 효과 목록을 반환합니다.
+    pub fn effects(&self) -> &[ActiveEffect] {
+        &self.effects
+    }
+
+    /// 효과가 적용 중인지 반환합니다.
+    pub fn is_active(&self, kind: PowerUpKind) -> bool {
+        self.effects.iter().any(|effect| effect.kind == kind)
+    }
+
+    /// 효과를 `duration`초 동안 적용합니다. 이미 적용 중이면 남은 시간을 새로 정합니다.
+    pub fn activate(&mut self, kind: PowerUpKind, duration: f32) {
+        match self.effects.iter_mut().find(|effect| effect.kind == kind) {
+            Some(effect) => effect.remaining = duration,
+            None => {
+                self.effects.push(ActiveEffect {
+                    kind,
+                    remaining: duration,
+                });
+                self.effects.sort_by_key(|effect| effect.kind as usize);
+            }
+        }
+    }
+
+    /// 남은 시간을 `delta`초 줄이고 끝난 효과를 제거합니다.
+    pub fn tick(&mut self, delta: f32) {
+        for effect in &mut self.effects {
+            effect.remaining -= delta;
+        }
+        self.effects.retain(|effect| effect.remaining > 0.0);
+    }
+
+    /// 지금 얻는 점수에 곱할 배율을 반환합니다.
+    pub fn score_multiplier(&self, config: &GameConfig) -> u32 {
+        if self.is_active(PowerUpKind::ScoreMultiplier) {
+            config.powerups.score_multiplier
+        } else {
+            1
+        }
+    }
+}
+
+/// 플레이어가 폭탄을 주웠을 때 보내는 메시지입니다.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct BombDetonated;
+
+/// 파워업 종류별 아이템 색을 반환합니다.
+pub fn pickup_color(kind: PowerUpKind) -> Color {
+    match kind {
+        PowerUpKind::SpreadShot => Color::srgb(1.0, 0.6, 0.1),
+        PowerUpKind::RapidFire => Color::srgb(1.0, 1.0, 0.2),
+        PowerUpKind::Shield => Color::srgb(0.2, 0.9, 1.0),
+        PowerUpKind::ExtraLife => Color::srgb(0.3, 1.0, 0.3),
+        PowerUpKind::ScoreMultiplier => Color::srgb(1.0, 0.3, 0.9),
+        PowerUpKind::Bomb => Color::srgb(1.0, 0.2, 0.2),
+        PowerUpKind::WeaponUp => Color::srgb(0.5, 0.5, 1.0),
+    }
+}
+
+// =============================================================================
+// 파워업 플러그인
+// =============================================================================
+
+/// 파워업 아이템과 효과를 담당하는 플러그인입니다.
+pub struct PowerUpPlugin;
+
+impl Plugin for PowerUpPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ActivePowerUps>()
+            .add_message::<BombDetonated>()
+            .add_systems(OnEnter(Playing), reset_powerups)
+            .add_systems(OnExit(Playing), cleanup_pickups)
+            .add_systems(
+                FixedUpdate,
+                (
+                    spawn_drops,
+                    collect_pickups,
+                    detonate_bombs,
+                    tick_powerups,
+                    despawn_offscreen_pickups,
+                )
+                    .chain()
+                    .before(tick_rank)
+                    .in_set(GameplaySet::Cleanup),
+            )
+            .add_systems(Update, tint_shielded_player.run_if(in_state(Playing)));
+    }
+}
+
+// =============================================================================
+// 시스템 (Systems)
+// =============================================================================
+
+/// 판 시작 시 적용 중인 효과를 모두 지우는 시스템입니다.
+fn reset_powerups(mut powerups: ResMut<ActivePowerUps>) {
+    *powerups = ActivePowerUps::default();
+}
+
+/// 모든 아이템 엔티티를 정리하는 시스템입니다.
+fn cleanup_pickups(mut commands: Commands, query: Query<Entity, With<Pickup>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// 이번 틱에 격추된 적의 확률표에 따라 아이템을 떨어뜨리는 시스템입니다.
+///
+/// 확률표가 있는 종류만 난수를 뽑습니다.
+/// 같은 틱에 여러 적이 격추되면 위치, 종류, 점수 순서로 난수를 뽑아,
+/// 엔티티 번호가 녹화 때와 다른 리플레이에서도 같은 적이 같은 아이템을 떨어뜨립니다.
+/// 이 값이 모두 같은 격추끼리는 순서가 바뀌어도 결과가 같습니다.
+fn spawn_drops(
+    mut commands: Commands,
+    mut destroyed: MessageReader<EnemyDestroyed>,
+    sprites: Res<SpriteAssets>,
+    config: Res<GameConfig>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    let mut kills: Vec<&EnemyDestroyed> = destroyed.read().collect();
+    kills.sort_by(|a, b| {
+        a.position
+            .x
+            .total_cmp(&b.position.x)
+            .then(a.position.y.total_cmp(&b.position.y))
+            .then(a.kind.cmp(&b.kind))
+            .then(a.score.cmp(&b.score))
+    });
+
+    for event in kills {
+        let Some(kind) = event.kind else {
+            continue;
+        };
+        let stats = config.enemies.get(kind);
+        if stats.drops.is_empty() {
+            continue;
+        }
+
+        let roll = game_rng.rng().random_range(0.0..1.0);
+        let Some(powerup) = stats.pick_drop(roll) else {
+            continue;
+        };
+
+        commands.spawn((
+            Sprite {
+                image: sprites.bullet.clone(),
+                color: pickup_color(powerup),
+                ..default()
+            },
+            Transform {
+                translation: event.position.extend(0.0),
+                scale: Vec3::splat(config.powerups.scale),
+                ..default()
+            },
+            Pickup,
+            powerup,
+            Velocity(Vec2::new(0.0, -config.powerups.fall_speed)),
+            AngularVelocity(PICKUP_SPIN_SPEED),
+            Collider::pickup(ColliderShape::Circle {
+                radius: config.powerups.collision_radius,
+            }),
+        ));
+    }
+}
+
+/// 플레이어에 닿은 아이템을 제거하고 효과를 적용하는 시스템입니다.
+///
+/// 플레이어가 아이템에 닿은 것은 충돌 단계의 [`Hit`] 메시지로 알 수 있습니다.
+/// 부활 대기 중인 플레이어는 충돌하지 않으므로 아이템을 줍지 않고,
+/// 이번 틱에 떨어진 아이템은 충돌 단계 뒤에 생기므로 다음 틱부터 줍습니다.
+fn collect_pickups(
+    mut commands: Commands,
+    mut hits: MessageReader<Hit>,
+    config: Res<GameConfig>,
+    mut powerups: ResMut<ActivePowerUps>,
+    mut bombs: MessageWriter<BombDetonated>,
+    mut player: Query<(&mut Lives, &mut Weapon), With<Player>>,
+    pickups: Query<&PowerUpKind, With<Pickup>>,
+) {
+    // 같은 아이템은 한 번만 줍기 (효과는 줍는 순서와 무관)
+    let collected: BTreeSet<Entity> = hits
+        .read()
+        .filter(|hit| player.contains(hit.attacker))
+        .map(|hit| hit.target)
+        .collect();
+
+    for entity in collected {
+        let Ok(kind) = pickups.get(entity) else {
+            continue;
+        };
+        let Ok((mut lives, mut weapon)) = player.single_mut() else {
+            return;
+        };
+
+        commands.entity(entity).despawn();
+        if let Some(duration) = config.powerups.duration(*kind) {
+            powerups.activate(*kind, duration);
+            continue;
+        }
+        match kind {
+            PowerUpKind::ExtraLife => lives.0 += 1,
+            PowerUpKind::Bomb => {
+                bombs.write(BombDetonated);
+            }
+            PowerUpKind::WeaponUp => weapon.upgrade(&config),
+            _ => {}
+        }
+    }
+}
+
+/// 폭탄 효과를 적용하는 시스템입니다.
+///
+/// 적 탄과 보스를 뺀 모든 적을 제거하고 점수를 얻으며,
+/// 피해를 받는 상태의 보스에게는 설정된 피해를 주되 체력을 1 아래로 깎지는 않습니다.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn detonate_bombs(
+    mut commands: Commands,
+    mut bombs: MessageReader<BombDetonated>,
+    config: Res<GameConfig>,
+    powerups: Res<ActivePowerUps>,
+    mut score: ResMut<Score>,
+    mut rank: ResMut<Rank>,
+    enemies: Query<(Entity, &ScoreValue), (With<Enemy>, Without<Boss>, Without<BossPart>)>,
+    mut bosses: Query<(&Boss, &mut Health)>,
+    hostile_projectiles: Query<Entity, (With<Projectile>, With<Hostile>)>,
+) {
+    if bombs.read().count() == 0 {
+        return;
+    }
+
+    let multiplier = powerups.score_multiplier(&config);
+    for (entity, score_value) in enemies.iter() {
+        commands.entity(entity).despawn();
+        score.0 += score_value.0 * multiplier;
+        rank.record_kill(&config.rank);
+    }
+    for projectile in hostile_projectiles.iter() {
+        commands.entity(projectile).despawn();
+    }
+    for (boss, mut health) in bosses.iter_mut() {
+        if boss.is_vulnerable() {
+            health.current = health
+                .current
+                .saturating_sub(config.powerups.bomb_boss_damage)
+                .max(1);
+        }
+    }
+}
+
+/// 지속 효과의 남은 시간을 줄이는 시스템입니다.
+fn tick_powerups(time: Res<Time>, mut powerups: ResMut<ActivePowerUps>) {
+    powerups.tick(time.delta_secs());
+}
+
+/// 화면 아래로 나간 아이템을 제거하는 시스템입니다.
+fn despawn_offscreen_pickups(
+    mut commands: Commands,
+    playfield: Res<Playfield>,
+    query: Query<(Entity, &Transform), With<Pickup>>,
+) {
+    let min_y = -playfield.half_extents().y - 50.0;
+
+    for (entity, transform) in query.iter() {
+        if transform.translation.y < min_y {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// 방어막이 켜져 있으면 플레이어 스프라이트 색을 바꾸는 시스템입니다.
+fn tint_shielded_player(
+    powerups: Res<ActivePowerUps>,
+    mut player: Query<&mut Sprite, With<Player>>,
+) {
+    let color = if powerups.is_active(PowerUpKind::Shield) {
+        SHIELD_COLOR
+    } else {
+        Color::WHITE
+    };
+    for mut sprite in player.iter_mut() {
+        if sprite.color != color {
+            sprite.color = color;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::boss::BossState;
+
+    fn remaining(powerups: &ActivePowerUps) -> Vec<(PowerUpKind, f32)> {
+        powerups
+            .effects()
+            .iter()
+            .map(|effect| (effect.kind, effect.remaining))
+            .collect()
+    }
+
+    #[test]
+    fn effects_tick_down_and_expire() {
+        let mut powerups = ActivePowerUps::default();
+        powerups.activate(PowerUpKind::Shield, 1.0);
+        powerups.activate(PowerUpKind::SpreadShot, 2.0);
+        // 얻은 순서와 상관없이 PowerUpKind::ALL 순서
+        assert_eq!(
+            remaining(&powerups),
+            [(PowerUpKind::SpreadShot, 2.0), (PowerUpKind::Shield, 1.0)]
+        );
+
+        powerups.tick(0.5);
+        assert_eq!(
+            remaining(&powerups),
+            [(PowerUpKind::SpreadShot, 1.5), (PowerUpKind::Shield, 0.5)]
+        );
+
+        // 남은 시간이 딱 0이 되면 끝남
+        powerups.tick(0.5);
+        assert!(!powerups.is_active(PowerUpKind::Shield));
+        assert_eq!(remaining(&powerups), [(PowerUpKind::SpreadShot, 1.0)]);
+    }
+
+    #[test]
+    fn picking_up_the_same_effect_restarts_its_timer() {
+        let mut powerups = ActivePowerUps::default();
+        powerups.activate(PowerUpKind::RapidFire, 2.0);
+        powerups.tick(1.5);
+        powerups.activate(PowerUpKind::RapidFire, 2.0);
+        assert_eq!(remaining(&powerups), [(PowerUpKind::RapidFire, 2.0)]);
+
+        // 남은 시간이 더 길어도 새 지속 시간으로 맞춤 (쌓이지 않음)
+        powerups.activate(PowerUpKind::RapidFire, 1.0);
+        assert_eq!(remaining(&powerups), [(PowerUpKind::RapidFire, 1.0)]);
+
+        powerups.tick(1.0);
+        assert!(powerups.effects().is_empty());
+    }
+
+    #[test]
+    fn score_multiplier_only_while_active() {
+        let config = GameConfig::default();
+        let mut powerups = ActivePowerUps::default();
+        assert_eq!(powerups.score_multiplier(&config), 1);
+        powerups.activate(PowerUpKind::ScoreMultiplier, 1.0);
+        assert_eq!(
+            powerups.score_multiplier(&config),
+            config.powerups.score_multiplier
+        );
+    }
+
+    #[test]
+    fn bomb_clears_enemies_and_bullets_but_only_wounds_the_boss() {
+        let mut world = World::new();
+        world.init_resource::<GameConfig>();
+        world.init_resource::<ActivePowerUps>();
+        world.init_resource::<Score>();
+        world.init_resource::<Rank>();
+        world.init_resource::<Messages<BombDetonated>>();
+
+        let drones = [
+            world.spawn((Enemy, ScoreValue(100))).id(),
+            world.spawn((Enemy, ScoreValue(250))).id(),
+        ];
+        let bullet = world.spawn((Projectile, Hostile)).id();
+        let own_bullet = world.spawn(Projectile).id();
+        let boss = world
+            .spawn((
+                Enemy,
+                Boss {
+                    state: BossState::Fighting,
+                    phase: 0,
+                    state_time: 0.0,
+                },
+                Health {
+                    current: 5,
+                    max: 100,
+                },
+                ScoreValue(5000),
+            ))
+            .id();
+        let part = world
+            .spawn((
+                Enemy,
+                BossPart {
+                    boss,
+                    offset: Vec2::ZERO,
+                },
+                ScoreValue(0),
+            ))
+            .id();
+
+        // 폭탄이 없으면 아무 일도 없음
+        world.run_system_once(detonate_bombs).unwrap();
+        assert!(world.get_entity(drones[0]).is_ok());
+
+        world.write_message(BombDetonated);
+        world.run_system_once(detonate_bombs).unwrap();
+        for entity in [drones[0], drones[1], bullet] {
+            assert!(world.get_entity(entity).is_err());
+        }
+        for entity in [own_bullet, boss, part] {
+            assert!(world.get_entity(entity).is_ok());
+        }
+        assert_eq!(world.resource::<Score>().0, 350);
+        // 남은 체력보다 큰 피해여도 1은 남김
+        assert_eq!(world.get::<Health>(boss).unwrap().current, 1);
+    }
+}
@@ -1,14 +1,24 @@
 //! 투사체 모듈
 //!
-//! 투사체의 이동과 화면 밖 제거를 담당합니다.
-//! 투사체 발사는 player.rs에서 처리합니다.
-//! InGame 상태에서만 동작합니다.
+//! 투사체의 화면 밖 제거와 수명([`Lifetime`])이 다한 투사체 제거를 담당합니다.
+//! 이동은 운동 모듈(`kinematics.rs`)이 처리하며, 투사체는 진행 방향을 향하고([`FaceVelocity`])
+//! 이동 전 위치([`PreviousPosition`])를 기록해 충돌 검사가 한 틱 동안 지나간 경로를 검사합니다.
+//! 투사체 발사는 weapon.rs(플레이어)와 enemy.rs, pattern.rs(적)에서 처리합니다.
+//! 적 투사체는 [`spawn_hostile_projectile`]로 만듭니다.
+//! 게임플레이 중(InGame, Replay)에만 동작합니다.
 
-use bevy::prelude::*;
-use bevy::window::PrimaryWindow;
+use bevy::{ecs::system::EntityCommands, prelude::*};
 
-use crate::components::{Projectile, Velocity};
-use crate::resources::AppState;
+use crate::components::{
+    Collider, ColliderShape, Damage, FaceVelocity, Hostile, Lifetime, PreviousPosition, Projectile,
+    Velocity,
+};
+use crate::config::GameConfig;
+use crate::resources::{Playfield, Playing, SpriteAssets};
+use crate::GameplaySet;
+
+/// 적 투사체 스프라이트에 곱하는 색 (플레이어 투사체와 구분)
+const HOSTILE_PROJECTILE_COLOR: Color = Color::srgb(1.0, 0.35, 0.45);
 
 // =============================================================================
 // 투사체 플러그인
@@ -21,17 +31,56 @@ impl Plugin for ProjectilePlugin {
     fn build(&self, app: &mut App) {
         app
             // 게임 종료 시 모든 투사체 정리
-            .add_systems(OnExit(AppState::InGame), cleanup_projectiles)
-            // 게임플레이 시스템 (InGame 상태에서만 실행)
+            .add_systems(OnExit(Playing), cleanup_projectiles)
+            // 게임플레이 시스템 (고정 틱, 게임플레이 중에만 실행)
             .add_systems(
-                Update,
-                (projectile_movement, despawn_offscreen_projectiles)
-                    .chain()
-                    .run_if(in_state(AppState::InGame)),
+                FixedUpdate,
+                (despawn_offscreen_projectiles, despawn_expired_projectiles)
+                    .in_set(GameplaySet::Cleanup),
             );
     }
 }
 
+// =============================================================================
+// 적 투사체 생성
+// =============================================================================
+
+/// 적 투사체([`Hostile`])를 스폰하고 [`EntityCommands`]를 반환합니다.
+///
+/// 크기, 충돌 반경, 피해는 설정의 적 투사체 값을 사용합니다.
+/// `direction`은 단위 벡터여야 하며, 속력이 0이어도 스프라이트는 이 방향을 향합니다.
+pub fn spawn_hostile_projectile<'a>(
+    commands: &'a mut Commands,
+    sprites: &SpriteAssets,
+    config: &GameConfig,
+    position: Vec2,
+    direction: Vec2,
+    speed: f32,
+) -> EntityCommands<'a> {
+    commands.spawn((
+        Sprite {
+            image: sprites.bullet.clone(),
+            color: HOSTILE_PROJECTILE_COLOR,
+            ..default()
+        },
+        Transform {
+            translation: position.extend(0.0),
+            // 투사체 스프라이트는 위쪽을 향하므로 진행 방향으로 회전
+            rotation: Quat::from_rotation_arc_2d(Vec2::Y, direction),
+            scale: Vec3::splat(config.enemy_projectile_scale),
+        },
+        Projectile,
+        Hostile,
+        Velocity(direction * speed),
+        FaceVelocity,
+        PreviousPosition(position),
+        Collider::enemy_bullet(ColliderShape::Circle {
+            radius: config.enemy_projectile_collision_radius,
+        }),
+        Damage(config.enemy_projectile_damage),
+    ))
+}
+
 // =============================================================================
 // 시스템 (Systems)
 // =============================================================================
@@ -43,30 +92,34 @@ fn cleanup_projectiles(mut commands: Commands, query: Query<Entity, With<Project
     }
 }
 
-/// 모든 투사체를 속도에 따라 이동시키는 시스템입니다.
-fn projectile_movement(
-    time: Res<Time>,
-    mut query: Query<(&mut Transform, &Velocity), With<Projectile>>,
-) {
-    for (mut transform, velocity) in query.iter_mut() {
-        transform.translation.x += velocity.0.x * time.delta_secs();
-        transform.translation.y += velocity.0.y * time.delta_secs();
-    }
-}
-
 /// 화면 밖으로 나간 투사체를 제거하는 시스템입니다.
+///
+/// 적 투사체는 어느 방향으로든 날아가므로 네 변 모두 검사합니다.
+/// 수명이 있는 투사체(유도탄)는 화면 밖으로 돌아 나갔다 들어올 수 있으므로 수명으로만 제거합니다.
+#[allow(clippy::type_complexity)]
 fn despawn_offscreen_projectiles(
     mut commands: Commands,
-    query: Query<(Entity, &Transform), With<Projectile>>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
+    playfield: Res<Playfield>,
+    query: Query<(Entity, &Transform), (With<Projectile>, Without<Lifetime>)>,
 ) {
-    let Ok(window) = window_query.single() else {
-        return;
-    };
-    let max_y = window.height() / 2.0 + 50.0;
+    let max = playfield.half_extents() + Vec2::splat(50.0);
 
     for (entity, transform) in query.iter() {
-        if transform.translation.y > max_y {
+        let position = transform.translation.truncate();
+        if position.x.abs() > max.x || position.y.abs() > max.y {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// 수명이 다한 투사체를 제거하는 시스템입니다.
+fn despawn_expired_projectiles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Lifetime), With<Projectile>>,
+) {
+    for (entity, mut lifetime) in query.iter_mut() {
+        if lifetime.0.tick(time.delta()).is_finished() {
             commands.entity(entity).despawn();
         }
     }
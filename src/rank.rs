@@ -0,0 +1,283 @@
+//! 랭크 모듈
+//!
+//! 아케이드 슈팅 게임의 숨은 랭크처럼 플레이 실력에 따라 난이도를 조절합니다.
+//! 랭크([`Rank::value`])는 0.0 ~ 1.0 사이 값이며 화면에는 보이지 않습니다 (디버그 오버레이 제외).
+//!
+//! # 랭크 변화
+//! - 오름: 적 격추, 명중, 피격 없이 버틴 시간 (연속 무피격 시간이 길수록 빠르게)
+//! - 내림: 발사 (빗나간 탄이 많으면 명중으로 얻는 만큼을 까먹음), 사망
+//!
+//! # 랭크 효과
+//! 랭크가 높을수록 적 스폰 빈도, 적 체력, 적 투사체 속도가 늘어납니다.
+//! 효과는 [`Difficulty`] 배율에 곱해지므로 스테이지와 무한 모드 모두에 적용됩니다.
+//! 수치는 설정 파일의 `rank` 항목에서 정합니다.
+//!
+//! # 판 기록
+//! 랭크는 1초마다 [`RankHistory`]에 기록됩니다.
+//! [`RunLogPlugin`]을 추가하면 판이 끝날 때마다 랭크 변화와 통계를
+//! 데이터 폴더의 `oxide-rain/runs.ron`에 한 줄씩 덧붙여 밸런스 분석에 쓸 수 있습니다.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::config::{GameConfig, RankSettings};
+use crate::leaderboard::DATA_DIR_NAME;
+use crate::replay::ReplayPlayback;
+use crate::resources::{AppState, Difficulty, PlayerName, Playing, RunDuration, Score};
+use crate::GameplaySet;
+
+/// 판 기록 파일 이름
+pub const RUN_LOG_FILE: &str = "runs.ron";
+
+// =============================================================================
+// 랭크
+// =============================================================================
+
+/// 숨은 랭크와 랭크 계산에 쓰는 판 통계를 저장하는 리소스입니다.
+///
+/// 판을 시작할 때 설정의 초기 랭크로 돌아갑니다.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct Rank {
+    /// 현재 랭크 (0.0 ~ 1.0)
+    pub value: f32,
+    /// 이번 판의 최고 랭크
+    pub peak: f32,
+    /// 격추 수
+    pub kills: u32,
+    /// 발사 수
+    pub shots: u32,
+    /// 명중 수
+    pub hits: u32,
+    /// 사망 수
+    pub deaths: u32,
+    /// 마지막으로 피해를 입은 뒤 흐른 시간 (초)
+    pub streak: f32,
+}
+
+impl Rank {
+    /// 설정의 초기 랭크로 새 판의 랭크를 만듭니다.
+    pub fn new(settings: &RankSettings) -> Self {
+        let value = settings.initial.clamp(0.0, 1.0);
+        Self {
+            value,
+            peak: value,
+            ..default()
+        }
+    }
+
+    /// 명중률 (0.0 ~ 1.0). 아직 쏘지 않았으면 1.0입니다.
+    pub fn accuracy(&self) -> f32 {
+        if self.shots == 0 {
+            1.0
+        } else {
+            self.hits as f32 / self.shots as f32
+        }
+    }
+
+    /// 플레이어가 한 발 쐈을 때 호출합니다.
+    pub fn record_shot(&mut self, settings: &RankSettings) {
+        self.shots += 1;
+        self.add(-settings.shot_cost);
+    }
+
+    /// 플레이어 투사체가 적에게 명중했을 때 호출합니다.
+    pub fn record_hit(&mut self, settings: &RankSettings) {
+        self.hits += 1;
+        self.add(settings.hit_gain);
+    }
+
+    /// 적을 격추했을 때 호출합니다.
+    pub fn record_kill(&mut self, settings: &RankSettings) {
+        self.kills += 1;
+        self.add(settings.kill_gain);
+    }
+
+    /// 플레이어가 피해를 입었을 때 호출합니다. 무피격 시간이 초기화됩니다.
+    pub fn record_damage(&mut self) {
+        self.streak = 0.0;
+    }
+
+    /// 플레이어가 목숨을 잃었을 때 호출합니다.
+    pub fn record_death(&mut self, settings: &RankSettings) {
+        self.deaths += 1;
+        self.streak = 0.0;
+        self.add(-settings.death_penalty);
+    }
+
+    /// 무피격 시간을 `delta`초 늘리고 그만큼 랭크를 올립니다.
+    ///
+    /// 무피격 시간이 `streak_ramp`초에 이를 때까지 오르는 속도가 선형으로 빨라집니다.
+    pub fn tick(&mut self, delta: f32, settings: &RankSettings) {
+        self.streak += delta;
+        let ramp = (self.streak / settings.streak_ramp).min(1.0);
+        self.add(settings.streak_gain * ramp * delta);
+    }
+
+    /// 난이도 배율에 랭크 효과를 곱합니다.
+    pub fn apply(&self, difficulty: &mut Difficulty, settings: &RankSettings) {
+        difficulty.spawn_rate *= 1.0 + self.value * settings.spawn_rate_bonus;
+        difficulty.enemy_health *= 1.0 + self.value * settings.enemy_health_bonus;
+        difficulty.bullet_speed *= 1.0 + self.value * settings.bullet_speed_bonus;
+    }
+
+    fn add(&mut self, amount: f32) {
+        self.value = (self.value + amount).clamp(0.0, 1.0);
+        self.peak = self.peak.max(self.value);
+    }
+}
+
+/// 이번 판의 랭크를 1초마다 기록하는 리소스입니다.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct RankHistory {
+    /// 1초 간격 랭크 기록
+    pub samples: Vec<f32>,
+    /// 다음 기록까지 남은 시간
+    timer: Timer,
+}
+
+// =============================================================================
+// 랭크 플러그인
+// =============================================================================
+
+/// 랭크 계산과 기록을 담당하는 플러그인입니다.
+///
+/// 격추, 명중, 발사, 피격은 각 게임플레이 시스템이 [`Rank`]에 직접 기록합니다.
+pub struct RankPlugin;
+
+impl Plugin for RankPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Rank>()
+            .init_resource::<RankHistory>()
+            .add_systems(OnEnter(Playing), reset_rank)
+            .add_systems(FixedUpdate, tick_rank.in_set(GameplaySet::Cleanup));
+    }
+}
+
+/// 판 시작 시 랭크와 기록을 초기화하는 시스템입니다.
+fn reset_rank(config: Res<GameConfig>, mut rank: ResMut<Rank>, mut history: ResMut<RankHistory>) {
+    *rank = Rank::new(&config.rank);
+    *history = RankHistory {
+        samples: vec![rank.value],
+        timer: Timer::from_seconds(1.0, TimerMode::Repeating),
+    };
+}
+
+/// 무피격 시간에 따라 랭크를 올리고 1초마다 기록하는 시스템입니다.
+///
+/// 랭크는 범위로 잘리므로, 같은 단계에서 랭크를 바꾸는 시스템은 이 시스템과 순서를 정해야 합니다.
+pub(crate) fn tick_rank(
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    mut rank: ResMut<Rank>,
+    mut history: ResMut<RankHistory>,
+) {
+    rank.tick(time.delta_secs(), &config.rank);
+
+    if history.timer.tick(time.delta()).just_finished() {
+        let value = rank.value;
+        history.samples.push(value);
+    }
+}
+
+// =============================================================================
+// 판 기록
+// =============================================================================
+
+/// 판 기록 파일에 한 줄로 저장되는 판 하나의 기록입니다.
+#[derive(Serialize, Debug, Clone)]
+pub struct RunRecord {
+    /// 플레이어 닉네임
+    pub name: String,
+    /// 기록 시각 (유닉스 시간, 초)
+    pub recorded_at: u64,
+    /// 최종 점수
+    pub score: u32,
+    /// 플레이 시간 (초)
+    pub duration_secs: f32,
+    /// 마지막 난이도 레벨
+    pub level: u32,
+    /// 마지막 랭크
+    pub final_rank: f32,
+    /// 최고 랭크
+    pub peak_rank: f32,
+    pub kills: u32,
+    pub shots: u32,
+    pub hits: u32,
+    pub deaths: u32,
+    /// 1초 간격 랭크 기록
+    pub rank_history: Vec<f32>,
+}
+
+impl RunRecord {
+    /// 판 기록 파일을 데이터 폴더에 만들 경로를 반환합니다.
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::data_dir().map(|dir| dir.join(DATA_DIR_NAME).join(RUN_LOG_FILE))
+    }
+
+    /// 기록을 파일 끝에 한 줄로 덧붙입니다.
+    pub fn append_to(&self, path: &std::path::Path) -> std::io::Result<()> {
+        let line = ron::to_string(self).map_err(std::io::Error::other)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{line}")
+    }
+}
+
+/// 판이 끝날 때마다 [`RunRecord`]를 디스크에 남기는 플러그인입니다.
+///
+/// 디스크에 접근하므로 [`crate::GamePlugins`]에는 포함되지 않습니다.
+/// 리플레이 재생은 기록하지 않습니다.
+pub struct RunLogPlugin;
+
+impl Plugin for RunLogPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::GameOver), write_run_record);
+    }
+}
+
+/// 끝난 판의 랭크와 통계를 판 기록 파일에 덧붙이는 시스템입니다.
+fn write_run_record(
+    rank: Res<Rank>,
+    history: Res<RankHistory>,
+    difficulty: Res<Difficulty>,
+    score: Res<Score>,
+    run_duration: Res<RunDuration>,
+    player_name: Res<PlayerName>,
+    playback: Option<Res<ReplayPlayback>>,
+) {
+    if playback.is_some() {
+        return;
+    }
+    let Some(path) = RunRecord::default_path() else {
+        return;
+    };
+
+    let record = RunRecord {
+        name: player_name.0.clone(),
+        recorded_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default(),
+        score: score.0,
+        duration_secs: run_duration.0.as_secs_f32(),
+        level: difficulty.level,
+        final_rank: rank.value,
+        peak_rank: rank.peak,
+        kills: rank.kills,
+        shots: rank.shots,
+        hits: rank.hits,
+        deaths: rank.deaths,
+        rank_history: history.samples.clone(),
+    };
+
+    if let Err(err) = record.append_to(&path) {
+        error!("Failed to write run record to {}: {err}", path.display());
+    }
+}
@@ -0,0 +1,685 @@
+//! 리플레이 모듈
+//!
+//! 판의 시드와 틱별 플레이어 입력을 녹화하고, 녹화된 입력으로 판을 다시 재생합니다.
+//! 게임플레이가 고정 틱과 시드 기반 난수로 진행되므로
+//! 같은 시드와 같은 입력을 넣으면 최종 점수까지 똑같이 재현됩니다.
+//!
+//! # 파일 형식 (리틀 엔디언)
+//! ```text
+//! magic        [u8; 4]   "OXRP"
+//! version      u16       REPLAY_VERSION
+//! seed         u64       GameRng 시드
+//! timestep     u64       고정 틱 간격 (나노초)
+//! config_hash  u64       녹화 당시 GameConfig 지문
+//! final_score  u32       녹화 당시 최종 점수
+//! run_count    u32       이어지는 (입력, 반복 횟수) 쌍의 개수
+//! runs         [(u8, u16); run_count]
+//! ```
+//! 틱 입력은 1바이트로 인코딩하고, 같은 입력이 이어지는 구간은 한 쌍으로 압축합니다.
+//! 쌍의 개수가 남은 파일 길이와 맞지 않거나 풀어낸 틱 수가 [`MAX_REPLAY_TICKS`]를 넘으면 거부합니다.
+//!
+//! 헤더나 입력 비트 구성이 바뀌면 `REPLAY_VERSION`을 올려 이전 파일을 거부합니다.
+//! - 1: 최초 형식
+//! - 2: `config_hash` 추가
+//! - 3: 입력 비트 5 (발사 버튼 누르고 있음) 추가
+//! - 4: 입력 비트 6 (무기 교체) 추가
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bevy::prelude::*;
+
+use crate::config::GameConfig;
+use crate::resources::{AppState, GameRng, PlayerInput, Score};
+use crate::GameplaySet;
+
+// =============================================================================
+// 상수
+// =============================================================================
+
+/// 파일 앞에 붙는 식별자
+pub const REPLAY_MAGIC: [u8; 4] = *b"OXRP";
+/// 현재 리플레이 파일 버전
+pub const REPLAY_VERSION: u16 = 4;
+/// 리플레이 파일을 저장하는 폴더
+pub const REPLAY_DIR: &str = "replays";
+/// 리플레이 파일 확장자
+pub const REPLAY_EXTENSION: &str = "oxr";
+/// 리플레이 한 개에 담을 수 있는 최대 틱 수 (60Hz로 24시간)
+///
+/// 손상되거나 조작된 파일이 반복 횟수만으로 메모리를 바닥내지 못하게 읽을 때 확인합니다.
+pub const MAX_REPLAY_TICKS: usize = 24 * 60 * 60 * 60;
+
+// 틱 입력 비트 구성
+const INPUT_RIGHT: u8 = 1 << 0;
+const INPUT_LEFT: u8 = 1 << 1;
+const INPUT_UP: u8 = 1 << 2;
+const INPUT_DOWN: u8 = 1 << 3;
+const INPUT_FIRE: u8 = 1 << 4;
+const INPUT_FIRE_HELD: u8 = 1 << 5;
+const INPUT_SWITCH_WEAPON: u8 = 1 << 6;
+
+// =============================================================================
+// 리플레이 데이터
+// =============================================================================
+
+/// 녹화된 한 판의 리플레이입니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replay {
+    /// 판 시작 시의 GameRng 시드
+    pub seed: u64,
+    /// 녹화 당시 고정 틱 간격 (나노초)
+    pub timestep_nanos: u64,
+    /// 녹화 당시 [`GameConfig::fingerprint`]
+    pub config_hash: u64,
+    /// 녹화 당시 최종 점수
+    pub final_score: u32,
+    /// 틱별 인코딩된 입력 (한 틱에 1바이트)
+    pub inputs: Vec<u8>,
+}
+
+impl Replay {
+    /// 파일에 기록할 바이트 배열로 변환합니다.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let runs = run_length_encode(&self.inputs);
+
+        let mut bytes = Vec::with_capacity(38 + runs.len() * 3);
+        bytes.extend_from_slice(&REPLAY_MAGIC);
+        bytes.extend_from_slice(&REPLAY_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&self.seed.to_le_bytes());
+        bytes.extend_from_slice(&self.timestep_nanos.to_le_bytes());
+        bytes.extend_from_slice(&self.config_hash.to_le_bytes());
+        bytes.extend_from_slice(&self.final_score.to_le_bytes());
+        bytes.extend_from_slice(&(runs.len() as u32).to_le_bytes());
+        for (input, count) in runs {
+            bytes.push(input);
+            bytes.extend_from_slice(&count.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// 파일에서 읽은 바이트 배열을 리플레이로 변환합니다.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ReplayError> {
+        let mut reader = ByteReader { bytes, pos: 0 };
+
+        if reader.take::<4>()? != REPLAY_MAGIC {
+            return Err(ReplayError::BadMagic);
+        }
+        let version = u16::from_le_bytes(reader.take()?);
+        if version != REPLAY_VERSION {
+            return Err(ReplayError::UnsupportedVersion(version));
+        }
+
+        let seed = u64::from_le_bytes(reader.take()?);
+        let timestep_nanos = u64::from_le_bytes(reader.take()?);
+        let config_hash = u64::from_le_bytes(reader.take()?);
+        let final_score = u32::from_le_bytes(reader.take()?);
+        let run_count = u32::from_le_bytes(reader.take()?) as usize;
+        // 쌍 하나가 3바이트이므로 남은 길이와 다르면 읽기 전에 거부
+        if reader.remaining() != run_count * 3 {
+            return Err(ReplayError::Truncated);
+        }
+
+        let mut inputs = Vec::new();
+        for _ in 0..run_count {
+            let [input] = reader.take()?;
+            let count = u16::from_le_bytes(reader.take()?) as usize;
+            if inputs.len() + count > MAX_REPLAY_TICKS {
+                return Err(ReplayError::TooLong);
+            }
+            inputs.extend(std::iter::repeat_n(input, count));
+        }
+
+        Ok(Self {
+            seed,
+            timestep_nanos,
+            config_hash,
+            final_score,
+            inputs,
+        })
+    }
+
+    /// 리플레이를 파일로 저장합니다.
+    pub fn save(&self, path: &Path) -> Result<(), ReplayError> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, self.to_bytes())?;
+        Ok(())
+    }
+
+    /// 파일에서 리플레이를 읽어옵니다.
+    pub fn load(path: &Path) -> Result<Self, ReplayError> {
+        Self::from_bytes(&fs::read(path)?)
+    }
+}
+
+/// 리플레이를 읽거나 쓸 때 발생하는 오류입니다.
+#[derive(Debug)]
+pub enum ReplayError {
+    /// 파일 입출력 오류
+    Io(std::io::Error),
+    /// 리플레이 파일이 아님
+    BadMagic,
+    /// 지원하지 않는 파일 버전
+    UnsupportedVersion(u16),
+    /// 파일이 중간에 끊기거나 길이가 맞지 않음
+    Truncated,
+    /// 풀어낸 틱 수가 [`MAX_REPLAY_TICKS`]를 넘음
+    TooLong,
+    /// 저장된 리플레이가 없음
+    NotFound,
+    /// 녹화 당시와 현재의 고정 틱 간격이 다름
+    TimestepMismatch { recorded: u64, current: u64 },
+    /// 녹화 당시와 현재의 게임 설정이 다름
+    ConfigMismatch { recorded: u64, current: u64 },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "입출력 오류: {err}"),
+            Self::BadMagic => write!(f, "리플레이 파일이 아닙니다"),
+            Self::UnsupportedVersion(version) => {
+                write!(
+                    f,
+                    "지원하지 않는 리플레이 버전입니다: {version} (현재 {REPLAY_VERSION})"
+                )
+            }
+            Self::Truncated => write!(f, "리플레이 파일이 손상되었습니다"),
+            Self::TooLong => write!(f, "리플레이가 너무 깁니다 (최대 {MAX_REPLAY_TICKS}틱)"),
+            Self::NotFound => write!(f, "저장된 리플레이가 없습니다"),
+            Self::TimestepMismatch { recorded, current } => write!(
+                f,
+                "고정 틱 간격이 다릅니다 (녹화 {recorded}ns, 현재 {current}ns)"
+            ),
+            Self::ConfigMismatch { recorded, current } => write!(
+                f,
+                "게임 설정이 다릅니다 (녹화 {recorded:016x}, 현재 {current:016x})"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+impl From<std::io::Error> for ReplayError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// 바이트 배열을 앞에서부터 읽는 간단한 리더입니다.
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl ByteReader<'_> {
+    /// 아직 읽지 않은 바이트 수
+    fn remaining(&self) -> usize {
+        self.bytes.len() - self.pos
+    }
+
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], ReplayError> {
+        let end = self.pos + N;
+        let slice = self
+            .bytes
+            .get(self.pos..end)
+            .ok_or(ReplayError::Truncated)?;
+        self.pos = end;
+        Ok(slice.try_into().expect("slice length is N"))
+    }
+}
+
+/// 연속된 같은 입력을 (입력, 반복 횟수) 쌍으로 압축합니다.
+fn run_length_encode(inputs: &[u8]) -> Vec<(u8, u16)> {
+    let mut runs: Vec<(u8, u16)> = Vec::new();
+    for &input in inputs {
+        match runs.last_mut() {
+            Some((last, count)) if *last == input && *count < u16::MAX => *count += 1,
+            _ => runs.push((input, 1)),
+        }
+    }
+    runs
+}
+
+/// 틱 입력을 1바이트로 인코딩합니다.
+///
+/// 키보드 이동 입력은 각 축이 -1, 0, 1 중 하나이므로 부호만 저장해도 손실이 없습니다.
+pub fn encode_input(input: &PlayerInput) -> u8 {
+    let mut bits = 0;
+    if input.movement.x > 0.0 {
+        bits |= INPUT_RIGHT;
+    }
+    if input.movement.x < 0.0 {
+        bits |= INPUT_LEFT;
+    }
+    if input.movement.y > 0.0 {
+        bits |= INPUT_UP;
+    }
+    if input.movement.y < 0.0 {
+        bits |= INPUT_DOWN;
+    }
+    if input.fire {
+        bits |= INPUT_FIRE;
+    }
+    if input.fire_held {
+        bits |= INPUT_FIRE_HELD;
+    }
+    if input.switch_weapon {
+        bits |= INPUT_SWITCH_WEAPON;
+    }
+    bits
+}
+
+/// 1바이트 틱 입력을 [`PlayerInput`]으로 되돌립니다.
+pub fn decode_input(bits: u8) -> PlayerInput {
+    let axis = |positive: u8, negative: u8| {
+        (bits & positive != 0) as i32 as f32 - (bits & negative != 0) as i32 as f32
+    };
+
+    PlayerInput {
+        movement: Vec2::new(axis(INPUT_RIGHT, INPUT_LEFT), axis(INPUT_UP, INPUT_DOWN)),
+        fire: bits & INPUT_FIRE != 0,
+        fire_held: bits & INPUT_FIRE_HELD != 0,
+        switch_weapon: bits & INPUT_SWITCH_WEAPON != 0,
+    }
+}
+
+/// 새 리플레이 파일 경로를 만듭니다. (replays/<유닉스 시간>.oxr)
+pub fn new_replay_path() -> PathBuf {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    Path::new(REPLAY_DIR).join(format!("{secs}.{REPLAY_EXTENSION}"))
+}
+
+/// 가장 최근에 저장된 리플레이 파일 경로를 찾습니다.
+pub fn latest_replay_path() -> Option<PathBuf> {
+    fs::read_dir(REPLAY_DIR)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == REPLAY_EXTENSION))
+        .max()
+}
+
+// =============================================================================
+// 리소스
+// =============================================================================
+
+/// 현재 판의 틱별 입력을 녹화하는 리소스입니다.
+#[derive(Resource, Default)]
+pub struct ReplayRecorder {
+    pub inputs: Vec<u8>,
+    /// 판을 시작할 때의 [`GameConfig::fingerprint`]
+    pub config_hash: u64,
+}
+
+/// 마지막으로 끝난 판의 리플레이를 저장하는 리소스입니다.
+///
+/// 게임 오버 화면의 "리플레이 저장" 버튼이 이 값을 파일로 저장합니다.
+#[derive(Resource, Default)]
+pub struct LastReplay(pub Option<Replay>);
+
+/// 재생 중인 리플레이와 다음에 읽을 틱 위치를 저장하는 리소스입니다.
+///
+/// 리플레이를 시작할 때 추가되고, 게임 오버 화면을 떠날 때 제거됩니다.
+#[derive(Resource)]
+pub struct ReplayPlayback {
+    pub replay: Replay,
+    pub cursor: usize,
+}
+
+impl ReplayPlayback {
+    /// 재생이 끝난 뒤 점수가 녹화된 점수와 일치하는지 확인합니다.
+    pub fn score_matches(&self, score: u32) -> bool {
+        self.replay.final_score == score
+    }
+}
+
+// =============================================================================
+// 리플레이 플러그인
+// =============================================================================
+
+/// 입력 녹화와 리플레이 재생을 담당하는 플러그인입니다.
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ReplayRecorder>()
+            .init_resource::<LastReplay>()
+            // 판 시작 시 녹화 초기화
+            .add_systems(OnEnter(AppState::InGame), reset_recorder)
+            // 틱 입력 확정: 직접 플레이하면 녹화, 리플레이면 재생
+            .add_systems(
+                FixedUpdate,
+                (
+                    record_tick_input.run_if(in_state(AppState::InGame)),
+                    playback_tick_input.run_if(in_state(AppState::Replay)),
+                )
+                    .in_set(GameplaySet::TickInput),
+            )
+            // 게임 오버: 녹화 마무리 또는 재생 결과 확인
+            .add_systems(
+                OnEnter(AppState::GameOver),
+                (
+                    finish_recording.run_if(not(resource_exists::<ReplayPlayback>)),
+                    verify_replay_score.run_if(resource_exists::<ReplayPlayback>),
+                ),
+            )
+            .add_systems(OnExit(AppState::GameOver), end_playback)
+            // 메인 메뉴에서 F2: 마지막 리플레이 재생
+            .add_systems(
+                Update,
+                start_latest_replay.run_if(in_state(AppState::MainMenu)),
+            )
+            // 리플레이 중 ESC: 메인 메뉴로
+            .add_systems(Update, exit_replay.run_if(in_state(AppState::Replay)));
+    }
+}
+
+// =============================================================================
+// 시스템 (Systems)
+// =============================================================================
+
+/// 녹화 버퍼를 비우고 판을 시작할 때의 설정 지문을 기록하는 시스템입니다.
+fn reset_recorder(mut recorder: ResMut<ReplayRecorder>, config: Res<GameConfig>) {
+    recorder.inputs.clear();
+    recorder.config_hash = config.fingerprint();
+}
+
+/// 이번 틱에 적용될 플레이어 입력을 녹화하는 시스템입니다.
+fn record_tick_input(input: Res<PlayerInput>, mut recorder: ResMut<ReplayRecorder>) {
+    recorder.inputs.push(encode_input(&input));
+}
+
+/// 녹화된 입력을 이번 틱의 플레이어 입력으로 넣는 시스템입니다.
+///
+/// 입력이 모두 소진되면 게임 오버로 전환합니다.
+fn playback_tick_input(
+    mut playback: ResMut<ReplayPlayback>,
+    mut input: ResMut<PlayerInput>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    let Some(&bits) = playback.replay.inputs.get(playback.cursor) else {
+        next_state.set(AppState::GameOver);
+        return;
+    };
+
+    playback.cursor += 1;
+    *input = decode_input(bits);
+}
+
+/// 끝난 판의 녹화 내용을 [`LastReplay`]로 옮기는 시스템입니다.
+///
+/// 판 도중에 설정이 다시 로드되었으면 리플레이가 재현되지 않으므로 경고합니다.
+fn finish_recording(
+    mut recorder: ResMut<ReplayRecorder>,
+    mut last_replay: ResMut<LastReplay>,
+    game_rng: Res<GameRng>,
+    fixed_time: Res<Time<Fixed>>,
+    config: Res<GameConfig>,
+    score: Res<Score>,
+) {
+    if config.fingerprint() != recorder.config_hash {
+        warn!("Game config changed during the run; the replay will not reproduce it");
+    }
+
+    last_replay.0 = Some(Replay {
+        seed: game_rng.seed(),
+        timestep_nanos: fixed_time.timestep().as_nanos() as u64,
+        config_hash: recorder.config_hash,
+        final_score: score.0,
+        inputs: std::mem::take(&mut recorder.inputs),
+    });
+}
+
+/// 재생이 끝난 뒤 최종 점수가 녹화된 점수와 같은지 확인하는 시스템입니다.
+fn verify_replay_score(playback: Res<ReplayPlayback>, score: Res<Score>) {
+    if playback.score_matches(score.0) {
+        info!("Replay verified: score {}", score.0);
+    } else {
+        warn!(
+            "Replay desync: recorded score {}, replayed score {}",
+            playback.replay.final_score, score.0
+        );
+    }
+}
+
+/// 리플레이 재생 상태를 정리하는 시스템입니다.
+fn end_playback(mut commands: Commands) {
+    commands.remove_resource::<ReplayPlayback>();
+}
+
+/// 메인 메뉴에서 F2를 누르면 가장 최근 리플레이를 재생하는 시스템입니다.
+fn start_latest_replay(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    fixed_time: Res<Time<Fixed>>,
+    config: Res<GameConfig>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if !keyboard.just_pressed(KeyCode::F2) {
+        return;
+    }
+
+    let result = latest_replay_path()
+        .ok_or(ReplayError::NotFound)
+        .and_then(|path| Replay::load(&path))
+        .and_then(|replay| {
+            let current = fixed_time.timestep().as_nanos() as u64;
+            if replay.timestep_nanos == current {
+                Ok(replay)
+            } else {
+                Err(ReplayError::TimestepMismatch {
+                    recorded: replay.timestep_nanos,
+                    current,
+                })
+            }
+        })
+        .and_then(|replay| {
+            let current = config.fingerprint();
+            if replay.config_hash == current {
+                Ok(replay)
+            } else {
+                Err(ReplayError::ConfigMismatch {
+                    recorded: replay.config_hash,
+                    current,
+                })
+            }
+        });
+
+    match result {
+        Ok(replay) => {
+            commands.insert_resource(ReplayPlayback { replay, cursor: 0 });
+            next_state.set(AppState::Replay);
+        }
+        Err(err) => warn!("Cannot start replay: {err}"),
+    }
+}
+
+/// 리플레이 중 ESC를 누르면 메인 메뉴로 돌아가는 시스템입니다.
+fn exit_replay(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if keyboard.just_pressed(KeyCode::Escape) {
+        commands.remove_resource::<ReplayPlayback>();
+        next_state.set(AppState::MainMenu);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{play_scripted, stepped_app};
+
+    fn sample() -> Replay {
+        let mut inputs = vec![0; 3];
+        inputs.extend([INPUT_LEFT | INPUT_FIRE, INPUT_RIGHT | INPUT_FIRE_HELD]);
+        // u16 하나에 담기지 않는 긴 구간
+        inputs.extend(std::iter::repeat_n(INPUT_UP | INPUT_SWITCH_WEAPON, 70_000));
+        inputs.push(INPUT_DOWN);
+        Replay {
+            seed: 0x0123_4567_89ab_cdef,
+            timestep_nanos: 16_666_667,
+            config_hash: 0xfeed_beef,
+            final_score: 12_345,
+            inputs,
+        }
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let replay = sample();
+        assert_eq!(Replay::from_bytes(&replay.to_bytes()).unwrap(), replay);
+
+        let empty = Replay {
+            inputs: Vec::new(),
+            ..sample()
+        };
+        assert_eq!(Replay::from_bytes(&empty.to_bytes()).unwrap(), empty);
+    }
+
+    #[test]
+    fn input_bits_round_trip() {
+        for bits in 0..(INPUT_SWITCH_WEAPON << 1) {
+            // 반대 방향을 함께 누른 입력은 0으로 상쇄되므로 제외
+            if bits & (INPUT_LEFT | INPUT_RIGHT) == INPUT_LEFT | INPUT_RIGHT
+                || bits & (INPUT_UP | INPUT_DOWN) == INPUT_UP | INPUT_DOWN
+            {
+                continue;
+            }
+            assert_eq!(encode_input(&decode_input(bits)), bits);
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_magic_and_version() {
+        let mut bytes = sample().to_bytes();
+        bytes[0] = b'X';
+        assert!(matches!(
+            Replay::from_bytes(&bytes),
+            Err(ReplayError::BadMagic)
+        ));
+
+        for version in [REPLAY_VERSION - 1, REPLAY_VERSION + 1] {
+            let mut bytes = sample().to_bytes();
+            bytes[4..6].copy_from_slice(&version.to_le_bytes());
+            assert!(matches!(
+                Replay::from_bytes(&bytes),
+                Err(ReplayError::UnsupportedVersion(v)) if v == version
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_truncated_or_padded_files() {
+        let bytes = sample().to_bytes();
+        for len in 0..bytes.len() {
+            assert!(
+                matches!(
+                    Replay::from_bytes(&bytes[..len]),
+                    Err(ReplayError::Truncated)
+                ),
+                "length {len}"
+            );
+        }
+
+        let mut padded = bytes.clone();
+        padded.push(0);
+        assert!(matches!(
+            Replay::from_bytes(&padded),
+            Err(ReplayError::Truncated)
+        ));
+
+        // 쌍의 개수만 크게 부풀린 파일
+        let mut inflated = bytes;
+        inflated[34..38].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            Replay::from_bytes(&inflated),
+            Err(ReplayError::Truncated)
+        ));
+    }
+
+    #[test]
+    fn rejects_too_many_ticks() {
+        let runs = MAX_REPLAY_TICKS / u16::MAX as usize + 1;
+        let mut bytes = Replay {
+            inputs: Vec::new(),
+            ..sample()
+        }
+        .to_bytes();
+        bytes[34..38].copy_from_slice(&(runs as u32).to_le_bytes());
+        for _ in 0..runs {
+            bytes.push(INPUT_FIRE);
+            bytes.extend_from_slice(&u16::MAX.to_le_bytes());
+        }
+        assert!(matches!(
+            Replay::from_bytes(&bytes),
+            Err(ReplayError::TooLong)
+        ));
+    }
+
+    /// 게임 오버 화면으로 넘어갑니다. (플레이어가 이미 죽었으면 그대로)
+    fn end_run(app: &mut App) {
+        if *app.world().resource::<State<AppState>>() != AppState::GameOver {
+            app.world_mut()
+                .resource_mut::<NextState<AppState>>()
+                .set(AppState::GameOver);
+            app.update();
+        }
+    }
+
+    #[test]
+    fn recorded_run_replays_to_the_same_score() {
+        // 녹화
+        let mut app = stepped_app(42);
+        app.world_mut()
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::InGame);
+        play_scripted(&mut app, 1200);
+        end_run(&mut app);
+        let recorded = app.world().resource::<Score>().0;
+        let replay = app.world().resource::<LastReplay>().0.clone().unwrap();
+        assert!(recorded > 0, "no enemy destroyed");
+        assert_eq!(replay.final_score, recorded);
+
+        // 저장 후 다시 읽기
+        let path = std::env::temp_dir().join(format!(
+            "oxide-rain-replay-test-{}.{REPLAY_EXTENSION}",
+            std::process::id()
+        ));
+        replay.save(&path).unwrap();
+        let loaded = Replay::load(&path);
+        fs::remove_file(&path).unwrap();
+        let loaded = loaded.unwrap();
+        assert_eq!(loaded, replay);
+
+        // 키보드 없이 재생
+        let mut app = stepped_app(0);
+        app.insert_resource(ReplayPlayback {
+            replay: loaded,
+            cursor: 0,
+        });
+        app.world_mut()
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::Replay);
+        for _ in 0..replay.inputs.len() + 10 {
+            app.update();
+            if *app.world().resource::<State<AppState>>() == AppState::GameOver {
+                break;
+            }
+        }
+        assert_eq!(
+            *app.world().resource::<State<AppState>>(),
+            AppState::GameOver
+        );
+        assert_eq!(app.world().resource::<Score>().0, replay.final_score);
+    }
+}
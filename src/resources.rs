@@ -4,6 +4,9 @@
 //! 타이머, 점수, 게임 상태, 플레이어 이름 등을 관리합니다.
 
 use bevy::prelude::*;
+use rand::{rngs::Xoshiro256PlusPlus, SeedableRng};
+
+use crate::components::EnemyKind;
 
 // =============================================================================
 // 게임 상태 (App State)
@@ -21,30 +24,92 @@ pub enum AppState {
 
     /// 게임 오버 상태
     GameOver,
+
+    /// 리플레이 재생 상태 (녹화된 입력으로 게임플레이 진행)
+    Replay,
+
+    /// 리더보드 화면 상태
+    Leaderboard,
+}
+
+/// 게임플레이가 진행 중인지를 나타내는 계산된 상태(ComputedStates)입니다.
+///
+/// `AppState::InGame`과 `AppState::Replay`에서 존재합니다.
+/// 플레이어/적/투사체의 스폰, 정리, 고정 틱 시스템은 이 상태를 기준으로 동작하므로
+/// 직접 플레이할 때와 리플레이할 때 같은 로직이 실행됩니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Playing;
+
+impl ComputedStates for Playing {
+    type SourceStates = AppState;
+
+    fn compute(sources: AppState) -> Option<Self> {
+        match sources {
+            AppState::InGame | AppState::Replay => Some(Playing),
+            _ => None,
+        }
+    }
+}
+
+/// 일시정지 여부를 나타내는 하위 상태(SubStates)입니다.
+///
+/// `AppState::InGame`에서만 존재하므로 일시정지해도 `AppState`와 [`Playing`]은 바뀌지 않고,
+/// `OnExit(Playing)` 정리 시스템도 실행되지 않습니다.
+#[derive(SubStates, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[source(AppState = AppState::InGame)]
+pub enum PauseState {
+    /// 게임 진행 중 (기본값)
+    #[default]
+    Running,
+
+    /// 일시정지 중 (게임플레이와 가상 시간이 멈춤)
+    Paused,
 }
 
 // =============================================================================
 // 게임 상수 (Game Constants)
 // =============================================================================
+// 밸런스 수치는 GameConfig의 기본값으로 쓰입니다.
+// 실제 게임에서는 assets/config/game.ron 설정 파일의 값이 우선합니다.
 
 /// 배경색 (아주 어두운 네이비 블루)
 pub const BACKGROUND_COLOR: Color = Color::srgb(0.02, 0.02, 0.08);
 
+// 시뮬레이션 설정 (초당 고정 틱 수)
+pub const FIXED_TICK_RATE: f64 = 60.0;
+
+// 플레이 영역 설정 (창 크기와 동일)
+pub const PLAYFIELD_WIDTH: f32 = 800.0;
+pub const PLAYFIELD_HEIGHT: f32 = 600.0;
+
 // 플레이어 설정
 pub const PLAYER_SPEED: f32 = 300.0;
 pub const PLAYER_COLLISION_RADIUS: f32 = 20.0;
 pub const PLAYER_SCALE: f32 = 0.5;
+pub const PLAYER_MAX_HEALTH: u32 = 3;
+pub const PLAYER_LIVES: u32 = 3;
+pub const PLAYER_RESPAWN_DELAY: f32 = 1.0;
+pub const PLAYER_INVULNERABILITY_DURATION: f32 = 2.0;
 
 // 투사체 설정
-pub const PROJECTILE_SPEED: f32 = 500.0;
 pub const PROJECTILE_COLLISION_RADIUS: f32 = 8.0;
 pub const PROJECTILE_SCALE: f32 = 0.4;
 
-// 적 설정
+// 적 설정 (ENEMY_SPEED ~ ENEMY_MAX_HEALTH, SCORE_PER_ENEMY는 기본 드론의 값)
+// 다른 적 종류의 기본값은 config.rs의 EnemyTable에 있습니다.
 pub const ENEMY_SPEED: f32 = 150.0;
 pub const ENEMY_COLLISION_RADIUS: f32 = 18.0;
 pub const ENEMY_SCALE: f32 = 0.5;
 pub const ENEMY_SPAWN_INTERVAL: f32 = 1.0;
+pub const ENEMY_MAX_HEALTH: u32 = 1;
+pub const ENEMY_HIT_FLASH_DURATION: f32 = 0.08;
+pub const ENEMY_CONTACT_DAMAGE: u32 = 1;
+
+// 적 투사체 설정
+pub const ENEMY_PROJECTILE_SPEED: f32 = 250.0;
+pub const ENEMY_PROJECTILE_COLLISION_RADIUS: f32 = 6.0;
+pub const ENEMY_PROJECTILE_SCALE: f32 = 0.35;
+pub const ENEMY_PROJECTILE_DAMAGE: u32 = 1;
 
 // 점수 설정
 pub const SCORE_PER_ENEMY: u32 = 100;
@@ -56,6 +121,121 @@ pub const MAX_NAME_LENGTH: usize = 12;
 // 리소스 정의
 // =============================================================================
 
+/// 논리적인 플레이 영역 크기를 저장하는 리소스입니다.
+///
+/// 원점(0, 0)이 중앙인 좌표계에서 게임이 진행되는 영역입니다.
+/// 스폰 위치, 화면 밖 판정, 플레이어 이동 제한은 창 대신 이 값을 기준으로 합니다.
+/// 따라서 창이 없는 헤드리스 실행에서도 동일하게 동작합니다.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct Playfield {
+    pub size: Vec2,
+}
+
+impl Playfield {
+    /// 중앙에서 가장자리까지의 거리 (너비/2, 높이/2)
+    pub fn half_extents(&self) -> Vec2 {
+        self.size / 2.0
+    }
+}
+
+impl Default for Playfield {
+    fn default() -> Self {
+        Self {
+            size: Vec2::new(PLAYFIELD_WIDTH, PLAYFIELD_HEIGHT),
+        }
+    }
+}
+
+/// 게임플레이 스프라이트 이미지 핸들을 저장하는 리소스입니다.
+///
+/// AssetServer가 있으면 시작 시 로드하고, 헤드리스 실행에서는 기본 핸들을 사용합니다.
+#[derive(Resource, Default)]
+pub struct SpriteAssets {
+    pub player: Handle<Image>,
+    pub enemy: Handle<Image>,
+    pub enemy_weaver: Handle<Image>,
+    pub enemy_diver: Handle<Image>,
+    pub enemy_kamikaze: Handle<Image>,
+    pub enemy_bruiser: Handle<Image>,
+    pub boss: Handle<Image>,
+    pub bullet: Handle<Image>,
+}
+
+impl SpriteAssets {
+    /// 적 종류에 맞는 스프라이트 핸들을 반환합니다.
+    pub fn enemy_sprite(&self, kind: EnemyKind) -> Handle<Image> {
+        match kind {
+            EnemyKind::Drone => self.enemy.clone(),
+            EnemyKind::Weaver => self.enemy_weaver.clone(),
+            EnemyKind::Diver => self.enemy_diver.clone(),
+            EnemyKind::Kamikaze => self.enemy_kamikaze.clone(),
+            EnemyKind::Bruiser => self.enemy_bruiser.clone(),
+        }
+    }
+}
+
+/// 게임플레이에서 사용하는 시드 기반 난수 생성기 리소스입니다.
+///
+/// 모든 게임플레이 난수는 이 리소스에서 뽑아야 합니다.
+/// 같은 시드와 같은 입력이면 항상 같은 판이 재현됩니다.
+/// 알고리즘(Xoshiro256++)과 시드 확장이 고정되어 있어 플랫폼이 바뀌어도 같은 난수 열이 나옵니다.
+/// 다만 `random_range` 같은 분포 변환은 rand 버전마다 값이 달라질 수 있으므로,
+/// rand를 올리면 이전 버전에서 녹화한 리플레이는 재현되지 않을 수 있습니다.
+#[derive(Resource)]
+pub struct GameRng {
+    seed: u64,
+    rng: Xoshiro256PlusPlus,
+}
+
+impl GameRng {
+    /// 주어진 시드로 난수 생성기를 만듭니다.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: Xoshiro256PlusPlus::seed_from_u64(seed),
+        }
+    }
+
+    /// 현재 판의 시드를 반환합니다.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// 내부 난수 생성기를 반환합니다. `rand::RngExt`의 메서드를 사용할 수 있습니다.
+    pub fn rng(&mut self) -> &mut Xoshiro256PlusPlus {
+        &mut self.rng
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+/// 판 시작 시 사용할 고정 시드를 저장하는 리소스입니다.
+///
+/// `None`이면 판마다 새로운 무작위 시드를 사용합니다.
+/// 리플레이, 회귀 테스트, 시드 챌린지에서는 `Some(seed)`로 고정합니다.
+#[derive(Resource, Default)]
+pub struct FixedSeed(pub Option<u64>);
+
+/// 한 고정 틱 동안 적용할 플레이어 입력을 저장하는 리소스입니다.
+///
+/// 키보드 상태는 프레임마다 갱신되지만 게임플레이는 고정 틱으로 진행되므로,
+/// 고정 틱 루프 직전에 입력을 이 리소스에 모아 둡니다.
+/// - `movement`: 이동 방향 (각 축 -1.0 ~ 1.0)
+/// - `fire`: 발사 요청. 발사 시스템이 소비하면 `false`로 돌아갑니다.
+/// - `fire_held`: 발사 키를 누르고 있는지 여부 (누르고 있으면 계속 발사)
+/// - `switch_weapon`: 무기 교체 요청. 무기 시스템이 소비하면 `false`로 돌아갑니다.
+#[derive(Resource, Default)]
+pub struct PlayerInput {
+    pub movement: Vec2,
+    pub fire: bool,
+    pub fire_held: bool,
+    pub switch_weapon: bool,
+}
+
 /// 적 스폰 타이머 리소스입니다.
 #[derive(Resource)]
 pub struct EnemySpawnTimer(pub Timer);
@@ -69,11 +249,56 @@ impl Default for EnemySpawnTimer {
     }
 }
 
+/// 현재 난이도를 저장하는 리소스입니다.
+///
+/// 스테이지가 없는 동안 흐른 시간에 따라 설정의 난이도 곡선에서 값을 읽어 갱신하고,
+/// 숨은 랭크(`rank.rs`)의 효과를 곱합니다.
+/// 배율은 모두 1.0이 기본이며, 판을 시작할 때 기본값으로 돌아갑니다.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct Difficulty {
+    /// 난이도 곡선이 진행된 시간 (초)
+    pub elapsed: f32,
+    /// 현재 레벨 (1부터)
+    pub level: u32,
+    /// 적 스폰 빈도 배율 (2.0이면 스폰 간격이 절반)
+    pub spawn_rate: f32,
+    /// 적 이동 속도 배율
+    pub enemy_speed: f32,
+    /// 적 사격 빈도 배율 (2.0이면 사격 간격이 절반)
+    pub fire_rate: f32,
+    /// 적 체력 배율
+    pub enemy_health: f32,
+    /// 적 투사체 속도 배율
+    pub bullet_speed: f32,
+}
+
+impl Default for Difficulty {
+    fn default() -> Self {
+        Self {
+            elapsed: 0.0,
+            level: 1,
+            spawn_rate: 1.0,
+            enemy_speed: 1.0,
+            fire_rate: 1.0,
+            enemy_health: 1.0,
+            bullet_speed: 1.0,
+        }
+    }
+}
+
 /// 현재 게임 점수를 저장하는 리소스입니다.
 #[derive(Resource, Default)]
 pub struct Score(pub u32);
 
-/// 세션 최고 기록을 저장하는 리소스입니다.
+/// 현재 판의 플레이 시간을 저장하는 리소스입니다.
+///
+/// 고정 틱마다 틱 간격만큼 늘어나므로 프레임 속도와 무관합니다.
+#[derive(Resource, Default)]
+pub struct RunDuration(pub std::time::Duration);
+
+/// 최고 기록을 저장하는 리소스입니다.
+///
+/// 리더보드를 사용하면 시작 시 저장된 최고 점수로 초기화됩니다.
 #[derive(Resource, Default)]
 pub struct HighScore(pub u32);
 
@@ -85,11 +310,5 @@ pub struct IsNewRecord(pub bool);
 ///
 /// 메인 메뉴에서 키보드 입력으로 설정합니다.
 /// 게임 오버 화면에서 이름과 함께 결과를 표시합니다.
-#[derive(Resource)]
+#[derive(Resource, Default)]
 pub struct PlayerName(pub String);
-
-impl Default for PlayerName {
-    fn default() -> Self {
-        Self(String::new())
-    }
-}
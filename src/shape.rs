@@ -0,0 +1,803 @@
+//! 충돌 도형 모듈
+//!
+//! [`Collider`]의 도형을 월드 좌표의 [`Shape`]로 바꾸고, 도형끼리의 겹침 검사와 선분 질의를 구현합니다.
+//! 모든 도형은 두 가지 중 하나로 나타냅니다.
+//! - 둥근 도형 ([`Shape::Round`]): 선분에서 반경 이내의 영역 (원은 길이가 0인 선분, 캡슐은 길이가 있는 선분)
+//! - 상자 ([`Shape::Box`]): 회전할 수 있는 직사각형 (AABB는 회전하지 않은 상자)
+//!
+//! 겹침 검사는 둥근 도형끼리는 선분 사이 거리, 상자가 끼면 상자 좌표계에서의 거리나 분리축으로 판정합니다.
+//! 움직이는 원의 연속 충돌 검사([`Shape::sweep_circle`])는 도형을 원의 반경만큼 부풀려 선분 질의로 바꿉니다.
+
+use std::cmp::Ordering;
+
+use bevy::prelude::*;
+
+use crate::components::{Collider, ColliderShape};
+
+// =============================================================================
+// 월드 좌표 도형
+// =============================================================================
+
+/// 월드 좌표의 충돌 도형입니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shape {
+    /// 선분 `start`→`end`에서 `radius` 이내의 영역
+    Round { start: Vec2, end: Vec2, radius: f32 },
+    /// 중심이 `center`이고 로컬 X축이 `axis`(단위 벡터)인 직사각형
+    Box {
+        center: Vec2,
+        axis: Vec2,
+        half_extents: Vec2,
+    },
+}
+
+impl Shape {
+    /// 원을 만듭니다.
+    pub fn circle(center: Vec2, radius: f32) -> Self {
+        Self::Round {
+            start: center,
+            end: center,
+            radius,
+        }
+    }
+
+    /// 충돌체의 도형을 엔티티의 위치와 회전에 맞춰 월드 좌표로 바꿉니다.
+    pub fn from_collider(collider: &Collider, transform: &Transform) -> Self {
+        let center = transform.translation.truncate();
+        let axis = (transform.rotation * Vec3::X)
+            .truncate()
+            .normalize_or(Vec2::X);
+        match collider.shape {
+            ColliderShape::Circle { radius } => Self::circle(center, radius),
+            ColliderShape::Aabb { half_extents } => Self::Box {
+                center,
+                axis: Vec2::X,
+                half_extents,
+            },
+            ColliderShape::Obb { half_extents } => Self::Box {
+                center,
+                axis,
+                half_extents,
+            },
+            ColliderShape::Capsule {
+                half_length,
+                radius,
+            } => {
+                let up = axis.perp() * half_length;
+                Self::Round {
+                    start: center - up,
+                    end: center + up,
+                    radius,
+                }
+            }
+        }
+    }
+
+    /// 도형을 감싸는 원의 `(중심, 반경)`을 반환합니다.
+    pub fn bounding_circle(&self) -> (Vec2, f32) {
+        match *self {
+            Self::Round { start, end, radius } => {
+                ((start + end) / 2.0, start.distance(end) / 2.0 + radius)
+            }
+            Self::Box {
+                center,
+                half_extents,
+                ..
+            } => (center, half_extents.length()),
+        }
+    }
+
+    /// 엔티티 번호와 무관하게 도형을 늘어놓는 순서입니다.
+    ///
+    /// 감싸는 원의 중심 x, 중심 y, 반경 순서로 비교합니다.
+    pub fn placement_cmp(&self, other: &Shape) -> Ordering {
+        let (center, radius) = self.bounding_circle();
+        let (other_center, other_radius) = other.bounding_circle();
+        center
+            .x
+            .total_cmp(&other_center.x)
+            .then(center.y.total_cmp(&other_center.y))
+            .then(radius.total_cmp(&other_radius))
+    }
+
+    /// 두 도형이 겹치는지 반환합니다. 경계가 맞닿기만 한 것은 겹치지 않은 것으로 봅니다.
+    pub fn overlaps(&self, other: &Shape) -> bool {
+        match (*self, *other) {
+            (
+                Self::Round { start, end, radius },
+                Self::Round {
+                    start: other_start,
+                    end: other_end,
+                    radius: other_radius,
+                },
+            ) => {
+                segment_segment_distance(start, end, other_start, other_end) < radius + other_radius
+            }
+            (
+                Self::Round { start, end, radius },
+                Self::Box {
+                    center,
+                    axis,
+                    half_extents,
+                },
+            )
+            | (
+                Self::Box {
+                    center,
+                    axis,
+                    half_extents,
+                },
+                Self::Round { start, end, radius },
+            ) => {
+                let to_local = |point: Vec2| local_point(point, center, axis);
+                segment_aabb_distance(to_local(start), to_local(end), half_extents) < radius
+            }
+            (
+                Self::Box {
+                    center,
+                    axis,
+                    half_extents,
+                },
+                Self::Box {
+                    center: other_center,
+                    axis: other_axis,
+                    half_extents: other_half_extents,
+                },
+            ) => {
+                // 분리축 정리: 네 축 중 하나라도 투영이 떨어져 있으면 겹치지 않음
+                let offset = other_center - center;
+                let projected_radius = |axis: Vec2, half_extents: Vec2, onto: Vec2| {
+                    half_extents.x * axis.dot(onto).abs()
+                        + half_extents.y * axis.perp().dot(onto).abs()
+                };
+                [axis, axis.perp(), other_axis, other_axis.perp()]
+                    .into_iter()
+                    .all(|onto| {
+                        offset.dot(onto).abs()
+                            < projected_radius(axis, half_extents, onto)
+                                + projected_radius(other_axis, other_half_extents, onto)
+                    })
+            }
+        }
+    }
+
+    /// 선분 `start`→`end`가 도형에 처음 닿는 지점까지의 거리를 반환합니다.
+    ///
+    /// 시작점이 도형 안에 있으면 0을 반환하고, 닿지 않으면 `None`을 반환합니다.
+    pub fn segment_distance(&self, start: Vec2, end: Vec2) -> Option<f32> {
+        match *self {
+            Self::Round {
+                start: round_start,
+                end: round_end,
+                radius,
+            } => {
+                let caps = [round_start, round_end]
+                    .into_iter()
+                    .filter_map(|center| segment_circle(start, end, center, radius));
+                // 캡슐의 몸통은 양 끝 원 사이의 직사각형
+                let body = (round_start != round_end)
+                    .then(|| Self::Box {
+                        center: (round_start + round_end) / 2.0,
+                        axis: (round_end - round_start).normalize(),
+                        half_extents: Vec2::new(round_start.distance(round_end) / 2.0, radius),
+                    })
+                    .and_then(|body| body.segment_distance(start, end));
+                caps.chain(body).min_by(f32::total_cmp)
+            }
+            Self::Box {
+                center,
+                axis,
+                half_extents,
+            } => {
+                let local_start = local_point(start, center, axis);
+                let local_end = local_point(end, center, axis);
+                segment_aabb_entry(local_start, local_end, half_extents)
+                    .map(|t| t * start.distance(end))
+            }
+        }
+    }
+
+    /// 반경 `radius`인 원이 `start`→`end`로 움직일 때 도형에 처음 닿기까지 움직인 거리를 반환합니다.
+    ///
+    /// 도형을 원의 반경만큼 부풀린 영역에 원의 중심이 지나는 선분이 처음 닿는 곳을 찾습니다.
+    /// 시작할 때 이미 닿아 있으면 0을 반환하고, 끝날 때까지 닿지 않으면 `None`을 반환합니다.
+    pub fn sweep_circle(&self, start: Vec2, end: Vec2, radius: f32) -> Option<f32> {
+        match *self {
+            Self::Round {
+                start: round_start,
+                end: round_end,
+                radius: round_radius,
+            } => Self::Round {
+                start: round_start,
+                end: round_end,
+                radius: round_radius + radius,
+            }
+            .segment_distance(start, end),
+            Self::Box {
+                center,
+                axis,
+                half_extents,
+            } => {
+                // 부풀린 상자 = 가로로 늘린 상자 + 세로로 늘린 상자 + 네 꼭짓점의 원
+                let stretched =
+                    [Vec2::new(radius, 0.0), Vec2::new(0.0, radius)].map(|extra| Self::Box {
+                        center,
+                        axis,
+                        half_extents: half_extents + extra,
+                    });
+                let corners = [
+                    Vec2::ONE,
+                    Vec2::new(-1.0, 1.0),
+                    Vec2::NEG_ONE,
+                    Vec2::new(1.0, -1.0),
+                ]
+                .map(|sign| {
+                    let local = half_extents * sign;
+                    center + axis * local.x + axis.perp() * local.y
+                });
+                stretched
+                    .into_iter()
+                    .filter_map(|body| body.segment_distance(start, end))
+                    .chain(
+                        corners
+                            .into_iter()
+                            .filter_map(|corner| segment_circle(start, end, corner, radius)),
+                    )
+                    .min_by(f32::total_cmp)
+            }
+        }
+    }
+}
+
+// =============================================================================
+// 기본 도형 함수
+// =============================================================================
+
+/// 선분 `start`→`end`가 원에 처음 닿는 지점까지의 거리를 반환합니다.
+///
+/// 시작점이 원 안에 있으면 0을 반환하고, 닿지 않으면 `None`을 반환합니다.
+pub fn segment_circle(start: Vec2, end: Vec2, center: Vec2, radius: f32) -> Option<f32> {
+    let segment = end - start;
+    let length = segment.length();
+    let offset = start - center;
+    let c = offset.length_squared() - radius * radius;
+    if c <= 0.0 {
+        return Some(0.0);
+    }
+    if length <= f32::EPSILON {
+        return None;
+    }
+
+    // |offset + direction * t| = radius 인 가장 작은 t
+    let direction = segment / length;
+    let b = offset.dot(direction);
+    if b > 0.0 {
+        // 원에서 멀어지는 방향
+        return None;
+    }
+    let discriminant = b * b - c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let distance = -b - discriminant.sqrt();
+    (distance <= length).then_some(distance)
+}
+
+/// 점을 중심이 `center`이고 X축이 `axis`인 좌표계로 옮깁니다.
+fn local_point(point: Vec2, center: Vec2, axis: Vec2) -> Vec2 {
+    let offset = point - center;
+    Vec2::new(offset.dot(axis), offset.dot(axis.perp()))
+}
+
+/// 점에서 선분까지의 거리를 반환합니다.
+fn point_segment_distance(point: Vec2, start: Vec2, end: Vec2) -> f32 {
+    let segment = end - start;
+    let length_squared = segment.length_squared();
+    if length_squared <= f32::EPSILON {
+        return point.distance(start);
+    }
+    let t = ((point - start).dot(segment) / length_squared).clamp(0.0, 1.0);
+    point.distance(start + segment * t)
+}
+
+/// 두 선분 사이의 가장 가까운 거리를 반환합니다. 길이가 0인 선분(점)도 됩니다.
+fn segment_segment_distance(start: Vec2, end: Vec2, other_start: Vec2, other_end: Vec2) -> f32 {
+    let d1 = end - start;
+    let d2 = other_end - other_start;
+    let r = start - other_start;
+    let a = d1.length_squared();
+    let e = d2.length_squared();
+    let f = d2.dot(r);
+
+    if a <= f32::EPSILON && e <= f32::EPSILON {
+        return start.distance(other_start);
+    }
+    // 각 선분 위의 가장 가까운 점의 매개변수 (0..=1)
+    let (s, t) = if a <= f32::EPSILON {
+        (0.0, (f / e).clamp(0.0, 1.0))
+    } else {
+        let c = d1.dot(r);
+        if e <= f32::EPSILON {
+            ((-c / a).clamp(0.0, 1.0), 0.0)
+        } else {
+            let b = d1.dot(d2);
+            let denominator = a * e - b * b;
+            // 평행하면 아무 점에서 시작
+            let s = if denominator > f32::EPSILON {
+                ((b * f - c * e) / denominator).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let t = (b * s + f) / e;
+            if t < 0.0 {
+                ((-c / a).clamp(0.0, 1.0), 0.0)
+            } else if t > 1.0 {
+                (((b - c) / a).clamp(0.0, 1.0), 1.0)
+            } else {
+                (s, t)
+            }
+        }
+    };
+    (start + d1 * s).distance(other_start + d2 * t)
+}
+
+/// 선분 `start`→`end`가 원점 중심의 직사각형에 처음 들어가는 매개변수(0..=1)를 반환합니다.
+///
+/// 시작점이 안에 있으면 0을 반환하고, 닿지 않으면 `None`을 반환합니다.
+fn segment_aabb_entry(start: Vec2, end: Vec2, half_extents: Vec2) -> Option<f32> {
+    let delta = end - start;
+    let mut enter: f32 = 0.0;
+    let mut exit: f32 = 1.0;
+    for axis in 0..2 {
+        if delta[axis].abs() <= f32::EPSILON {
+            if start[axis].abs() > half_extents[axis] {
+                return None;
+            }
+            continue;
+        }
+        let near = (-half_extents[axis] - start[axis]) / delta[axis];
+        let far = (half_extents[axis] - start[axis]) / delta[axis];
+        enter = enter.max(near.min(far));
+        exit = exit.min(near.max(far));
+        if enter > exit {
+            return None;
+        }
+    }
+    Some(enter)
+}
+
+/// 선분과 원점 중심 직사각형 사이의 가장 가까운 거리를 반환합니다. 닿아 있으면 0입니다.
+fn segment_aabb_distance(start: Vec2, end: Vec2, half_extents: Vec2) -> f32 {
+    if segment_aabb_entry(start, end, half_extents).is_some() {
+        return 0.0;
+    }
+    // 떨어져 있으면 가장 가까운 점 중 하나는 선분 끝점이나 직사각형 꼭짓점
+    let point_distance = |point: Vec2| (point.abs() - half_extents).max(Vec2::ZERO).length();
+    let corners = [
+        half_extents,
+        Vec2::new(-half_extents.x, half_extents.y),
+        -half_extents,
+        Vec2::new(half_extents.x, -half_extents.y),
+    ];
+    corners
+        .into_iter()
+        .map(|corner| point_segment_distance(corner, start, end))
+        .chain([point_distance(start), point_distance(end)])
+        .fold(f32::INFINITY, f32::min)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn circle(x: f32, y: f32, radius: f32) -> Shape {
+        Shape::circle(Vec2::new(x, y), radius)
+    }
+
+    fn aabb(x: f32, y: f32, half_x: f32, half_y: f32) -> Shape {
+        Shape::Box {
+            center: Vec2::new(x, y),
+            axis: Vec2::X,
+            half_extents: Vec2::new(half_x, half_y),
+        }
+    }
+
+    fn obb(x: f32, y: f32, half_x: f32, half_y: f32, axis: Vec2) -> Shape {
+        Shape::Box {
+            center: Vec2::new(x, y),
+            axis,
+            half_extents: Vec2::new(half_x, half_y),
+        }
+    }
+
+    fn capsule(start: (f32, f32), end: (f32, f32), radius: f32) -> Shape {
+        Shape::Round {
+            start: Vec2::new(start.0, start.1),
+            end: Vec2::new(end.0, end.1),
+            radius,
+        }
+    }
+
+    fn diagonal() -> Vec2 {
+        Vec2::from_angle(45f32.to_radians())
+    }
+
+    #[test]
+    fn overlaps_every_shape_pair() {
+        let cases = [
+            // 원 - 원
+            (
+                "circles overlapping",
+                circle(0.0, 0.0, 10.0),
+                circle(15.0, 0.0, 10.0),
+                true,
+            ),
+            (
+                "circles touching",
+                circle(0.0, 0.0, 10.0),
+                circle(20.0, 0.0, 10.0),
+                false,
+            ),
+            (
+                "circles separated",
+                circle(0.0, 0.0, 10.0),
+                circle(25.0, 0.0, 10.0),
+                false,
+            ),
+            // 원 - AABB
+            (
+                "circle into box side",
+                circle(0.0, 0.0, 10.0),
+                aabb(14.0, 0.0, 5.0, 5.0),
+                true,
+            ),
+            (
+                "circle touching box side",
+                circle(0.0, 0.0, 10.0),
+                aabb(15.0, 0.0, 5.0, 5.0),
+                false,
+            ),
+            (
+                "circle off box side",
+                circle(0.0, 0.0, 10.0),
+                aabb(16.0, 0.0, 5.0, 5.0),
+                false,
+            ),
+            (
+                "circle into box corner",
+                circle(0.0, 0.0, 10.0),
+                aabb(12.0, 12.0, 5.0, 5.0),
+                true,
+            ),
+            (
+                "circle off box corner",
+                circle(0.0, 0.0, 10.0),
+                aabb(13.0, 13.0, 5.0, 5.0),
+                false,
+            ),
+            (
+                "circle inside box",
+                circle(0.0, 0.0, 1.0),
+                aabb(0.0, 0.0, 5.0, 5.0),
+                true,
+            ),
+            // 원 - OBB: 90도 돌리면 가로 폭이 10에서 5로 줄어듦
+            (
+                "circle into wide box",
+                circle(0.0, 0.0, 10.0),
+                aabb(15.0, 0.0, 10.0, 5.0),
+                true,
+            ),
+            (
+                "circle touching turned box",
+                circle(0.0, 0.0, 10.0),
+                obb(15.0, 0.0, 10.0, 5.0, Vec2::Y),
+                false,
+            ),
+            // 45도 돌린 정사각형은 꼭짓점이 25 - 10√2 ≈ 10.86까지 나옴
+            (
+                "circle misses square",
+                circle(0.0, 0.0, 12.0),
+                aabb(25.0, 0.0, 10.0, 10.0),
+                false,
+            ),
+            (
+                "circle hits diamond tip",
+                circle(0.0, 0.0, 12.0),
+                obb(25.0, 0.0, 10.0, 10.0, diagonal()),
+                true,
+            ),
+            (
+                "circle misses diamond tip",
+                circle(0.0, 0.0, 10.0),
+                obb(25.0, 0.0, 10.0, 10.0, diagonal()),
+                false,
+            ),
+            // 원 - 캡슐
+            (
+                "circle into capsule body",
+                circle(0.0, 14.0, 10.0),
+                capsule((-20.0, 0.0), (20.0, 0.0), 5.0),
+                true,
+            ),
+            (
+                "circle touching capsule body",
+                circle(0.0, 15.0, 10.0),
+                capsule((-20.0, 0.0), (20.0, 0.0), 5.0),
+                false,
+            ),
+            (
+                "circle into capsule cap",
+                circle(29.0, 0.0, 5.0),
+                capsule((-20.0, 0.0), (20.0, 0.0), 5.0),
+                true,
+            ),
+            (
+                "circle touching capsule cap",
+                circle(30.0, 0.0, 5.0),
+                capsule((-20.0, 0.0), (20.0, 0.0), 5.0),
+                false,
+            ),
+            // 캡슐 - 캡슐
+            (
+                "parallel capsules overlapping",
+                capsule((-10.0, 0.0), (10.0, 0.0), 5.0),
+                capsule((-10.0, 9.0), (10.0, 9.0), 5.0),
+                true,
+            ),
+            (
+                "parallel capsules touching",
+                capsule((-10.0, 0.0), (10.0, 0.0), 5.0),
+                capsule((-10.0, 10.0), (10.0, 10.0), 5.0),
+                false,
+            ),
+            (
+                "crossing capsules",
+                capsule((-10.0, -10.0), (10.0, 10.0), 1.0),
+                capsule((-10.0, 10.0), (10.0, -10.0), 1.0),
+                true,
+            ),
+            (
+                "capsules end to end",
+                capsule((-10.0, 0.0), (0.0, 0.0), 2.0),
+                capsule((5.0, 0.0), (15.0, 0.0), 2.0),
+                false,
+            ),
+            // 캡슐 - 상자
+            (
+                "capsule into box",
+                capsule((0.0, -10.0), (0.0, 10.0), 5.0),
+                aabb(14.0, 0.0, 10.0, 10.0),
+                true,
+            ),
+            (
+                "capsule touching box",
+                capsule((0.0, -10.0), (0.0, 10.0), 5.0),
+                aabb(15.0, 0.0, 10.0, 10.0),
+                false,
+            ),
+            (
+                "capsule through diamond",
+                capsule((-30.0, 0.0), (30.0, 0.0), 1.0),
+                obb(0.0, 0.0, 5.0, 5.0, diagonal()),
+                true,
+            ),
+            // 상자 - 상자
+            (
+                "boxes overlapping",
+                aabb(0.0, 0.0, 10.0, 10.0),
+                aabb(19.0, 0.0, 10.0, 10.0),
+                true,
+            ),
+            (
+                "boxes touching",
+                aabb(0.0, 0.0, 10.0, 10.0),
+                aabb(20.0, 0.0, 10.0, 10.0),
+                false,
+            ),
+            (
+                "boxes separated",
+                aabb(0.0, 0.0, 10.0, 10.0),
+                aabb(0.0, 25.0, 10.0, 10.0),
+                false,
+            ),
+            // 10√2 ≈ 14.14
+            (
+                "box and diamond overlapping",
+                aabb(0.0, 0.0, 10.0, 10.0),
+                obb(24.0, 0.0, 10.0, 10.0, diagonal()),
+                true,
+            ),
+            (
+                "box and diamond separated",
+                aabb(0.0, 0.0, 10.0, 10.0),
+                obb(25.0, 0.0, 10.0, 10.0, diagonal()),
+                false,
+            ),
+            (
+                "diamonds tip to tip overlapping",
+                obb(0.0, 0.0, 10.0, 10.0, diagonal()),
+                obb(28.0, 0.0, 10.0, 10.0, diagonal()),
+                true,
+            ),
+            (
+                "diamonds tip to tip separated",
+                obb(0.0, 0.0, 10.0, 10.0, diagonal()),
+                obb(29.0, 0.0, 10.0, 10.0, diagonal()),
+                false,
+            ),
+            (
+                "turned box touching box",
+                aabb(0.0, 0.0, 10.0, 10.0),
+                obb(15.0, 0.0, 10.0, 5.0, Vec2::Y),
+                false,
+            ),
+            (
+                "turned box into box",
+                aabb(0.0, 0.0, 10.0, 10.0),
+                obb(14.0, 0.0, 10.0, 5.0, Vec2::Y),
+                true,
+            ),
+        ];
+
+        for (name, a, b, expected) in cases {
+            assert_eq!(a.overlaps(&b), expected, "{name}");
+            assert_eq!(b.overlaps(&a), expected, "{name} (swapped)");
+        }
+    }
+
+    #[test]
+    fn zero_length_capsule_behaves_like_a_circle() {
+        let collider = Collider::enemy(ColliderShape::Capsule {
+            half_length: 0.0,
+            radius: 5.0,
+        });
+        let transform =
+            Transform::from_xyz(3.0, 4.0, 0.0).with_rotation(Quat::from_rotation_z(1.0));
+        let degenerate = Shape::from_collider(&collider, &transform);
+        assert_eq!(degenerate, circle(3.0, 4.0, 5.0));
+
+        let point = capsule((0.0, 0.0), (0.0, 0.0), 5.0);
+        let cases = [
+            ("circle overlapping", circle(9.0, 0.0, 5.0), true),
+            ("circle touching", circle(10.0, 0.0, 5.0), false),
+            (
+                "capsule overlapping",
+                capsule((-10.0, 9.0), (10.0, 9.0), 5.0),
+                true,
+            ),
+            (
+                "capsule touching",
+                capsule((-10.0, 10.0), (10.0, 10.0), 5.0),
+                false,
+            ),
+            (
+                "other point overlapping",
+                capsule((0.0, 9.0), (0.0, 9.0), 5.0),
+                true,
+            ),
+            ("box overlapping", aabb(9.0, 0.0, 5.0, 5.0), true),
+            ("box touching", aabb(10.0, 0.0, 5.0, 5.0), false),
+            (
+                "diamond overlapping",
+                obb(12.0, 0.0, 5.0, 5.0, diagonal()),
+                true,
+            ),
+        ];
+        for (name, other, expected) in cases {
+            assert_eq!(point.overlaps(&other), expected, "{name}");
+            assert_eq!(other.overlaps(&point), expected, "{name} (swapped)");
+        }
+
+        assert_eq!(
+            point.segment_distance(Vec2::new(-20.0, 0.0), Vec2::new(20.0, 0.0)),
+            Some(15.0)
+        );
+        assert_eq!(
+            point.segment_distance(Vec2::new(-20.0, 6.0), Vec2::new(20.0, 6.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn segment_distance_to_every_shape() {
+        let start = Vec2::ZERO;
+        let end = Vec2::new(20.0, 0.0);
+        let cases = [
+            ("circle", circle(10.0, 0.0, 2.0), Some(8.0)),
+            ("circle behind", circle(-10.0, 0.0, 2.0), None),
+            ("circle beside", circle(10.0, 5.0, 2.0), None),
+            ("circle beyond end", circle(25.0, 0.0, 2.0), None),
+            ("start inside circle", circle(0.0, 0.0, 2.0), Some(0.0)),
+            ("box", aabb(10.0, 0.0, 2.0, 2.0), Some(8.0)),
+            ("box beside", aabb(10.0, 5.0, 2.0, 2.0), None),
+            ("turned box", obb(10.0, 0.0, 4.0, 2.0, Vec2::Y), Some(8.0)),
+            (
+                "diamond",
+                obb(10.0, 0.0, 1.0, 1.0, diagonal()),
+                Some(10.0 - 2f32.sqrt()),
+            ),
+            (
+                "capsule body",
+                capsule((10.0, -5.0), (10.0, 5.0), 1.0),
+                Some(9.0),
+            ),
+            (
+                "capsule cap",
+                capsule((10.0, 3.0), (10.0, 10.0), 3.0),
+                Some(10.0),
+            ),
+        ];
+
+        for (name, shape, expected) in cases {
+            let distance = shape.segment_distance(start, end);
+            match (distance, expected) {
+                (Some(distance), Some(expected)) => {
+                    assert!(
+                        (distance - expected).abs() < 1e-4,
+                        "{name}: {distance} != {expected}"
+                    )
+                }
+                _ => assert_eq!(distance, expected, "{name}"),
+            }
+        }
+    }
+
+    #[test]
+    fn sweep_circle_catches_target_skipped_within_one_tick() {
+        // 반경 4인 탄이 한 틱에 100픽셀을 움직여 반경 18인 적을 통째로 건너뜀
+        let enemy = circle(0.0, 0.0, 18.0);
+        let (start, end) = (Vec2::new(0.0, -50.0), Vec2::new(0.0, 50.0));
+        assert!(!enemy.overlaps(&circle(start.x, start.y, 4.0)));
+        assert!(!enemy.overlaps(&circle(end.x, end.y, 4.0)));
+
+        assert_eq!(enemy.sweep_circle(start, end, 4.0), Some(28.0));
+        assert_eq!(
+            aabb(0.0, 0.0, 18.0, 18.0).sweep_circle(start, end, 4.0),
+            Some(28.0)
+        );
+        // 적을 비켜 가는 경로는 닿지 않음
+        assert_eq!(
+            enemy.sweep_circle(Vec2::new(23.0, -50.0), Vec2::new(23.0, 50.0), 4.0),
+            None
+        );
+    }
+
+    #[test]
+    fn zero_length_sweep_is_an_overlap_test() {
+        let point = Vec2::new(0.0, -30.0);
+        let cases = [
+            ("circle away", circle(0.0, 0.0, 18.0), None),
+            ("circle overlapping", circle(0.0, -20.0, 18.0), Some(0.0)),
+            ("box away", aabb(0.0, 0.0, 18.0, 18.0), None),
+            ("box overlapping", aabb(0.0, -20.0, 18.0, 18.0), Some(0.0)),
+            (
+                "capsule away",
+                capsule((-20.0, 0.0), (20.0, 0.0), 18.0),
+                None,
+            ),
+            (
+                "capsule overlapping",
+                capsule((-20.0, -20.0), (20.0, -20.0), 18.0),
+                Some(0.0),
+            ),
+        ];
+        for (name, shape, expected) in cases {
+            assert_eq!(shape.sweep_circle(point, point, 4.0), expected, "{name}");
+        }
+    }
+
+    #[test]
+    fn sweep_starting_inside_hits_at_zero() {
+        let (start, end) = (Vec2::new(0.0, -10.0), Vec2::new(0.0, 50.0));
+        let cases = [
+            ("circle", circle(0.0, 0.0, 18.0)),
+            ("box", aabb(0.0, 0.0, 18.0, 18.0)),
+            ("diamond", obb(0.0, 0.0, 18.0, 18.0, diagonal())),
+            ("capsule", capsule((-20.0, 0.0), (20.0, 0.0), 18.0)),
+        ];
+        for (name, shape) in cases {
+            assert_eq!(shape.sweep_circle(start, end, 4.0), Some(0.0), "{name}");
+        }
+    }
+}
@@ -0,0 +1,589 @@
+//! 공간 분할 모듈
+//!
+//! 충돌 검사 전에 매 틱 [`SpatialGrid`](균일 격자 공간 해시)를 다시 만들어
+//! 모든 충돌 시스템이 "근처에 있는 것"만 검사하도록 합니다.
+//! 탄이 수천 개여도 충돌 검사가 `투사체 수 × 적 수`로 늘어나지 않습니다.
+//!
+//! # 격자
+//! - [`Transform`]과 [`Collider`]를 가진 모든 엔티티가 월드 좌표 도형([`Shape`])과 층으로 들어감
+//!   (부활 대기 중인 엔티티는 빠짐)
+//! - 도형을 감싸는 원이 걸친 칸마다 들어가며, 플레이 영역을 [`SPATIAL_CELL_SIZE`] 크기의 칸으로 나누고
+//!   영역 밖의 엔티티는 가장자리 칸에 들어감
+//! - 질의([`SpatialGrid::query_shape`], [`SpatialGrid::query_segment`], [`SpatialGrid::query_sweep`])는 찾을 층을 받아 그 층의 후보만
+//!   배치 순서([`GridEntry::placement_cmp`])로 정렬해 반환하므로 쿼리 순회 순서와 무관하게 결과가 같음
+//!
+//! 엔티티 번호는 판 밖에서 생겼다 사라진 엔티티(메뉴 UI 등)에 따라 녹화와 리플레이에서 달라질 수 있으므로
+//! 정렬에는 쓰지 않고, 도형과 층까지 모두 같은 충돌체끼리만 마지막 기준으로 씁니다.
+//!
+//! 격자는 이동 단계가 끝난 위치로 만들어지므로 같은 틱의 충돌 단계와 정리 단계에서만 유효합니다.
+//! 질의 결과에는 이번 틱에 삭제된 엔티티가 남아 있을 수 있으니, 쿼리로 다시 확인해야 합니다.
+
+use std::cmp::Ordering;
+
+use bevy::prelude::*;
+
+use crate::components::{Collider, CollisionLayers, Respawning};
+use crate::resources::Playfield;
+use crate::shape::Shape;
+use crate::GameplaySet;
+
+/// 격자 한 칸의 한 변 길이 (픽셀)
+///
+/// 대부분의 충돌체(탄, 플레이어, 일반 적)가 한두 칸에 들어가는 크기입니다.
+pub const SPATIAL_CELL_SIZE: f32 = 64.0;
+
+// =============================================================================
+// 공간 격자
+// =============================================================================
+
+/// 격자에 들어간 충돌체 하나입니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridEntry {
+    pub entity: Entity,
+    /// 월드 좌표 도형
+    pub shape: Shape,
+    /// 충돌체가 속한 층
+    pub layers: CollisionLayers,
+}
+
+impl GridEntry {
+    /// 질의 결과를 늘어놓는 순서입니다.
+    ///
+    /// 도형의 배치([`Shape::placement_cmp`]), 층 순서로 비교하고 모두 같을 때만 엔티티 순서를 씁니다.
+    pub fn placement_cmp(&self, other: &GridEntry) -> Ordering {
+        self.shape
+            .placement_cmp(&other.shape)
+            .then(self.layers.0.cmp(&other.layers.0))
+            .then(self.entity.cmp(&other.entity))
+    }
+}
+
+/// 플레이 영역을 같은 크기의 칸으로 나눈 공간 해시 리소스입니다.
+///
+/// 엔티티는 도형을 감싸는 원의 사각형이 걸친 모든 칸에 들어가며,
+/// 질의는 질의 영역이 걸친 칸의 후보를 중복 없이 모아 반환합니다.
+#[derive(Resource, Debug, Clone)]
+pub struct SpatialGrid {
+    /// 칸의 한 변 길이
+    cell_size: f32,
+    /// 격자의 왼쪽 아래 모서리
+    origin: Vec2,
+    /// 가로, 세로 칸 수
+    dimensions: UVec2,
+    /// 칸마다 들어간 충돌체 (행 우선 순서)
+    cells: Vec<Vec<GridEntry>>,
+}
+
+impl Default for SpatialGrid {
+    fn default() -> Self {
+        Self::new(Playfield::default().size, SPATIAL_CELL_SIZE)
+    }
+}
+
+impl SpatialGrid {
+    /// 중심이 원점이고 크기가 `size`인 영역을 덮는 빈 격자를 만듭니다.
+    pub fn new(size: Vec2, cell_size: f32) -> Self {
+        let mut grid = Self {
+            cell_size,
+            origin: Vec2::ZERO,
+            dimensions: UVec2::ZERO,
+            cells: Vec::new(),
+        };
+        grid.reset(size);
+        grid
+    }
+
+    /// 모든 충돌체를 지우고, 영역 크기가 바뀌었으면 칸을 다시 나눕니다.
+    ///
+    /// 칸의 메모리는 재사용하므로 매 틱 호출해도 할당이 거의 없습니다.
+    pub fn reset(&mut self, size: Vec2) {
+        let dimensions = (size / self.cell_size).ceil().as_uvec2().max(UVec2::ONE);
+        if dimensions != self.dimensions {
+            self.dimensions = dimensions;
+            self.cells = vec![Vec::new(); (dimensions.x * dimensions.y) as usize];
+        }
+        self.origin = -size / 2.0;
+        for cell in &mut self.cells {
+            cell.clear();
+        }
+    }
+
+    /// 충돌체 하나를 도형이 걸친 모든 칸에 넣습니다.
+    pub fn insert(&mut self, entity: Entity, shape: Shape, layers: CollisionLayers) {
+        let entry = GridEntry {
+            entity,
+            shape,
+            layers,
+        };
+        let (position, radius) = shape.bounding_circle();
+        let (min, max) = self.cell_range(position - radius, position + radius);
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let index = self.cell_index(UVec2::new(x, y));
+                self.cells[index].push(entry);
+            }
+        }
+    }
+
+    /// 사각형 `min`~`max`가 걸친 칸에서 `layers`에 속한 충돌체를 중복 없이 배치 순서로 반환합니다.
+    ///
+    /// 칸 단위로 고르므로 실제로 사각형과 겹치지 않는 충돌체도 들어 있을 수 있습니다.
+    pub fn query_rect(&self, min: Vec2, max: Vec2, layers: CollisionLayers) -> Vec<GridEntry> {
+        let (min, max) = self.cell_range(min, max);
+        let mut entries: Vec<GridEntry> = Vec::new();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let cell = &self.cells[self.cell_index(UVec2::new(x, y))];
+                entries.extend(cell.iter().filter(|entry| entry.layers.intersects(layers)));
+            }
+        }
+        // 같은 충돌체는 모든 값이 같으므로 정렬하면 나란히 모임
+        entries.sort_unstable_by(GridEntry::placement_cmp);
+        entries.dedup_by_key(|entry| entry.entity);
+        entries
+    }
+
+    /// `shape`과 겹치는 `layers`의 충돌체를 배치 순서로 반환합니다.
+    pub fn query_shape(&self, shape: &Shape, layers: CollisionLayers) -> Vec<GridEntry> {
+        let (center, radius) = shape.bounding_circle();
+        let mut entries = self.query_rect(center - radius, center + radius, layers);
+        entries.retain(|entry| shape.overlaps(&entry.shape));
+        entries
+    }
+
+    /// 선분 `start`→`end`에 닿을 수 있는 `layers`의 충돌체 후보를 배치 순서로 반환합니다.
+    ///
+    /// 선분을 감싸는 사각형이 걸친 칸의 충돌체를 모두 반환하므로,
+    /// 실제로 닿는지는 [`Shape::segment_distance`]로 확인해야 합니다.
+    pub fn query_segment(&self, start: Vec2, end: Vec2, layers: CollisionLayers) -> Vec<GridEntry> {
+        self.query_rect(start.min(end), start.max(end), layers)
+    }
+
+    /// 반경 `radius`인 원이 `start`→`end`로 움직이며 닿는 `layers`의 충돌체를 배치 순서로 반환합니다.
+    ///
+    /// 각 충돌체에 처음 닿기까지 움직인 거리([`Shape::sweep_circle`])를 함께 반환합니다.
+    pub fn query_sweep(
+        &self,
+        start: Vec2,
+        end: Vec2,
+        radius: f32,
+        layers: CollisionLayers,
+    ) -> Vec<(GridEntry, f32)> {
+        self.query_rect(start.min(end) - radius, start.max(end) + radius, layers)
+            .into_iter()
+            .filter_map(|entry| {
+                entry
+                    .shape
+                    .sweep_circle(start, end, radius)
+                    .map(|distance| (entry, distance))
+            })
+            .collect()
+    }
+
+    /// 사각형 `min`~`max`가 걸친 칸 범위를 반환합니다. 격자 밖은 가장자리 칸으로 맞춥니다.
+    fn cell_range(&self, min: Vec2, max: Vec2) -> (UVec2, UVec2) {
+        (self.cell_coord(min), self.cell_coord(max))
+    }
+
+    /// 위치가 속한 칸의 좌표를 반환합니다. 격자 밖은 가장자리 칸으로 맞춥니다.
+    fn cell_coord(&self, position: Vec2) -> UVec2 {
+        let cell = ((position - self.origin) / self.cell_size).floor();
+        let last = (self.dimensions - UVec2::ONE).as_vec2();
+        // NaN은 0번 칸으로
+        cell.clamp(Vec2::ZERO, last).as_uvec2()
+    }
+
+    fn cell_index(&self, coord: UVec2) -> usize {
+        (coord.y * self.dimensions.x + coord.x) as usize
+    }
+}
+
+// =============================================================================
+// 공간 분할 플러그인
+// =============================================================================
+
+/// 충돌 단계마다 [`SpatialGrid`]를 다시 만드는 플러그인입니다.
+pub struct SpatialPlugin;
+
+impl Plugin for SpatialPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpatialGrid>().add_systems(
+            FixedUpdate,
+            rebuild_spatial_grid.in_set(GameplaySet::Collision),
+        );
+    }
+}
+
+// =============================================================================
+// 시스템 (Systems)
+// =============================================================================
+
+/// 충돌체를 가진 모든 엔티티로 격자를 다시 만드는 시스템입니다.
+///
+/// 부활 대기 중인 엔티티는 넣지 않으므로 아무것과도 충돌하지 않습니다.
+/// 충돌 시스템들은 이 시스템 뒤에 실행되어야 합니다.
+pub(crate) fn rebuild_spatial_grid(
+    playfield: Res<Playfield>,
+    mut grid: ResMut<SpatialGrid>,
+    query: Query<(Entity, &Transform, &Collider), Without<Respawning>>,
+) {
+    grid.reset(playfield.size);
+    for (entity, transform, collider) in query.iter() {
+        grid.insert(
+            entity,
+            Shape::from_collider(collider, transform),
+            collider.layers,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+    use std::time::{Duration, Instant};
+
+    use rand::RngExt;
+
+    use super::*;
+    use crate::components::{Hostile, Projectile};
+    use crate::config::GameConfig;
+    use crate::enemy::enemy_spawning;
+    use crate::projectile::spawn_hostile_projectile;
+    use crate::resources::{AppState, GameRng, SpriteAssets};
+    use crate::stage::run_stage_timeline;
+    use crate::tests::stepped_app;
+
+    /// 성능 측정에서 화면에 유지할 적 탄 수
+    const BENCH_BULLETS: usize = 3000;
+    /// 성능 측정에서 잴 틱 수
+    const BENCH_TICKS: u32 = 600;
+    /// 측정 전에 버리는 틱 수 (적이 나오고 탄이 채워질 때까지)
+    const BENCH_WARMUP_TICKS: u32 = 120;
+    /// 성능 측정용 적 탄 속력 범위 (픽셀/초)
+    const BENCH_BULLET_SPEED: std::ops::Range<f32> = 40.0..120.0;
+
+    const LAYERS: [CollisionLayers; 4] = [
+        CollisionLayers::PLAYER,
+        CollisionLayers::PLAYER_BULLET,
+        CollisionLayers::ENEMY,
+        CollisionLayers::ENEMY_BULLET,
+    ];
+
+    /// 플레이 영역 안팎(음수 좌표 포함)에 크기가 제각각인 무작위 도형을 만듭니다.
+    fn random_shape(rng: &mut GameRng) -> Shape {
+        let rng = rng.rng();
+        let center = Vec2::new(
+            rng.random_range(-500.0..500.0),
+            rng.random_range(-400.0..400.0),
+        );
+        // 작은 탄부터 여러 칸에 걸치는 보스 크기까지
+        let size = if rng.random_bool(0.2) {
+            rng.random_range(64.0..200.0)
+        } else {
+            rng.random_range(2.0..40.0)
+        };
+        match rng.random_range(0..3) {
+            0 => Shape::circle(center, size),
+            1 => Shape::Box {
+                center,
+                axis: Vec2::from_angle(rng.random_range(0.0..std::f32::consts::TAU)),
+                half_extents: Vec2::new(size, rng.random_range(2.0..40.0)),
+            },
+            _ => {
+                let half = Vec2::from_angle(rng.random_range(0.0..std::f32::consts::TAU)) * size;
+                Shape::Round {
+                    start: center - half,
+                    end: center + half,
+                    radius: rng.random_range(2.0..20.0),
+                }
+            }
+        }
+    }
+
+    fn random_layers(rng: &mut GameRng) -> CollisionLayers {
+        LAYERS[rng.rng().random_range(0..LAYERS.len())]
+    }
+
+    fn random_mask(rng: &mut GameRng) -> CollisionLayers {
+        CollisionLayers(rng.rng().random_range(1..16))
+    }
+
+    /// 무작위 충돌체를 넣은 격자와, 비교용으로 같은 충돌체를 모두 담은 목록을 만듭니다.
+    fn random_grid(seed: u64, count: usize) -> (SpatialGrid, Vec<GridEntry>, GameRng) {
+        let mut world = World::new();
+        let mut rng = GameRng::new(seed);
+        let mut grid = SpatialGrid::new(Vec2::new(800.0, 600.0), SPATIAL_CELL_SIZE);
+        let mut all = Vec::new();
+        for _ in 0..count {
+            let entry = GridEntry {
+                entity: world.spawn_empty().id(),
+                shape: random_shape(&mut rng),
+                layers: random_layers(&mut rng),
+            };
+            grid.insert(entry.entity, entry.shape, entry.layers);
+            all.push(entry);
+        }
+        (grid, all, rng)
+    }
+
+    /// 전수 검사 결과를 질의와 같은 순서로 정렬합니다.
+    fn brute_force(
+        all: &[GridEntry],
+        mask: CollisionLayers,
+        keep: impl Fn(&GridEntry) -> bool,
+    ) -> Vec<GridEntry> {
+        let mut expected: Vec<GridEntry> = all
+            .iter()
+            .copied()
+            .filter(|entry| entry.layers.intersects(mask) && keep(entry))
+            .collect();
+        expected.sort_by(GridEntry::placement_cmp);
+        expected
+    }
+
+    fn assert_sorted(entries: &[GridEntry]) {
+        assert!(entries
+            .windows(2)
+            .all(|pair| pair[0].placement_cmp(&pair[1]) == Ordering::Less));
+    }
+
+    #[test]
+    fn query_shape_matches_brute_force() {
+        let (grid, all, mut rng) = random_grid(1, 300);
+
+        for _ in 0..200 {
+            let shape = random_shape(&mut rng);
+            let mask = random_mask(&mut rng);
+            let found = grid.query_shape(&shape, mask);
+
+            assert_sorted(&found);
+            assert_eq!(
+                found,
+                brute_force(&all, mask, |entry| shape.overlaps(&entry.shape))
+            );
+        }
+    }
+
+    #[test]
+    fn query_rect_covers_every_overlapping_bounding_box() {
+        let (grid, all, mut rng) = random_grid(2, 300);
+
+        for _ in 0..200 {
+            let corner = random_shape(&mut rng).bounding_circle().0;
+            let size = Vec2::new(
+                rng.rng().random_range(0.0..300.0),
+                rng.rng().random_range(0.0..300.0),
+            );
+            let (min, max) = (corner, corner + size);
+            let mask = random_mask(&mut rng);
+            let overlaps_rect = |entry: &GridEntry| {
+                let (center, radius) = entry.shape.bounding_circle();
+                (center - radius).cmple(max).all() && (center + radius).cmpge(min).all()
+            };
+
+            let found = grid.query_rect(min, max, mask);
+            assert_sorted(&found);
+            // 칸 단위 후보이므로 겹치는 것만 골라 비교
+            let narrowed: Vec<GridEntry> = found.into_iter().filter(overlaps_rect).collect();
+            assert_eq!(narrowed, brute_force(&all, mask, overlaps_rect));
+        }
+    }
+
+    #[test]
+    fn query_segment_and_sweep_match_brute_force() {
+        let (grid, all, mut rng) = random_grid(3, 300);
+
+        for _ in 0..200 {
+            let start = random_shape(&mut rng).bounding_circle().0;
+            let end = random_shape(&mut rng).bounding_circle().0;
+            let radius = rng.rng().random_range(1.0..30.0);
+            let mask = random_mask(&mut rng);
+
+            let found = grid.query_segment(start, end, mask);
+            assert_sorted(&found);
+            let narrowed: Vec<GridEntry> = found
+                .into_iter()
+                .filter(|entry| entry.shape.segment_distance(start, end).is_some())
+                .collect();
+            assert_eq!(
+                narrowed,
+                brute_force(&all, mask, |entry| entry
+                    .shape
+                    .segment_distance(start, end)
+                    .is_some())
+            );
+
+            let swept = grid.query_sweep(start, end, radius, mask);
+            let expected: Vec<(GridEntry, f32)> = brute_force(&all, mask, |_| true)
+                .into_iter()
+                .filter_map(|entry| {
+                    entry
+                        .shape
+                        .sweep_circle(start, end, radius)
+                        .map(|distance| (entry, distance))
+                })
+                .collect();
+            assert_eq!(swept, expected);
+        }
+    }
+
+    #[test]
+    fn collider_spanning_many_cells_is_returned_once() {
+        let mut world = World::new();
+        let mut grid = SpatialGrid::new(Vec2::new(800.0, 600.0), SPATIAL_CELL_SIZE);
+        let entity = world.spawn_empty().id();
+        let shape = Shape::circle(Vec2::new(-200.0, -150.0), 180.0);
+        grid.insert(entity, shape, CollisionLayers::ENEMY);
+
+        let found = grid.query_rect(
+            Vec2::splat(-400.0),
+            Vec2::splat(400.0),
+            CollisionLayers::ENEMY,
+        );
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].entity, entity);
+        assert!(grid
+            .query_rect(
+                Vec2::splat(-400.0),
+                Vec2::splat(400.0),
+                CollisionLayers::PLAYER
+            )
+            .is_empty());
+    }
+
+    #[test]
+    fn query_sweep_reports_distance_to_first_contact() {
+        let mut world = World::new();
+        let mut grid = SpatialGrid::new(Vec2::new(800.0, 600.0), SPATIAL_CELL_SIZE);
+        let near = world.spawn_empty().id();
+        let far = world.spawn_empty().id();
+        grid.insert(
+            near,
+            Shape::circle(Vec2::ZERO, 18.0),
+            CollisionLayers::ENEMY,
+        );
+        grid.insert(
+            far,
+            Shape::circle(Vec2::new(200.0, 0.0), 18.0),
+            CollisionLayers::ENEMY,
+        );
+
+        // 한 틱에 적을 통째로 건너뛰는 경로
+        let found = grid.query_sweep(
+            Vec2::new(0.0, -50.0),
+            Vec2::new(0.0, 50.0),
+            4.0,
+            CollisionLayers::ENEMY,
+        );
+        assert_eq!(found.len(), 1);
+        assert_eq!((found[0].0.entity, found[0].1), (near, 28.0));
+
+        // 움직이지 않으면 지금 겹친 것만
+        let point = Vec2::new(0.0, -20.0);
+        let found = grid.query_sweep(point, point, 4.0, CollisionLayers::ENEMY);
+        assert_eq!(found.len(), 1);
+        assert_eq!((found[0].0.entity, found[0].1), (near, 0.0));
+        let point = Vec2::new(0.0, -30.0);
+        assert!(grid
+            .query_sweep(point, point, 4.0, CollisionLayers::ENEMY)
+            .is_empty());
+
+        // 이미 겹친 채로 출발하면 거리 0, 지나가며 닿은 대상도 함께
+        let found = grid.query_sweep(
+            Vec2::new(10.0, 0.0),
+            Vec2::new(250.0, 0.0),
+            4.0,
+            CollisionLayers::ENEMY,
+        );
+        let found: Vec<(Entity, f32)> = found
+            .into_iter()
+            .map(|(entry, distance)| (entry.entity, distance))
+            .collect();
+        assert_eq!(found, [(near, 0.0), (far, 168.0)]);
+    }
+
+    /// 화면의 적 탄이 [`BENCH_BULLETS`]개보다 적으면 무작위 위치와 방향으로 채우는 시스템입니다.
+    fn refill_bullets(
+        mut commands: Commands,
+        sprites: Res<SpriteAssets>,
+        config: Res<GameConfig>,
+        playfield: Res<Playfield>,
+        mut rng: ResMut<GameRng>,
+        bullets: Query<(), (With<Projectile>, With<Hostile>)>,
+    ) {
+        let half = playfield.half_extents();
+        for _ in bullets.iter().count()..BENCH_BULLETS {
+            let rng = rng.rng();
+            let position = Vec2::new(
+                rng.random_range(-half.x..half.x),
+                rng.random_range(-half.y..half.y),
+            );
+            let direction = Vec2::from_angle(rng.random_range(0.0..std::f32::consts::TAU));
+            let speed = rng.random_range(BENCH_BULLET_SPEED);
+            spawn_hostile_projectile(&mut commands, &sprites, &config, position, direction, speed);
+        }
+    }
+
+    /// 창 없이 게임을 돌리며 적 탄 수천 개를 유지해도 초당 60틱을 버티는지 확인합니다.
+    ///
+    /// 플레이어는 발사 키를 누른 채 가만히 있고, 체력을 크게 주어 탄에 맞아도 죽지 않습니다.
+    /// 적과 보스는 평소처럼 나옵니다. 최적화 빌드에서만 의미가 있으므로 따로 실행합니다.
+    ///
+    /// ```bash
+    /// cargo test --release dense_bullets -- --ignored --nocapture
+    /// ```
+    #[test]
+    #[ignore = "성능 측정: cargo test --release -- --ignored"]
+    fn dense_bullets_keep_sixty_ticks_per_second() {
+        let mut app = stepped_app(0);
+        // 탄을 채우는 난수가 스테이지와 적 스폰의 난수 순서를 흩트리지 않도록 그 뒤에 실행
+        app.add_systems(
+            FixedUpdate,
+            refill_bullets
+                .after(run_stage_timeline)
+                .after(enemy_spawning)
+                .in_set(GameplaySet::Spawn),
+        );
+        app.world_mut()
+            .resource_mut::<GameConfig>()
+            .player_max_health = u32::MAX;
+        app.world_mut()
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::InGame);
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+
+        for _ in 0..BENCH_WARMUP_TICKS {
+            app.update();
+        }
+
+        let mut slowest = Duration::ZERO;
+        let start = Instant::now();
+        for _ in 0..BENCH_TICKS {
+            let tick_start = Instant::now();
+            app.update();
+            slowest = slowest.max(tick_start.elapsed());
+        }
+        let elapsed = start.elapsed();
+
+        let world = app.world_mut();
+        let on_screen = world
+            .query_filtered::<(), (With<Projectile>, With<Hostile>)>()
+            .iter(world)
+            .count();
+        let ticks_per_second = BENCH_TICKS as f64 / elapsed.as_secs_f64();
+
+        println!("적 탄 {on_screen}개, {BENCH_TICKS}틱");
+        println!(
+            "틱당 평균 {:.2?}, 최대 {slowest:.2?}",
+            elapsed / BENCH_TICKS
+        );
+        println!("초당 {ticks_per_second:.0}틱 처리 가능");
+        assert!(on_screen >= BENCH_BULLETS / 2, "적 탄 {on_screen}개");
+        assert!(
+            ticks_per_second >= 60.0,
+            "초당 60틱을 유지하지 못함 ({ticks_per_second:.0}틱)"
+        );
+    }
+}
@@ -0,0 +1,755 @@
+//! 스테이지 모듈
+//!
+//! 스테이지 타임라인 에셋(`assets/stages/*.stage.ron`)을 읽어 정해진 순서대로 적을 스폰합니다.
+//! 스테이지가 진행 중인 동안에는 `enemy.rs`의 무작위 스폰이 멈추고,
+//! 설정의 `stages` 목록을 모두 마치면 무작위 스폰으로 돌아갑니다.
+//!
+//! # 이벤트 ([`StageEvent`])
+//! - `Wait`: 지정한 시간(초)만큼 대기
+//! - `Spawn`: 한 종류의 적 여러 마리를 대형([`Formation`])으로 화면 위에 스폰
+//! - `WaitClear`: 화면의 적이 모두 사라질 때까지 대기
+//! - `Message`: 화면 가운데에 안내 문구 표시 (대기하지 않음)
+//! - `Boss`: [`BossStart`] 메시지를 보내고 (보스 스폰은 `boss.rs`), 화면의 적이 모두 사라질 때까지 대기
+//!
+//! # 스테이지 예시
+//! ```text
+//! (
+//!     events: [
+//!         Message(text: "STAGE 1", duration: 2.0),
+//!         Wait(2.0),
+//!         Spawn(kind: Drone, count: 5, formation: Line, x: 0.0, spacing: 80.0),
+//!         WaitClear,
+//!         Boss,
+//!     ],
+//! )
+//! ```
+//!
+//! # 결정성
+//! 타임라인은 고정 틱의 경과 시간과 [`crate::resources::GameRng`]만 사용합니다.
+//! 판 도중에 로드가 끝나 진행이 달라지지 않도록 스테이지 파일은 설정이 바뀔 때 미리 읽어 두고,
+//! 판을 시작할 때 첫 스테이지가 아직 로드되지 않았으면 무작위 스폰으로 진행합니다.
+
+use std::fmt;
+use std::sync::Arc;
+
+use bevy::{
+    asset::{io::Reader, AssetLoadFailedEvent, AssetLoader, LoadContext},
+    ecs::message::MessageReader,
+    platform::collections::HashMap,
+    prelude::*,
+};
+use serde::Deserialize;
+
+use crate::components::{Enemy, EnemyKind};
+use crate::config::GameConfig;
+use crate::enemy::spawn_enemy;
+use crate::resources::{Difficulty, GameRng, Playfield, Playing, SpriteAssets};
+use crate::GameplaySet;
+
+/// 대형의 기본 간격 (픽셀)
+const DEFAULT_FORMATION_SPACING: f32 = 60.0;
+
+// =============================================================================
+// 스테이지 정의
+// =============================================================================
+
+/// 스테이지 타임라인 에셋입니다.
+#[derive(Asset, TypePath, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct StageTimeline {
+    /// 순서대로 실행할 이벤트 목록
+    pub events: Arc<[StageEvent]>,
+}
+
+/// 스테이지 타임라인의 이벤트 하나입니다.
+#[derive(Deserialize, Debug, Clone)]
+pub enum StageEvent {
+    /// 대기 (초)
+    Wait(f32),
+    /// 적 스폰
+    Spawn {
+        /// 적 종류
+        kind: EnemyKind,
+        /// 스폰할 수
+        #[serde(default = "default_count")]
+        count: u32,
+        /// 대형
+        #[serde(default)]
+        formation: Formation,
+        /// 대형 중심의 X 좌표 (0이 화면 가운데)
+        #[serde(default)]
+        x: f32,
+        /// 대형 안의 간격 (픽셀)
+        #[serde(default = "default_spacing")]
+        spacing: f32,
+    },
+    /// 화면의 적이 모두 사라질 때까지 대기
+    WaitClear,
+    /// 안내 문구를 `duration`초 동안 표시
+    Message { text: String, duration: f32 },
+    /// 보스 시작
+    Boss,
+}
+
+fn default_count() -> u32 {
+    1
+}
+
+fn default_spacing() -> f32 {
+    DEFAULT_FORMATION_SPACING
+}
+
+/// 여러 마리를 스폰할 때의 대형입니다.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Formation {
+    /// 가로 한 줄
+    #[default]
+    Line,
+    /// 세로 한 줄 (한 마리씩 이어서 내려옴)
+    Column,
+    /// 가운데가 앞선 V자
+    V,
+}
+
+impl Formation {
+    /// 대형 중심에서 `index`번째 적까지의 위치 차이를 반환합니다.
+    ///
+    /// 화면 위에서 내려오므로 Y가 클수록 늦게 등장합니다.
+    pub fn offset(self, index: u32, count: u32, spacing: f32) -> Vec2 {
+        let from_center = index as f32 - (count as f32 - 1.0) * 0.5;
+        match self {
+            Self::Line => Vec2::new(from_center * spacing, 0.0),
+            Self::Column => Vec2::new(0.0, index as f32 * spacing),
+            Self::V => Vec2::new(from_center * spacing, from_center.abs() * spacing),
+        }
+    }
+}
+
+impl StageTimeline {
+    /// RON 문자열에서 타임라인을 읽고 검증합니다.
+    pub fn from_ron(text: &str) -> Result<Self, StageError> {
+        let timeline: StageTimeline = ron::from_str(text)?;
+        timeline.validate()?;
+        Ok(timeline)
+    }
+
+    /// 타임라인 값이 유효한지 검사합니다.
+    ///
+    /// 잘못된 이벤트를 모두 모아 한 번에 보고합니다.
+    pub fn validate(&self) -> Result<(), StageError> {
+        let mut problems = Vec::new();
+
+        for (index, event) in self.events.iter().enumerate() {
+            let here = format!("events[{index}]");
+            match event {
+                StageEvent::Wait(seconds) => {
+                    if !(seconds.is_finite() && *seconds >= 0.0) {
+                        problems.push(format!("{here}.Wait: 0 이상이어야 합니다 (현재 {seconds})"));
+                    }
+                }
+                StageEvent::Spawn {
+                    count, x, spacing, ..
+                } => {
+                    if *count == 0 {
+                        problems.push(format!("{here}.count: 1 이상이어야 합니다 (현재 0)"));
+                    }
+                    for (name, value) in [("x", *x), ("spacing", *spacing)] {
+                        if !value.is_finite() {
+                            problems.push(format!(
+                                "{here}.{name}: 유한한 값이어야 합니다 (현재 {value})"
+                            ));
+                        }
+                    }
+                }
+                StageEvent::Message { duration, .. } => {
+                    if !(duration.is_finite() && *duration > 0.0) {
+                        problems.push(format!(
+                            "{here}.duration: 0보다 큰 값이어야 합니다 (현재 {duration})"
+                        ));
+                    }
+                }
+                StageEvent::WaitClear | StageEvent::Boss => {}
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(StageError::Invalid(problems))
+        }
+    }
+}
+
+/// 스테이지 파일을 읽을 때 발생하는 오류입니다.
+#[derive(Debug)]
+pub enum StageError {
+    /// 파일 입출력 오류
+    Io(std::io::Error),
+    /// RON 문법 오류 또는 알 수 없는 항목
+    Parse(ron::error::SpannedError),
+    /// 값 검증 실패 (잘못된 항목 목록)
+    Invalid(Vec<String>),
+}
+
+impl fmt::Display for StageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "입출력 오류: {err}"),
+            Self::Parse(err) => write!(f, "스테이지 파일 문법 오류: {err}"),
+            Self::Invalid(problems) => {
+                write!(f, "잘못된 스테이지 값 {}개", problems.len())?;
+                for problem in problems {
+                    write!(f, "\n  - {problem}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for StageError {}
+
+impl From<std::io::Error> for StageError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<ron::error::SpannedError> for StageError {
+    fn from(err: ron::error::SpannedError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+/// `.stage.ron` 파일을 [`StageTimeline`]으로 읽는 에셋 로더입니다.
+///
+/// 읽은 뒤 [`StageTimeline::validate`]로 검증하며, 실패하면 로드 자체가 실패합니다.
+#[derive(Default, TypePath)]
+pub struct StageTimelineLoader;
+
+impl AssetLoader for StageTimelineLoader {
+    type Asset = StageTimeline;
+    type Settings = ();
+    type Error = StageError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let timeline: StageTimeline = ron::de::from_bytes(&bytes)?;
+        timeline.validate()?;
+        Ok(timeline)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["stage.ron"]
+    }
+}
+
+// =============================================================================
+// 스테이지 진행 상태
+// =============================================================================
+
+/// 진행 중인 스테이지를 저장하는 리소스입니다.
+///
+/// `current`가 `None`이면 스테이지 없이 무작위 스폰으로 진행합니다.
+#[derive(Resource, Debug, Default)]
+pub struct StageProgress {
+    pub current: Option<ActiveStage>,
+}
+
+/// 진행 중인 스테이지 하나의 실행 위치입니다.
+#[derive(Debug, Clone)]
+pub struct ActiveStage {
+    /// 설정의 `stages` 목록에서의 순서 (0부터)
+    pub number: usize,
+    events: Arc<[StageEvent]>,
+    next: usize,
+    /// 다음 이벤트까지 남은 대기 시간 (초, 음수면 지난 틱에서 넘친 시간)
+    wait: f32,
+    /// 화면의 적이 모두 사라지기를 기다리는 중인지 여부
+    waiting_clear: bool,
+}
+
+impl ActiveStage {
+    /// 타임라인을 처음부터 실행하는 스테이지를 만듭니다.
+    pub fn new(number: usize, timeline: &StageTimeline) -> Self {
+        Self {
+            number,
+            events: timeline.events.clone(),
+            next: 0,
+            wait: 0.0,
+            waiting_clear: false,
+        }
+    }
+
+    /// 모든 이벤트를 실행했는지 여부입니다.
+    pub fn is_finished(&self) -> bool {
+        self.next >= self.events.len() && !self.waiting_clear && self.wait <= 0.0
+    }
+}
+
+/// 스테이지가 보낸 안내 문구를 저장하는 리소스입니다. UI가 화면에 표시합니다.
+#[derive(Resource, Debug, Default)]
+pub struct StageMessage {
+    /// 표시할 문구 (`None`이면 표시하지 않음)
+    pub text: Option<String>,
+    /// 남은 표시 시간
+    pub timer: Timer,
+}
+
+/// 스테이지 타임라인이 보스 차례에 보내는 메시지입니다.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct BossStart {
+    /// 보스가 나온 스테이지 순서 (0부터)
+    pub stage: usize,
+}
+
+/// 설정에 있는 스테이지 파일의 핸들을 경로별로 보관하는 리소스입니다.
+#[derive(Resource, Debug, Default)]
+pub struct StageLibrary {
+    pub handles: HashMap<String, Handle<StageTimeline>>,
+}
+
+/// 스테이지가 진행 중일 때만 참인 실행 조건입니다.
+pub fn stage_active(progress: Res<StageProgress>) -> bool {
+    progress.current.is_some()
+}
+
+// =============================================================================
+// 스테이지 플러그인
+// =============================================================================
+
+/// 스테이지 타임라인 에셋과 실행 시스템을 모아놓은 플러그인입니다.
+///
+/// AssetPlugin이 없는 헤드리스 앱에서는 스테이지 파일을 읽지 않으므로 무작위 스폰으로 진행하지만,
+/// [`StageProgress::current`]에 [`ActiveStage`]를 직접 넣으면 똑같이 실행됩니다.
+pub struct StagePlugin;
+
+impl Plugin for StagePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StageProgress>()
+            .init_resource::<StageMessage>()
+            .init_resource::<StageLibrary>()
+            .add_message::<BossStart>()
+            .add_systems(OnEnter(Playing), start_first_stage)
+            .add_systems(FixedUpdate, run_stage_timeline.in_set(GameplaySet::Spawn));
+
+        if !app.is_plugin_added::<AssetPlugin>() {
+            return;
+        }
+
+        app.init_asset::<StageTimeline>()
+            .init_asset_loader::<StageTimelineLoader>()
+            .add_systems(
+                Update,
+                (
+                    load_stages.run_if(resource_changed::<GameConfig>),
+                    report_stage_errors,
+                ),
+            );
+    }
+}
+
+// =============================================================================
+// 시스템 (Systems)
+// =============================================================================
+
+/// 설정의 스테이지 파일을 읽어 [`StageLibrary`]에 보관하는 시스템입니다.
+fn load_stages(
+    asset_server: Res<AssetServer>,
+    config: Res<GameConfig>,
+    mut library: ResMut<StageLibrary>,
+) {
+    for path in &config.stages {
+        if !library.handles.contains_key(path) {
+            let handle = asset_server.load(path.clone());
+            library.handles.insert(path.clone(), handle);
+        }
+    }
+}
+
+/// 스테이지 파일을 읽지 못했을 때 오류를 보고하는 시스템입니다.
+fn report_stage_errors(mut events: MessageReader<AssetLoadFailedEvent<StageTimeline>>) {
+    for event in events.read() {
+        error!("Failed to load stage '{}': {}", event.path, event.error);
+    }
+}
+
+/// 설정의 `number`번째 스테이지가 로드되어 있으면 실행 상태를 만듭니다.
+fn load_active_stage(
+    number: usize,
+    config: &GameConfig,
+    library: &StageLibrary,
+    timelines: Option<&Assets<StageTimeline>>,
+) -> Option<ActiveStage> {
+    let path = config.stages.get(number)?;
+    let timeline = timelines?.get(library.handles.get(path)?)?;
+    Some(ActiveStage::new(number, timeline))
+}
+
+/// 판 시작 시 첫 스테이지를 시작하고 안내 문구를 지우는 시스템입니다.
+fn start_first_stage(
+    config: Res<GameConfig>,
+    library: Res<StageLibrary>,
+    timelines: Option<Res<Assets<StageTimeline>>>,
+    mut progress: ResMut<StageProgress>,
+    mut message: ResMut<StageMessage>,
+) {
+    progress.current = load_active_stage(0, &config, &library, timelines.as_deref());
+    message.text = None;
+}
+
+/// 진행 중인 스테이지의 타임라인을 한 틱 실행하는 시스템입니다.
+///
+/// 스테이지를 마치면 다음 스테이지로 넘어가고, 마지막 스테이지였다면 무작위 스폰으로 돌아갑니다.
+/// 대기 시간이 틱 경계와 맞지 않아도 넘친 시간을 다음 틱으로 넘기므로 이벤트 간격이 밀리지 않습니다.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn run_stage_timeline(
+    mut commands: Commands,
+    time: Res<Time>,
+    sprites: Res<SpriteAssets>,
+    playfield: Res<Playfield>,
+    config: Res<GameConfig>,
+    difficulty: Res<Difficulty>,
+    library: Res<StageLibrary>,
+    timelines: Option<Res<Assets<StageTimeline>>>,
+    mut game_rng: ResMut<GameRng>,
+    mut progress: ResMut<StageProgress>,
+    mut message: ResMut<StageMessage>,
+    mut boss_start: MessageWriter<BossStart>,
+    enemies: Query<(), With<Enemy>>,
+) {
+    if message.text.is_some() && message.timer.tick(time.delta()).is_finished() {
+        message.text = None;
+    }
+
+    let Some(stage) = progress.current.as_mut() else {
+        return;
+    };
+
+    if stage.waiting_clear {
+        if !enemies.is_empty() {
+            return;
+        }
+        stage.waiting_clear = false;
+        stage.wait = 0.0;
+    } else {
+        stage.wait -= time.delta_secs();
+    }
+
+    let spawn_y = playfield.half_extents().y + 50.0;
+
+    while stage.wait <= 0.0 && !stage.waiting_clear {
+        let Some(event) = stage.events.get(stage.next).cloned() else {
+            break;
+        };
+        stage.next += 1;
+
+        match event {
+            StageEvent::Wait(seconds) => stage.wait += seconds,
+            StageEvent::Spawn {
+                kind,
+                count,
+                formation,
+                x,
+                spacing,
+            } => {
+                for index in 0..count {
+                    let position = Vec2::new(x, spawn_y) + formation.offset(index, count, spacing);
+                    spawn_enemy(
+                        &mut commands,
+                        &sprites,
+                        &config,
+                        &difficulty,
+                        &mut game_rng,
+                        kind,
+                        position,
+                    );
+                }
+            }
+            // 이번 틱에 스폰한 적은 다음 틱부터 보이므로 검사는 다음 틱에 합니다.
+            StageEvent::WaitClear => stage.waiting_clear = true,
+            StageEvent::Message { text, duration } => {
+                message.text = Some(text);
+                message.timer = Timer::from_seconds(duration, TimerMode::Once);
+            }
+            StageEvent::Boss => {
+                boss_start.write(BossStart {
+                    stage: stage.number,
+                });
+                stage.waiting_clear = true;
+            }
+        }
+    }
+
+    if stage.is_finished() {
+        let next = stage.number + 1;
+        info!("Stage {} cleared", stage.number + 1);
+        progress.current = load_active_stage(next, &config, &library, timelines.as_deref());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    /// 테스트 한 틱의 길이 (초)
+    const TICK: f32 = 0.25;
+
+    #[test]
+    fn formations_spread_around_the_center() {
+        let cases = [
+            (Formation::Line, 0, 1, Vec2::ZERO),
+            (Formation::Line, 0, 3, Vec2::new(-40.0, 0.0)),
+            (Formation::Line, 2, 3, Vec2::new(40.0, 0.0)),
+            (Formation::Line, 1, 4, Vec2::new(-20.0, 0.0)),
+            (Formation::Column, 0, 3, Vec2::ZERO),
+            (Formation::Column, 2, 3, Vec2::new(0.0, 80.0)),
+            (Formation::V, 0, 3, Vec2::new(-40.0, 40.0)),
+            (Formation::V, 1, 3, Vec2::ZERO),
+            (Formation::V, 2, 3, Vec2::new(40.0, 40.0)),
+            (Formation::V, 3, 4, Vec2::new(60.0, 60.0)),
+        ];
+        for (formation, index, count, expected) in cases {
+            assert_eq!(
+                formation.offset(index, count, 40.0),
+                expected,
+                "{formation:?} {index}/{count}"
+            );
+        }
+    }
+
+    #[test]
+    fn omitted_spawn_fields_use_defaults() {
+        let timeline = StageTimeline::from_ron("(events: [Spawn(kind: Drone)])").unwrap();
+        let StageEvent::Spawn {
+            kind,
+            count,
+            formation,
+            x,
+            spacing,
+        } = &timeline.events[0]
+        else {
+            panic!("expected a spawn event");
+        };
+        assert_eq!(*kind, EnemyKind::Drone);
+        assert_eq!(*count, 1);
+        assert_eq!(*formation, Formation::Line);
+        assert_eq!(*x, 0.0);
+        assert_eq!(*spacing, DEFAULT_FORMATION_SPACING);
+    }
+
+    #[test]
+    fn invalid_events_are_all_reported_together() {
+        let timeline = StageTimeline {
+            events: Arc::from([
+                StageEvent::Wait(-1.0),
+                StageEvent::Spawn {
+                    kind: EnemyKind::Drone,
+                    count: 0,
+                    formation: Formation::Line,
+                    x: f32::INFINITY,
+                    spacing: f32::NAN,
+                },
+                StageEvent::WaitClear,
+                StageEvent::Message {
+                    text: "HI".into(),
+                    duration: 0.0,
+                },
+                StageEvent::Boss,
+            ]),
+        };
+        let Err(StageError::Invalid(problems)) = timeline.validate() else {
+            panic!("expected validation errors");
+        };
+        assert_eq!(
+            problems,
+            [
+                "events[0].Wait: 0 이상이어야 합니다 (현재 -1)",
+                "events[1].count: 1 이상이어야 합니다 (현재 0)",
+                "events[1].x: 유한한 값이어야 합니다 (현재 inf)",
+                "events[1].spacing: 유한한 값이어야 합니다 (현재 NaN)",
+                "events[3].duration: 0보다 큰 값이어야 합니다 (현재 0)",
+            ]
+        );
+
+        assert!(matches!(
+            StageTimeline::from_ron("(events: [Wait(-1.0)])"),
+            Err(StageError::Invalid(_))
+        ));
+        assert!(matches!(
+            StageTimeline::from_ron("(events: [], music: \"boss\")"),
+            Err(StageError::Parse(_))
+        ));
+    }
+
+    /// 설정에 `stages`를 등록하고 `run_stage_timeline`을 돌릴 리소스를 갖춘 월드를 만듭니다.
+    fn stage_world(stages: &[&str]) -> World {
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs_f32(TICK));
+        world.insert_resource(time);
+        world.init_resource::<SpriteAssets>();
+        world.init_resource::<Playfield>();
+        world.init_resource::<Difficulty>();
+        world.init_resource::<StageProgress>();
+        world.init_resource::<StageMessage>();
+        world.init_resource::<Messages<BossStart>>();
+        world.insert_resource(GameRng::new(1));
+
+        let mut config = GameConfig {
+            stages: Vec::new(),
+            ..default()
+        };
+        let mut library = StageLibrary::default();
+        let mut timelines = Assets::<StageTimeline>::default();
+        for (number, text) in stages.iter().enumerate() {
+            let path = format!("stages/{number}.stage.ron");
+            let handle = timelines.add(StageTimeline::from_ron(text).unwrap());
+            library.handles.insert(path.clone(), handle);
+            config.stages.push(path);
+        }
+        world.insert_resource(config);
+        world.insert_resource(library);
+        world.insert_resource(timelines);
+        world
+    }
+
+    fn tick(world: &mut World) {
+        world.run_system_once(run_stage_timeline).unwrap();
+    }
+
+    /// 화면의 적 위치를 정렬해 반환합니다.
+    fn enemy_positions(world: &mut World) -> Vec<Vec2> {
+        let mut enemies = world.query_filtered::<&Transform, With<Enemy>>();
+        let mut positions: Vec<Vec2> = enemies
+            .iter(world)
+            .map(|transform| transform.translation.truncate())
+            .collect();
+        positions.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        positions
+    }
+
+    fn clear_enemies(world: &mut World) {
+        let enemies: Vec<Entity> = world
+            .query_filtered::<Entity, With<Enemy>>()
+            .iter(world)
+            .collect();
+        for enemy in enemies {
+            world.despawn(enemy);
+        }
+    }
+
+    fn current_stage(world: &World) -> Option<usize> {
+        world
+            .resource::<StageProgress>()
+            .current
+            .as_ref()
+            .map(|stage| stage.number)
+    }
+
+    #[test]
+    fn timeline_runs_tick_by_tick_into_the_next_stage() {
+        let mut world = stage_world(&[
+            "(events: [
+                Message(text: \"STAGE 1\", duration: 0.5),
+                Wait(0.6),
+                Spawn(kind: Drone, count: 3, formation: Line, x: 100.0, spacing: 40.0),
+                Wait(0.6),
+                Spawn(kind: Drone, count: 2, formation: Column, x: -50.0, spacing: 60.0),
+                WaitClear,
+                Boss,
+            ])",
+            "(events: [Spawn(kind: Drone, count: 3, formation: V, x: 0.0, spacing: 50.0)])",
+        ]);
+        let config = world.resource::<GameConfig>();
+        let library = world.resource::<StageLibrary>();
+        let timelines = world.resource::<Assets<StageTimeline>>();
+        let first = load_active_stage(0, config, library, Some(timelines));
+        assert!(first.is_some());
+        world.resource_mut::<StageProgress>().current = first;
+        let top = world.resource::<Playfield>().half_extents().y + 50.0;
+
+        // 0.25초: 문구를 띄우고 대기 시작
+        tick(&mut world);
+        assert_eq!(
+            world.resource::<StageMessage>().text.as_deref(),
+            Some("STAGE 1")
+        );
+        // 0.5초: 아직 대기 중
+        tick(&mut world);
+        assert!(enemy_positions(&mut world).is_empty());
+
+        // 0.75초: 0.6초 대기가 끝나 가로 대형 스폰, 0.5초짜리 문구도 사라짐
+        tick(&mut world);
+        assert_eq!(
+            enemy_positions(&mut world),
+            [
+                Vec2::new(60.0, top),
+                Vec2::new(100.0, top),
+                Vec2::new(140.0, top),
+            ]
+        );
+        assert_eq!(world.resource::<StageMessage>().text, None);
+
+        // 지난 대기에서 넘친 0.15초가 이어지므로 두 번째 대기는 1.2초(5틱째)에 끝남
+        tick(&mut world);
+        assert_eq!(enemy_positions(&mut world).len(), 3);
+        tick(&mut world);
+        let positions = enemy_positions(&mut world);
+        assert_eq!(positions.len(), 5);
+        assert_eq!(
+            positions[..2],
+            [Vec2::new(-50.0, top), Vec2::new(-50.0, top + 60.0)]
+        );
+
+        // 적이 남아 있는 동안은 WaitClear에서 멈춤
+        for _ in 0..3 {
+            tick(&mut world);
+        }
+        assert!(world.resource::<Messages<BossStart>>().is_empty());
+        assert_eq!(current_stage(&world), Some(0));
+
+        // 적이 사라지면 보스 시작을 알리고, 보스가 사라질 때까지 다시 대기
+        clear_enemies(&mut world);
+        tick(&mut world);
+        let starts: Vec<BossStart> = world
+            .resource_mut::<Messages<BossStart>>()
+            .drain()
+            .collect();
+        assert_eq!(starts.len(), 1);
+        assert_eq!(starts[0].stage, 0);
+        world.spawn((Transform::default(), Enemy));
+        tick(&mut world);
+        tick(&mut world);
+        assert_eq!(current_stage(&world), Some(0));
+
+        // 보스가 사라지면 다음 스테이지로 넘어가고, 그 다음 틱에 V자 대형 스폰
+        clear_enemies(&mut world);
+        tick(&mut world);
+        assert_eq!(current_stage(&world), Some(1));
+        assert!(enemy_positions(&mut world).is_empty());
+        tick(&mut world);
+        assert_eq!(
+            enemy_positions(&mut world),
+            [
+                Vec2::new(-50.0, top + 50.0),
+                Vec2::new(0.0, top),
+                Vec2::new(50.0, top + 50.0),
+            ]
+        );
+
+        // 마지막 스테이지를 마치면 무작위 스폰으로 돌아감
+        assert_eq!(current_stage(&world), None);
+    }
+}
@@ -2,18 +2,40 @@
 //!
 //! 게임의 모든 사용자 인터페이스를 담당합니다.
 //! - 메인 메뉴: 닉네임 입력 (Enter로 시작)
-//! - 인게임 UI: 미니멀한 점수 표시 (숫자만)
-//! - 게임 오버 화면: 닉네임과 함께 결과 표시
+//! - 인게임 UI: 미니멀한 점수 표시 (숫자만), 난이도 레벨, 남은 목숨과 체력, 무기와 강화 단계, 적용 중인 파워업, 스테이지 안내 문구, 보스 체력 바
+//! - 게임 오버 화면: 닉네임과 함께 결과 표시, 리플레이 저장
+//! - 일시정지 메뉴: 계속하기, 다시 시작, 메인 메뉴 (ESC/P로 열고 닫기)
+//! - 리플레이: 인게임 HUD에 리플레이 안내 표시
+//! - 리더보드: 상위 기록 목록 (메인 메뉴에서 F1, 또는 게임 오버 화면의 버튼)
+//! - 디버그 오버레이: 숨은 랭크와 난이도 배율, 엔티티 수 (게임 중 F3으로 켜고 끄기)
 //!
 //! # 주의: Bevy 0.18
 //! KeyboardInput 이벤트를 사용하여 입력을 처리합니다.
 
-use bevy::{ecs::message::MessageReader, input::keyboard::{Key, KeyboardInput}, prelude::*};
+use bevy::{
+    ecs::message::MessageReader,
+    input::keyboard::{Key, KeyboardInput},
+    prelude::*,
+};
 
+use crate::boss::Boss;
 use crate::components::{
-    ButtonAction, CursorBlink, GameOverUI, InGameUI, MainMenuUI, NewRecordText, PulseAnimation, ScoreText,
+    BossHealthBar, BossHealthFill, ButtonAction, CursorBlink, DebugOverlayText, Enemy, GameOverUI,
+    Health, Hostile, InGameUI, LeaderboardUI, LevelText, Lives, LivesText, MainMenuUI,
+    NewRecordText, PauseMenuUI, Player, PowerUpKind, PowerUpText, Projectile, PulseAnimation,
+    ReplayBanner, ScoreText, StageMessageText, WeaponKind, WeaponText,
+};
+use crate::config::GameConfig;
+use crate::leaderboard::{Leaderboard, LeaderboardEntry};
+use crate::powerup::ActivePowerUps;
+use crate::rank::Rank;
+use crate::replay::{new_replay_path, LastReplay, ReplayPlayback};
+use crate::resources::{
+    AppState, Difficulty, HighScore, IsNewRecord, PauseState, PlayerName, Playing, RunDuration,
+    Score, MAX_NAME_LENGTH,
 };
-use crate::resources::{AppState, HighScore, IsNewRecord, PlayerName, Score, MAX_NAME_LENGTH};
+use crate::stage::StageMessage;
+use crate::weapon::Weapon;
 
 // =============================================================================
 // 추가 UI 컴포넌트
@@ -23,6 +45,12 @@ use crate::resources::{AppState, HighScore, IsNewRecord, PlayerName, Score, MAX_
 #[derive(Component)]
 pub struct NicknameDisplay;
 
+/// 디버그 오버레이 표시 여부를 저장하는 리소스입니다. 판이 바뀌어도 유지됩니다.
+#[derive(Resource, Default)]
+pub struct DebugOverlay {
+    pub visible: bool,
+}
+
 // =============================================================================
 // UI 플러그인
 // =============================================================================
@@ -31,28 +59,61 @@ pub struct UiPlugin;
 
 impl Plugin for UiPlugin {
     fn build(&self, app: &mut App) {
-        app
+        app.init_resource::<DebugOverlay>()
             // 메인 메뉴
             .add_systems(OnEnter(AppState::MainMenu), setup_main_menu)
             .add_systems(OnExit(AppState::MainMenu), cleanup_main_menu)
             .add_systems(
                 Update,
-                (nickname_input_system, cursor_blink_system).run_if(in_state(AppState::MainMenu)),
+                (
+                    nickname_input_system,
+                    cursor_blink_system,
+                    open_leaderboard_system,
+                )
+                    .run_if(in_state(AppState::MainMenu)),
             )
-            // 인게임
-            .add_systems(OnEnter(AppState::InGame), setup_ingame_ui)
-            .add_systems(OnExit(AppState::InGame), cleanup_ingame_ui)
+            // 인게임 (리플레이 포함)
+            .add_systems(OnEnter(Playing), setup_ingame_ui)
+            .add_systems(OnExit(Playing), cleanup_ingame_ui)
             .add_systems(
                 Update,
-                update_score_text.run_if(in_state(AppState::InGame)),
+                (
+                    update_score_text,
+                    update_level_text,
+                    update_lives_text,
+                    update_powerup_text,
+                    update_weapon_text,
+                    update_stage_message_text,
+                    update_boss_health_bar,
+                    (toggle_debug_overlay, update_debug_overlay_text).chain(),
+                )
+                    .run_if(in_state(Playing)),
             )
+            // 일시정지 메뉴
+            .add_systems(OnEnter(PauseState::Paused), setup_pause_menu)
+            .add_systems(OnExit(PauseState::Paused), cleanup_pause_menu)
             // 게임 오버
             .add_systems(OnEnter(AppState::GameOver), setup_game_over_ui)
             .add_systems(OnExit(AppState::GameOver), cleanup_game_over_ui)
             .add_systems(
                 Update,
-                (animate_new_record_text, button_interaction_system)
-                    .run_if(in_state(AppState::GameOver)),
+                animate_new_record_text.run_if(in_state(AppState::GameOver)),
+            )
+            // 리더보드
+            .add_systems(OnEnter(AppState::Leaderboard), setup_leaderboard_ui)
+            .add_systems(OnExit(AppState::Leaderboard), cleanup_leaderboard_ui)
+            .add_systems(
+                Update,
+                close_leaderboard_system.run_if(in_state(AppState::Leaderboard)),
+            )
+            // 버튼 (게임 오버, 리더보드, 일시정지)
+            .add_systems(
+                Update,
+                button_interaction_system.run_if(
+                    in_state(AppState::GameOver)
+                        .or(in_state(AppState::Leaderboard))
+                        .or(in_state(PauseState::Paused)),
+                ),
             );
     }
 }
@@ -163,7 +224,7 @@ fn setup_main_menu(
 
             // 5. 조작법 안내
             parent.spawn((
-                Text::new("WASD: 이동 | SPACE: 발사"),
+                Text::new("WASD: 이동 | SPACE: 발사 | F1: 리더보드 | F2: 마지막 리플레이"),
                 TextFont {
                     font,
                     font_size: 18.0,
@@ -200,10 +261,8 @@ fn nickname_input_system(
     let mut text_changed = false;
 
     // Backspace: 마지막 문자 삭제
-    if keyboard.just_pressed(KeyCode::Backspace) {
-        if player_name.0.pop().is_some() {
-            text_changed = true;
-        }
+    if keyboard.just_pressed(KeyCode::Backspace) && player_name.0.pop().is_some() {
+        text_changed = true;
     }
 
     // 문자 입력 처리 (KeyboardInput 사용)
@@ -217,11 +276,9 @@ fn nickname_input_system(
         if let Key::Character(ref smol_str) = event.logical_key {
             for c in smol_str.chars() {
                 // ASCII 영문자 및 숫자만 허용
-                if c.is_ascii_alphanumeric() {
-                    if player_name.0.len() < MAX_NAME_LENGTH {
-                        player_name.0.push(c);
-                        text_changed = true;
-                    }
+                if c.is_ascii_alphanumeric() && player_name.0.len() < MAX_NAME_LENGTH {
+                    player_name.0.push(c);
+                    text_changed = true;
                 }
             }
         }
@@ -239,11 +296,19 @@ fn nickname_input_system(
     }
 
     // Enter: 게임 시작
-    if keyboard.just_pressed(KeyCode::Enter) {
-        if !player_name.0.is_empty() {
-            println!("Game Starting with player: {}", player_name.0);
-            next_state.set(AppState::InGame);
-        }
+    if keyboard.just_pressed(KeyCode::Enter) && !player_name.0.is_empty() {
+        println!("Game Starting with player: {}", player_name.0);
+        next_state.set(AppState::InGame);
+    }
+}
+
+/// F1 키로 리더보드 화면을 여는 시스템입니다.
+fn open_leaderboard_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if keyboard.just_pressed(KeyCode::F1) {
+        next_state.set(AppState::Leaderboard);
     }
 }
 
@@ -279,15 +344,42 @@ fn cursor_blink_system(
 // =============================================================================
 
 /// 미니멀한 점수 HUD를 생성하는 시스템입니다.
-/// 배경 없이 숫자만 우상단에 표시합니다.
-fn setup_ingame_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// 배경 없이 숫자만 우상단에 표시하고, 그 아래에 난이도 레벨을,
+/// 남은 목숨과 체력은 좌하단에 표시합니다.
+/// 스테이지 안내 문구는 화면 가운데 위쪽에 표시합니다.
+/// 리플레이 중에는 좌상단에 리플레이 안내를 함께 표시합니다.
+fn setup_ingame_ui(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    state: Res<State<AppState>>,
+) {
     let font: Handle<Font> = asset_server.load("fonts/font.ttf");
 
+    if *state.get() == AppState::Replay {
+        commands.spawn((
+            Text::new("REPLAY  [ESC] 나가기"),
+            TextFont {
+                font: font.clone(),
+                font_size: 24.0,
+                ..default()
+            },
+            TextColor(NEON_PINK),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(30.0),
+                top: Val::Px(20.0),
+                ..default()
+            },
+            ReplayBanner,
+            InGameUI,
+        ));
+    }
+
     // 점수 텍스트 (우상단, 배경 없음)
     commands.spawn((
         Text::new("0"),
         TextFont {
-            font,
+            font: font.clone(),
             font_size: 60.0,
             ..default()
         },
@@ -301,6 +393,166 @@ fn setup_ingame_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
         ScoreText,
         InGameUI,
     ));
+
+    // 난이도 레벨 텍스트 (점수 아래)
+    commands.spawn((
+        Text::new("LV 1"),
+        TextFont {
+            font: font.clone(),
+            font_size: 24.0,
+            ..default()
+        },
+        TextColor(NEON_CYAN),
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(30.0),
+            top: Val::Px(90.0),
+            ..default()
+        },
+        LevelText,
+        InGameUI,
+    ));
+
+    // 스테이지 안내 문구 (가운데 위쪽, 문구가 있을 때만 채워짐)
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                top: Val::Percent(30.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            InGameUI,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 48.0,
+                    ..default()
+                },
+                TextColor(NEON_PINK),
+                StageMessageText,
+            ));
+        });
+
+    // 보스 체력 바 (가운데 위쪽, 보스가 있을 때만 표시)
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                top: Val::Px(24.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            InGameUI,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(50.0),
+                        height: Val::Px(14.0),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BorderColor::all(NEON_PINK),
+                    BackgroundColor(OVERLAY_COLOR),
+                    Visibility::Hidden,
+                    BossHealthBar,
+                ))
+                .with_children(|bar| {
+                    bar.spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(NEON_PINK),
+                        BossHealthFill,
+                    ));
+                });
+        });
+
+    // 디버그 오버레이 (좌상단, F3으로 켜고 끔, 리플레이 안내 아래)
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font: font.clone(),
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(30.0),
+            top: Val::Px(60.0),
+            ..default()
+        },
+        Visibility::Hidden,
+        DebugOverlayText,
+        InGameUI,
+    ));
+
+    // 적용 중인 파워업 텍스트 (좌하단, 목숨 위)
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font: font.clone(),
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(NEON_CYAN),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(30.0),
+            bottom: Val::Px(52.0),
+            ..default()
+        },
+        PowerUpText,
+        InGameUI,
+    ));
+
+    // 무기 텍스트 (우하단, 플레이어 스폰 후 채워짐)
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font: font.clone(),
+            font_size: 24.0,
+            ..default()
+        },
+        TextColor(NEON_CYAN),
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(30.0),
+            bottom: Val::Px(20.0),
+            ..default()
+        },
+        WeaponText,
+        InGameUI,
+    ));
+
+    // 목숨/체력 텍스트 (좌하단, 플레이어 스폰 후 채워짐)
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font,
+            font_size: 24.0,
+            ..default()
+        },
+        TextColor(NEON_CYAN),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(30.0),
+            bottom: Val::Px(20.0),
+            ..default()
+        },
+        LivesText,
+        InGameUI,
+    ));
 }
 
 /// 인게임 UI를 정리하는 시스템입니다.
@@ -319,6 +571,189 @@ fn update_score_text(score: Res<Score>, mut query: Query<&mut Text, With<ScoreTe
     }
 }
 
+/// 난이도 레벨 텍스트를 업데이트하는 시스템입니다.
+fn update_level_text(difficulty: Res<Difficulty>, mut query: Query<&mut Text, With<LevelText>>) {
+    for mut text in query.iter_mut() {
+        let level = format!("LV {}", difficulty.level);
+        if **text != level {
+            **text = level;
+        }
+    }
+}
+
+/// 목숨/체력 텍스트를 업데이트하는 시스템입니다.
+#[allow(clippy::type_complexity)]
+fn update_lives_text(
+    player: Query<(&Lives, &Health), (With<Player>, Or<(Changed<Lives>, Changed<Health>)>)>,
+    mut query: Query<&mut Text, With<LivesText>>,
+) {
+    let Ok((lives, health)) = player.single() else {
+        return;
+    };
+
+    for mut text in query.iter_mut() {
+        **text = format!("목숨 {}   체력 {}/{}", lives.0, health.current, health.max);
+    }
+}
+
+/// 적용 중인 파워업과 남은 시간을 표시하는 시스템입니다.
+fn update_powerup_text(
+    powerups: Res<ActivePowerUps>,
+    config: Res<GameConfig>,
+    mut query: Query<&mut Text, With<PowerUpText>>,
+) {
+    if !powerups.is_changed() {
+        return;
+    }
+
+    let list = powerups
+        .effects()
+        .iter()
+        .map(|effect| {
+            let label = match effect.kind {
+                PowerUpKind::SpreadShot => "확산탄".to_string(),
+                PowerUpKind::RapidFire => "연사".to_string(),
+                PowerUpKind::Shield => "방어막".to_string(),
+                PowerUpKind::ScoreMultiplier => {
+                    format!("점수 x{}", config.powerups.score_multiplier)
+                }
+                PowerUpKind::ExtraLife | PowerUpKind::Bomb | PowerUpKind::WeaponUp => String::new(),
+            };
+            format!("{label} {:.1}초", effect.remaining)
+        })
+        .collect::<Vec<_>>()
+        .join("   ");
+    for mut text in query.iter_mut() {
+        **text = list.clone();
+    }
+}
+
+/// 플레이어 무기의 종류와 강화 단계 텍스트를 업데이트하는 시스템입니다.
+fn update_weapon_text(
+    player: Query<&Weapon, (With<Player>, Changed<Weapon>)>,
+    mut query: Query<&mut Text, With<WeaponText>>,
+) {
+    let Ok(weapon) = player.single() else {
+        return;
+    };
+
+    let name = match weapon.kind {
+        WeaponKind::Twin => "트윈",
+        WeaponKind::Spread => "확산",
+        WeaponKind::Laser => "레이저",
+        WeaponKind::Homing => "유도",
+    };
+    let label = format!("{name} Lv.{}", weapon.level + 1);
+    for mut text in query.iter_mut() {
+        if **text != label {
+            **text = label.clone();
+        }
+    }
+}
+
+/// 스테이지 안내 문구 텍스트를 업데이트하는 시스템입니다.
+fn update_stage_message_text(
+    message: Res<StageMessage>,
+    mut query: Query<&mut Text, With<StageMessageText>>,
+) {
+    if message.is_changed() {
+        for mut text in query.iter_mut() {
+            **text = message.text.clone().unwrap_or_default();
+        }
+    }
+}
+
+/// 보스가 있으면 체력 바를 보이고 남은 체력 비율만큼 채우는 시스템입니다.
+fn update_boss_health_bar(
+    bosses: Query<&Health, With<Boss>>,
+    mut bars: Query<&mut Visibility, With<BossHealthBar>>,
+    mut fills: Query<&mut Node, With<BossHealthFill>>,
+) {
+    let health = bosses.iter().next();
+
+    let visibility = if health.is_some() {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut bar in bars.iter_mut() {
+        bar.set_if_neq(visibility);
+    }
+
+    let Some(health) = health else {
+        return;
+    };
+    let width = Val::Percent(100.0 * health.current as f32 / health.max as f32);
+    for mut fill in fills.iter_mut() {
+        if fill.width != width {
+            fill.width = width;
+        }
+    }
+}
+
+/// F3 키로 디버그 오버레이를 켜고 끄는 시스템입니다.
+fn toggle_debug_overlay(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut overlay: ResMut<DebugOverlay>,
+    mut query: Query<&mut Visibility, With<DebugOverlayText>>,
+) {
+    if keyboard.just_pressed(KeyCode::F3) {
+        overlay.visible = !overlay.visible;
+    }
+
+    let visibility = if overlay.visible {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut current in query.iter_mut() {
+        current.set_if_neq(visibility);
+    }
+}
+
+/// 디버그 오버레이에 숨은 랭크, 난이도 배율, 엔티티 수를 표시하는 시스템입니다.
+#[allow(clippy::type_complexity)]
+fn update_debug_overlay_text(
+    overlay: Res<DebugOverlay>,
+    rank: Res<Rank>,
+    difficulty: Res<Difficulty>,
+    enemies: Query<(), With<Enemy>>,
+    hostile_projectiles: Query<(), (With<Projectile>, With<Hostile>)>,
+    mut query: Query<&mut Text, With<DebugOverlayText>>,
+) {
+    if !overlay.visible {
+        return;
+    }
+
+    let report = format!(
+        "RANK {:.3} (peak {:.3})\n\
+         accuracy {:.0}% ({}/{})  kills {}  deaths {}  no-hit {:.0}s\n\
+         LV {}  spawn x{:.2}  speed x{:.2}  fire x{:.2}  health x{:.2}  bullet x{:.2}\n\
+         enemies {}  bullets {}",
+        rank.value,
+        rank.peak,
+        rank.accuracy() * 100.0,
+        rank.hits,
+        rank.shots,
+        rank.kills,
+        rank.deaths,
+        rank.streak,
+        difficulty.level,
+        difficulty.spawn_rate,
+        difficulty.enemy_speed,
+        difficulty.fire_rate,
+        difficulty.enemy_health,
+        difficulty.bullet_speed,
+        enemies.iter().count(),
+        hostile_projectiles.iter().count(),
+    );
+    for mut text in query.iter_mut() {
+        if **text != report {
+            **text = report.clone();
+        }
+    }
+}
+
 /// 점수를 천 단위 구분 기호가 포함된 문자열로 변환합니다.
 fn format_score(score: u32) -> String {
     let s = score.to_string();
@@ -332,23 +767,98 @@ fn format_score(score: u32) -> String {
     result.chars().rev().collect()
 }
 
+// =============================================================================
+// 일시정지 메뉴 시스템
+// =============================================================================
+
+/// 일시정지 메뉴를 생성하는 시스템입니다.
+///
+/// 게임 화면 위에 반투명 오버레이로 표시되며, 게임 엔티티는 그대로 남아 있습니다.
+fn setup_pause_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let font: Handle<Font> = asset_server.load("fonts/font.ttf");
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(10.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+            PauseMenuUI,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("일시정지"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 60.0,
+                    ..default()
+                },
+                TextColor(NEON_CYAN),
+                Node {
+                    margin: UiRect::bottom(Val::Px(30.0)),
+                    ..default()
+                },
+            ));
+
+            spawn_secondary_button(parent, &font, "계속하기", ButtonAction::Resume);
+            spawn_secondary_button(parent, &font, "다시 시작", ButtonAction::RestartGame);
+            spawn_secondary_button(parent, &font, "메인 메뉴", ButtonAction::MainMenu);
+
+            parent.spawn((
+                Text::new("[ESC/P] 계속하기"),
+                TextFont {
+                    font,
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.4, 0.4, 0.4)),
+                Node {
+                    margin: UiRect::top(Val::Px(20.0)),
+                    ..default()
+                },
+            ));
+        });
+}
+
+/// 일시정지 메뉴를 정리하는 시스템입니다.
+fn cleanup_pause_menu(mut commands: Commands, query: Query<Entity, With<PauseMenuUI>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
 // =============================================================================
 // 게임 오버 UI 시스템
 // =============================================================================
 
 /// 게임 오버 UI를 생성하는 시스템입니다.
+///
+/// 직접 플레이한 판이면 리더보드에 기록을 추가하고 디스크에 저장합니다.
+/// 리플레이가 끝난 경우에는 기록을 갱신하지 않고,
+/// 녹화된 점수와 일치하는지 결과를 표시합니다.
+#[allow(clippy::too_many_arguments)]
 fn setup_game_over_ui(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     score: Res<Score>,
+    run_duration: Res<RunDuration>,
     mut high_score: ResMut<HighScore>,
     mut is_new_record: ResMut<IsNewRecord>,
+    mut leaderboard: ResMut<Leaderboard>,
     player_name: Res<PlayerName>,
+    playback: Option<Res<ReplayPlayback>>,
 ) {
     let font: Handle<Font> = asset_server.load("fonts/font.ttf");
 
-    // 신기록 판정
-    let new_record = score.0 > high_score.0 && score.0 > 0;
+    // 신기록 판정 (리플레이는 제외)
+    let new_record = playback.is_none() && score.0 > high_score.0 && score.0 > 0;
     is_new_record.0 = new_record;
 
     if new_record {
@@ -361,6 +871,17 @@ fn setup_game_over_ui(
         player_name.0.clone()
     };
 
+    // 리더보드 기록 (리플레이와 0점은 제외)
+    leaderboard.last_rank = None;
+    if playback.is_none() && score.0 > 0 {
+        let entry = LeaderboardEntry::now(name.clone(), score.0, run_duration.0);
+        if leaderboard.submit(entry).is_some() {
+            if let Err(err) = leaderboard.save() {
+                error!("Failed to save leaderboard: {err}");
+            }
+        }
+    }
+
     commands
         .spawn((
             Node {
@@ -424,8 +945,34 @@ fn setup_game_over_ui(
                 TextColor(Color::srgb(0.6, 0.7, 0.8)),
             ));
 
-            // 신기록 또는 도발 메시지
-            if new_record {
+            // 리플레이 결과, 신기록 또는 도발 메시지
+            if let Some(playback) = &playback {
+                let (message, color) = if playback.score_matches(score.0) {
+                    ("리플레이 점수 일치".to_string(), NEON_CYAN)
+                } else {
+                    (
+                        format!(
+                            "리플레이 불일치 (기록: {})",
+                            format_score(playback.replay.final_score)
+                        ),
+                        Color::srgb(1.0, 0.2, 0.2),
+                    )
+                };
+
+                parent.spawn((
+                    Text::new(message),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 24.0,
+                        ..default()
+                    },
+                    TextColor(color),
+                    Node {
+                        margin: UiRect::top(Val::Px(20.0)),
+                        ..default()
+                    },
+                ));
+            } else if new_record {
                 parent.spawn((
                     Text::new(format!("신기록 달성, {}!", name)),
                     TextFont {
@@ -486,37 +1033,66 @@ fn setup_game_over_ui(
                     ));
                 });
 
-            // 메인 메뉴 버튼
+            // 보조 버튼 (메인 메뉴, 리더보드, 리플레이 저장)
             parent
-                .spawn((
-                    Button,
-                    Node {
-                        width: Val::Px(250.0),
-                        height: Val::Px(50.0),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        margin: UiRect::top(Val::Px(10.0)),
-                        border: UiRect::all(Val::Px(1.0)),
-                        ..default()
-                    },
-                    BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 0.8)),
-                    BorderColor::all(Color::srgb(0.4, 0.4, 0.5)),
-                    ButtonAction::MainMenu,
-                ))
-                .with_children(|button| {
-                    button.spawn((
-                        Text::new("메인 메뉴"),
-                        TextFont {
-                            font,
-                            font_size: 22.0,
-                            ..default()
-                        },
-                        TextColor(Color::srgb(0.7, 0.7, 0.8)),
-                    ));
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(10.0),
+                    margin: UiRect::top(Val::Px(10.0)),
+                    ..default()
+                })
+                .with_children(|row| {
+                    spawn_secondary_button(row, &font, "메인 메뉴", ButtonAction::MainMenu);
+                    spawn_secondary_button(row, &font, "리더보드", ButtonAction::Leaderboard);
+
+                    // 리플레이 저장은 직접 플레이한 판만
+                    if playback.is_none() {
+                        spawn_secondary_button(
+                            row,
+                            &font,
+                            "리플레이 저장",
+                            ButtonAction::SaveReplay,
+                        );
+                    }
                 });
         });
 }
 
+/// 게임 오버 화면과 리더보드 화면에서 쓰는 작은 보조 버튼을 생성합니다.
+fn spawn_secondary_button(
+    parent: &mut ChildSpawnerCommands,
+    font: &Handle<Font>,
+    label: &str,
+    action: ButtonAction,
+) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(180.0),
+                height: Val::Px(50.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 0.8)),
+            BorderColor::all(Color::srgb(0.4, 0.4, 0.5)),
+            action,
+        ))
+        .with_children(|button| {
+            button.spawn((
+                Text::new(label),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.8)),
+            ));
+        });
+}
+
 /// 게임 오버 UI를 정리하는 시스템입니다.
 fn cleanup_game_over_ui(mut commands: Commands, query: Query<Entity, With<GameOverUI>>) {
     for entity in query.iter() {
@@ -540,25 +1116,178 @@ fn animate_new_record_text(
     }
 }
 
+// =============================================================================
+// 리더보드 UI 시스템
+// =============================================================================
+
+/// 리더보드 화면을 생성하는 시스템입니다.
+///
+/// 순위, 닉네임, 점수, 플레이 시간을 표로 보여줍니다.
+/// 방금 끝난 판이 순위에 들었으면 그 줄을 강조합니다.
+fn setup_leaderboard_ui(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    leaderboard: Res<Leaderboard>,
+) {
+    let font: Handle<Font> = asset_server.load("fonts/font.ttf");
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(Color::BLACK),
+            LeaderboardUI,
+        ))
+        .with_children(|parent| {
+            // 제목
+            parent.spawn((
+                Text::new("리더보드"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 60.0,
+                    ..default()
+                },
+                TextColor(NEON_CYAN),
+                Node {
+                    margin: UiRect::bottom(Val::Px(20.0)),
+                    ..default()
+                },
+            ));
+
+            if leaderboard.entries.is_empty() {
+                parent.spawn((
+                    Text::new("아직 기록이 없습니다"),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 24.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                ));
+            }
+
+            // 기록 목록
+            for (rank, entry) in leaderboard.entries.iter().enumerate() {
+                let highlighted = leaderboard.last_rank == Some(rank);
+                let (text_color, background) = if highlighted {
+                    (GOLD, Color::srgba(1.0, 0.2, 0.8, 0.25))
+                } else {
+                    (Color::srgb(0.8, 0.8, 0.9), Color::NONE)
+                };
+
+                let cells = [
+                    ((rank + 1).to_string(), 50.0),
+                    (entry.name.clone(), 220.0),
+                    (format_score(entry.score), 160.0),
+                    (format_duration(entry.duration_secs), 100.0),
+                ];
+
+                parent
+                    .spawn((
+                        Node {
+                            flex_direction: FlexDirection::Row,
+                            padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)),
+                            ..default()
+                        },
+                        BackgroundColor(background),
+                    ))
+                    .with_children(|row| {
+                        for (text, width) in cells {
+                            row.spawn((
+                                Text::new(text),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 24.0,
+                                    ..default()
+                                },
+                                TextColor(text_color),
+                                Node {
+                                    width: Val::Px(width),
+                                    ..default()
+                                },
+                            ));
+                        }
+                    });
+            }
+
+            // 돌아가기 안내
+            parent.spawn((
+                Text::new("[ESC] 메인 메뉴"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.4, 0.4, 0.4)),
+                Node {
+                    margin: UiRect::top(Val::Px(30.0)),
+                    ..default()
+                },
+            ));
+
+            spawn_secondary_button(parent, &font, "메인 메뉴", ButtonAction::MainMenu);
+        });
+}
+
+/// 리더보드 UI를 정리하는 시스템입니다.
+fn cleanup_leaderboard_ui(mut commands: Commands, query: Query<Entity, With<LeaderboardUI>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// ESC 키로 리더보드 화면을 닫고 메인 메뉴로 돌아가는 시스템입니다.
+fn close_leaderboard_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if keyboard.just_pressed(KeyCode::Escape) {
+        next_state.set(AppState::MainMenu);
+    }
+}
+
+/// 플레이 시간(초)을 `분:초` 형식의 문자열로 변환합니다.
+fn format_duration(secs: f32) -> String {
+    let total = secs.max(0.0) as u32;
+    format!("{}:{:02}", total / 60, total % 60)
+}
+
 // =============================================================================
 // 버튼 상호작용 시스템
 // =============================================================================
 
 /// 버튼 클릭을 감지하고 상태를 전환하는 시스템입니다.
+///
+/// 일시정지 중 재시작하면 `AppState`가 InGame 그대로이므로
+/// [`PauseState`]도 함께 Running으로 되돌립니다.
+#[allow(clippy::type_complexity)]
 fn button_interaction_system(
     mut next_state: ResMut<NextState<AppState>>,
+    mut next_pause_state: ResMut<NextState<PauseState>>,
     mut score: ResMut<Score>,
+    last_replay: Res<LastReplay>,
     mut interaction_query: Query<
         (
             &Interaction,
             &mut BackgroundColor,
             &mut BorderColor,
             &ButtonAction,
+            &Children,
         ),
         (Changed<Interaction>, With<Button>),
     >,
+    mut text_query: Query<&mut Text>,
 ) {
-    for (interaction, mut bg_color, mut border_color, action) in interaction_query.iter_mut() {
+    for (interaction, mut bg_color, mut border_color, action, children) in
+        interaction_query.iter_mut()
+    {
         match *interaction {
             Interaction::Pressed => {
                 *bg_color = BUTTON_PRESSED.into();
@@ -567,10 +1296,39 @@ fn button_interaction_system(
                     ButtonAction::RestartGame => {
                         score.0 = 0;
                         next_state.set(AppState::InGame);
+                        next_pause_state.set(PauseState::Running);
+                    }
+                    ButtonAction::Resume => {
+                        next_pause_state.set(PauseState::Running);
                     }
                     ButtonAction::MainMenu => {
                         next_state.set(AppState::MainMenu);
                     }
+                    ButtonAction::Leaderboard => {
+                        next_state.set(AppState::Leaderboard);
+                    }
+                    ButtonAction::SaveReplay => {
+                        let Some(replay) = &last_replay.0 else {
+                            continue;
+                        };
+
+                        let path = new_replay_path();
+                        let label = match replay.save(&path) {
+                            Ok(()) => {
+                                info!("Replay saved to {}", path.display());
+                                "저장 완료"
+                            }
+                            Err(err) => {
+                                error!("Failed to save replay: {err}");
+                                "저장 실패"
+                            }
+                        };
+
+                        let mut texts = text_query.iter_many_mut(children);
+                        while let Some(mut text) = texts.fetch_next() {
+                            **text = label.to_string();
+                        }
+                    }
                 }
             }
             Interaction::Hovered => {
@@ -0,0 +1,418 @@
+//! 무기 모듈
+//!
+//! 플레이어의 [`Weapon`]으로 투사체를 발사합니다.
+//! 발사 키를 누르고 있으면 무기의 발사 간격마다 계속 발사하고,
+//! 짧게 누르면 다음 발사가 가능해지는 대로 한 번 발사합니다.
+//!
+//! # 발사 과정
+//! 1. 쿨다운: 남은 시간이 없을 때만 발사 (연사 파워업이면 간격이 줄어듦)
+//! 2. 능력치: 무기 종류와 강화 단계로 설정의 [`WeaponLevel`]을 찾음
+//! 3. 탄 배치: [`WeaponLevel::volley`]로 탄마다 위치와 각도를 정함 (확산탄 파워업이면 양옆으로 한 벌씩 더)
+//! 4. 탄 생성: 무기 종류별로 탄을 만듦
+//!
+//! 무기 종류(트윈, 확산, 레이저, 유도)는 설정 값과 4단계만 다르고 나머지 과정은 같습니다.
+//! `Q`로 무기를 바꿔도 강화 단계는 유지되며, 강화 단계는 `WeaponUp` 파워업으로 오릅니다.
+//!
+//! # 레이저
+//! 레이저는 투사체 대신 발사 키를 누르고 있는 동안 탄 배치마다 광선([`LaserBeam`])을 유지합니다.
+//! 광선은 처음 닿은 적까지만 뻗고(`collision.rs`의 광선 질의), 발사 간격마다 피해를 줍니다.
+//!
+//! # 보조 무기
+//! 플레이어의 [`MissileLauncher`]는 발사 키를 누르고 있는 동안 주 무기와 따로 유도 미사일을 쏩니다.
+//! 능력치는 설정의 `missiles.launcher`이며 강화 단계와 파워업의 영향을 받지 않습니다.
+//! 유도 미사일과 유도 무기의 탄은 [`Homing`]과 수명([`Lifetime`])을 갖고, 유도는 `homing.rs`가 처리합니다.
+
+use bevy::{ecs::system::EntityCommands, prelude::*};
+
+use crate::components::{
+    Collider, ColliderShape, Damage, FaceVelocity, Lifetime, Player, PowerUpKind, PreviousPosition,
+    Projectile, Respawning, Velocity, WeaponKind,
+};
+use crate::config::{GameConfig, WeaponLevel};
+use crate::homing::Homing;
+use crate::player::player_movement;
+use crate::powerup::ActivePowerUps;
+use crate::rank::Rank;
+use crate::resources::{PlayerInput, Playing, SpriteAssets};
+use crate::GameplaySet;
+
+/// 탄이 플레이어 중심에서 앞으로 떨어져 나오는 거리 (픽셀)
+const MUZZLE_OFFSET: f32 = 40.0;
+/// 레이저 광선의 굵기 (픽셀)
+const LASER_BEAM_WIDTH: f32 = 10.0;
+
+// =============================================================================
+// 무기 컴포넌트
+// =============================================================================
+
+/// 플레이어가 든 무기입니다.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Weapon {
+    pub kind: WeaponKind,
+    /// 강화 단계 (0부터, 설정의 `levels` 순서)
+    pub level: u32,
+    /// 다음 발사까지 남은 시간 (초)
+    pub cooldown: f32,
+    /// 이번 틱에 발사 키가 눌려 있는지 여부
+    pub trigger: bool,
+    /// 이번 틱에 발사했는지 여부 (쿨다운이 끝난 틱)
+    pub fired: bool,
+}
+
+impl Weapon {
+    /// 강화하지 않은 무기를 만듭니다.
+    pub fn new(kind: WeaponKind) -> Self {
+        Self {
+            kind,
+            level: 0,
+            cooldown: 0.0,
+            trigger: false,
+            fired: false,
+        }
+    }
+
+    /// 현재 강화 단계의 능력치를 반환합니다.
+    pub fn stats<'a>(&self, config: &'a GameConfig) -> &'a WeaponLevel {
+        config.weapons.get(self.kind).level(self.level)
+    }
+
+    /// 강화 단계를 하나 올립니다. 지금 무기의 최고 단계를 넘지 않습니다.
+    pub fn upgrade(&mut self, config: &GameConfig) {
+        let max_level = config.weapons.get(self.kind).max_level();
+        self.level = (self.level + 1).min(max_level);
+    }
+}
+
+/// 플레이어의 보조 무기인 유도 미사일 발사기입니다.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Default)]
+pub struct MissileLauncher {
+    /// 다음 발사까지 남은 시간 (초)
+    pub cooldown: f32,
+}
+
+/// 레이저 광선 하나입니다. 플레이어가 레이저를 쏘는 동안만 존재합니다.
+///
+/// 시작점과 방향은 무기 시스템이, 길이는 충돌 시스템이 매 틱 정합니다.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct LaserBeam {
+    /// 탄 배치에서의 순서
+    pub index: usize,
+    /// 광선 시작점
+    pub origin: Vec2,
+    /// 진행 방향 (단위 벡터)
+    pub direction: Vec2,
+    /// 이번 틱에 줄 피해 (발사 간격마다 한 번, 나머지 틱은 0)
+    pub damage: u32,
+    /// 처음 닿은 적까지의 길이 (닿은 적이 없으면 화면 끝까지)
+    pub length: f32,
+}
+
+/// 무기 종류별 탄 스프라이트에 곱하는 색을 반환합니다.
+pub fn weapon_color(kind: WeaponKind) -> Color {
+    match kind {
+        WeaponKind::Twin => Color::WHITE,
+        WeaponKind::Spread => Color::srgb(1.0, 0.7, 0.3),
+        WeaponKind::Laser => Color::srgb(0.4, 1.0, 1.0),
+        WeaponKind::Homing => Color::srgb(1.0, 0.4, 0.9),
+    }
+}
+
+// =============================================================================
+// 무기 플러그인
+// =============================================================================
+
+/// 플레이어 무기의 교체와 발사를 담당하는 플러그인입니다.
+pub struct WeaponPlugin;
+
+impl Plugin for WeaponPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(Playing), cleanup_laser_beams)
+            .add_systems(
+                FixedUpdate,
+                (
+                    switch_weapon,
+                    fire_weapon,
+                    fire_missiles,
+                    update_laser_beams,
+                )
+                    .chain()
+                    .after(player_movement)
+                    .in_set(GameplaySet::Input),
+            )
+            .add_systems(Update, draw_laser_beams.run_if(in_state(Playing)));
+    }
+}
+
+// =============================================================================
+// 시스템 (Systems)
+// =============================================================================
+
+/// 무기 교체 요청이 있으면 다음 종류의 무기로 바꾸는 시스템입니다.
+fn switch_weapon(mut input: ResMut<PlayerInput>, mut query: Query<&mut Weapon, With<Player>>) {
+    if !std::mem::take(&mut input.switch_weapon) {
+        return;
+    }
+
+    for mut weapon in query.iter_mut() {
+        weapon.kind = weapon.kind.next();
+    }
+}
+
+/// 발사 키가 눌려 있고 쿨다운이 끝났으면 무기를 발사하는 시스템입니다.
+///
+/// 부활 대기 중에는 발사 요청을 버립니다.
+/// 남은 쿨다운은 다음 발사로 이월되므로 발사 간격이 틱 간격의 배수가 아니어도 평균 간격이 유지됩니다.
+/// 레이저는 여기서 탄을 만들지 않고 [`update_laser_beams`]가 광선을 유지합니다.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn fire_weapon(
+    mut input: ResMut<PlayerInput>,
+    mut commands: Commands,
+    time: Res<Time>,
+    sprites: Res<SpriteAssets>,
+    config: Res<GameConfig>,
+    powerups: Res<ActivePowerUps>,
+    mut rank: ResMut<Rank>,
+    mut query: Query<(&Transform, &mut Weapon), (With<Player>, Without<Respawning>)>,
+) {
+    let requested = std::mem::take(&mut input.fire) || input.fire_held;
+
+    // 플레이어가 없거나 부활 대기 중이면 조기 종료
+    let Ok((player_transform, mut weapon)) = query.single_mut() else {
+        return;
+    };
+
+    weapon.trigger = requested;
+    weapon.cooldown -= time.delta_secs();
+    weapon.fired = requested && weapon.cooldown <= 0.0;
+    if !weapon.fired {
+        weapon.cooldown = weapon.cooldown.max(0.0);
+        return;
+    }
+
+    let stats = weapon.stats(&config);
+    let mut interval = stats.interval;
+    if powerups.is_active(PowerUpKind::RapidFire) {
+        interval /= config.powerups.rapid_fire_rate;
+    }
+    weapon.cooldown += interval;
+
+    if weapon.kind == WeaponKind::Laser {
+        return;
+    }
+
+    let muzzle = player_transform.translation.truncate() + Vec2::new(0.0, MUZZLE_OFFSET);
+    for (offset, direction) in shots(stats, &config, &powerups) {
+        spawn_player_projectile(
+            &mut commands,
+            &sprites,
+            &config,
+            weapon.kind,
+            muzzle + Vec2::new(offset, 0.0),
+            direction * stats.speed,
+            stats.damage,
+        );
+        rank.record_shot(&config.rank);
+    }
+}
+
+/// 발사 키가 눌려 있는 동안 발사 간격마다 유도 미사일을 쏘는 시스템입니다.
+///
+/// 발사 키 상태는 [`fire_weapon`]이 정한 [`Weapon::trigger`]를 따르며, 쿨다운은 주 무기와 같은 방식으로 이월됩니다.
+#[allow(clippy::type_complexity)]
+fn fire_missiles(
+    mut commands: Commands,
+    time: Res<Time>,
+    sprites: Res<SpriteAssets>,
+    config: Res<GameConfig>,
+    mut rank: ResMut<Rank>,
+    mut query: Query<
+        (&Transform, &Weapon, &mut MissileLauncher),
+        (With<Player>, Without<Respawning>),
+    >,
+) {
+    let Ok((player_transform, weapon, mut launcher)) = query.single_mut() else {
+        return;
+    };
+
+    launcher.cooldown -= time.delta_secs();
+    if !(weapon.trigger && launcher.cooldown <= 0.0) {
+        launcher.cooldown = launcher.cooldown.max(0.0);
+        return;
+    }
+
+    let stats = &config.missiles.launcher;
+    launcher.cooldown += stats.interval;
+
+    let origin = player_transform.translation.truncate();
+    for (offset, angle) in stats.volley() {
+        let direction = Vec2::from_angle(-angle.to_radians()).rotate(Vec2::Y);
+        spawn_player_projectile(
+            &mut commands,
+            &sprites,
+            &config,
+            WeaponKind::Homing,
+            origin + Vec2::new(offset, 0.0),
+            direction * stats.speed,
+            stats.damage,
+        );
+        rank.record_shot(&config.rank);
+    }
+}
+
+/// 레이저를 쏘는 동안 탄 배치마다 광선을 하나씩 유지하는 시스템입니다.
+///
+/// 광선의 시작점과 방향을 플레이어 위치에 맞추고, 발사한 틱에만 피해를 싣습니다.
+/// 레이저가 아니거나 발사 키를 떼거나 부활 대기 중이면 광선을 모두 제거합니다.
+#[allow(clippy::type_complexity)]
+fn update_laser_beams(
+    mut commands: Commands,
+    sprites: Res<SpriteAssets>,
+    config: Res<GameConfig>,
+    powerups: Res<ActivePowerUps>,
+    mut rank: ResMut<Rank>,
+    player: Query<(&Transform, &Weapon), (With<Player>, Without<Respawning>)>,
+    mut beams: Query<(Entity, &mut LaserBeam)>,
+) {
+    let firing = player
+        .single()
+        .ok()
+        .filter(|(_, weapon)| weapon.kind == WeaponKind::Laser && weapon.trigger);
+    let Some((player_transform, weapon)) = firing else {
+        for (entity, _) in beams.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    };
+
+    let stats = weapon.stats(&config);
+    let damage = if weapon.fired { stats.damage } else { 0 };
+    let muzzle = player_transform.translation.truncate() + Vec2::new(0.0, MUZZLE_OFFSET);
+    let shots: Vec<_> = shots(stats, &config, &powerups).collect();
+
+    let mut existing = vec![false; shots.len()];
+    for (entity, mut beam) in beams.iter_mut() {
+        let Some(&(offset, direction)) = shots.get(beam.index) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        existing[beam.index] = true;
+        beam.origin = muzzle + Vec2::new(offset, 0.0);
+        beam.direction = direction;
+        beam.damage = damage;
+    }
+
+    for (index, &(offset, direction)) in shots.iter().enumerate() {
+        if !existing[index] {
+            commands.spawn((
+                Sprite {
+                    image: sprites.bullet.clone(),
+                    color: weapon_color(WeaponKind::Laser),
+                    ..default()
+                },
+                Transform::default(),
+                LaserBeam {
+                    index,
+                    origin: muzzle + Vec2::new(offset, 0.0),
+                    direction,
+                    damage,
+                    length: 0.0,
+                },
+            ));
+        }
+    }
+
+    if weapon.fired {
+        for _ in &shots {
+            rank.record_shot(&config.rank);
+        }
+    }
+}
+
+/// 광선의 스프라이트를 시작점에서 길이만큼 늘여 그리는 시스템입니다.
+fn draw_laser_beams(mut beams: Query<(&LaserBeam, &mut Transform, &mut Sprite)>) {
+    for (beam, mut transform, mut sprite) in beams.iter_mut() {
+        let center = beam.origin + beam.direction * beam.length * 0.5;
+        transform.translation = center.extend(transform.translation.z);
+        transform.rotation = Quat::from_rotation_arc_2d(Vec2::Y, beam.direction);
+        sprite.custom_size = Some(Vec2::new(LASER_BEAM_WIDTH, beam.length));
+    }
+}
+
+/// 모든 레이저 광선을 정리하는 시스템입니다.
+fn cleanup_laser_beams(mut commands: Commands, query: Query<Entity, With<LaserBeam>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// 한 번 발사할 때 각 탄의 `(가로 위치, 진행 방향)`을 반환합니다.
+///
+/// 확산탄 파워업이 켜져 있으면 같은 탄 배치를 양옆으로 기울여 한 벌씩 더 쏩니다.
+fn shots<'a>(
+    stats: &'a WeaponLevel,
+    config: &GameConfig,
+    powerups: &ActivePowerUps,
+) -> impl Iterator<Item = (f32, Vec2)> + 'a {
+    let spread = config.powerups.spread_shot_angle;
+    let tilts = if powerups.is_active(PowerUpKind::SpreadShot) {
+        vec![-spread, 0.0, spread]
+    } else {
+        vec![0.0]
+    };
+
+    tilts.into_iter().flat_map(move |tilt| {
+        stats.volley().map(move |(offset, angle)| {
+            // 양수 각도는 오른쪽으로 기울어짐
+            let direction = Vec2::from_angle(-(angle + tilt).to_radians()).rotate(Vec2::Y);
+            (offset, direction)
+        })
+    })
+}
+
+/// 플레이어 투사체 한 발을 무기 종류에 맞게 스폰하고 [`EntityCommands`]를 반환합니다.
+///
+/// 크기와 충돌 반경은 설정의 플레이어 투사체 값을 사용합니다.
+/// 유도 탄은 설정의 `missiles` 값으로 [`Homing`]과 [`Lifetime`]을 붙입니다.
+fn spawn_player_projectile<'a>(
+    commands: &'a mut Commands,
+    sprites: &SpriteAssets,
+    config: &GameConfig,
+    kind: WeaponKind,
+    position: Vec2,
+    velocity: Vec2,
+    damage: u32,
+) -> EntityCommands<'a> {
+    let mut projectile = commands.spawn((
+        Sprite {
+            image: sprites.bullet.clone(),
+            color: weapon_color(kind),
+            ..default()
+        },
+        Transform {
+            translation: position.extend(0.0),
+            // 투사체 스프라이트는 위쪽을 향하므로 진행 방향으로 회전
+            rotation: Quat::from_rotation_arc_2d(Vec2::Y, velocity.normalize()),
+            scale: Vec3::splat(config.projectile_scale),
+        },
+        Projectile,
+        Velocity(velocity),
+        FaceVelocity,
+        PreviousPosition(position),
+        Collider::player_bullet(ColliderShape::Circle {
+            radius: config.projectile_collision_radius,
+        }),
+        Damage(damage),
+    ));
+
+    if kind == WeaponKind::Homing {
+        projectile.insert((
+            Homing::new(config.missiles.turn_rate),
+            Lifetime(Timer::from_seconds(
+                config.missiles.lifetime,
+                TimerMode::Once,
+            )),
+        ));
+    }
+
+    projectile
+}